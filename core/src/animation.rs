@@ -0,0 +1,647 @@
+//! Animate values over time with easing curves and springs.
+//!
+//! An [`Animation`] holds a value that transitions smoothly towards a target
+//! every time it changes. Widgets usually keep an [`Animation`] in their
+//! state, retarget it with [`Animation::go_mut`], read it with
+//! [`Animation::value`] while drawing and keep it moving by forwarding
+//! [`Animation::redraw_request`] to [`Shell::request_redraw`].
+//!
+//! [`Shell::request_redraw`]: crate::Shell::request_redraw
+use crate::border;
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{
+    Background, Border, Color, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/// A value that transitions smoothly towards a target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    transition: Option<Transition>,
+    timing: Timing,
+    delay: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transition {
+    started_at: Instant,
+    duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Timing {
+    Eased { duration: Duration, easing: Easing },
+    Spring(Spring),
+}
+
+impl Timing {
+    fn duration(&self) -> Duration {
+        match self {
+            Timing::Eased { duration, .. } => *duration,
+            Timing::Spring(spring) => spring.duration(),
+        }
+    }
+
+    fn progress(&self, elapsed: Duration, duration: Duration) -> f32 {
+        match self {
+            Timing::Eased { easing, .. } => {
+                easing.apply(elapsed.as_secs_f32() / duration.as_secs_f32())
+            }
+            Timing::Spring(spring) => spring.progress(elapsed.as_secs_f32()),
+        }
+    }
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolable + PartialEq,
+{
+    /// The default duration of an eased [`Animation`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Animation`] resting at the given value.
+    ///
+    /// By default, transitions take [`Self::DEFAULT_DURATION`] and follow
+    /// [`Easing::EaseInOut`].
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            transition: None,
+            timing: Timing::Eased {
+                duration: Self::DEFAULT_DURATION,
+                easing: Easing::default(),
+            },
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Sets the duration of the transitions of the [`Animation`].
+    ///
+    /// This replaces any [`Spring`] previously set.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.timing = match self.timing {
+            Timing::Eased { easing, .. } => Timing::Eased { duration, easing },
+            Timing::Spring(_) => Timing::Eased {
+                duration,
+                easing: Easing::default(),
+            },
+        };
+        self
+    }
+
+    /// Sets the [`Easing`] of the transitions of the [`Animation`].
+    ///
+    /// This replaces any [`Spring`] previously set.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.timing = match self.timing {
+            Timing::Eased { duration, .. } => {
+                Timing::Eased { duration, easing }
+            }
+            Timing::Spring(_) => Timing::Eased {
+                duration: Self::DEFAULT_DURATION,
+                easing,
+            },
+        };
+        self
+    }
+
+    /// Drives the transitions of the [`Animation`] with the given [`Spring`].
+    ///
+    /// The duration of every transition is then determined by the time the
+    /// [`Spring`] takes to settle.
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.timing = Timing::Spring(spring);
+        self
+    }
+
+    /// Sets the delay before every transition of the [`Animation`] starts.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Starts a transition towards the given target at the given [`Instant`].
+    pub fn go(mut self, target: T, at: Instant) -> Self {
+        self.go_mut(target, at);
+        self
+    }
+
+    /// Starts a transition towards the given target at the given [`Instant`].
+    ///
+    /// Nothing happens if the [`Animation`] is already heading towards the
+    /// target. Otherwise, the new transition starts from the current value,
+    /// so retargeting an ongoing transition never makes the value jump.
+    pub fn go_mut(&mut self, target: T, at: Instant) {
+        if self.to == target {
+            return;
+        }
+
+        self.from = self.value(at);
+        self.to = target;
+        self.transition = Some(Transition {
+            started_at: at,
+            duration: self.timing.duration(),
+        });
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, at: Instant) -> T {
+        let Some(transition) = self.transition else {
+            return self.to.clone();
+        };
+
+        let start = transition.started_at + self.delay;
+
+        if at <= start {
+            return self.from.clone();
+        }
+
+        let elapsed = at - start;
+
+        if elapsed >= transition.duration {
+            return self.to.clone();
+        }
+
+        self.from.interpolate(
+            &self.to,
+            self.timing.progress(elapsed, transition.duration),
+        )
+    }
+
+    /// Returns the target of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns whether the [`Animation`] is still transitioning at the given
+    /// [`Instant`], including any pending delay.
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.transition.is_some_and(|transition| {
+            at < transition.started_at + self.delay + transition.duration
+        })
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the [`Animation`]
+    /// moving at the given [`Instant`], if any.
+    ///
+    /// While a transition is delayed, a redraw is requested for the moment it
+    /// starts. Afterwards, every frame is requested until it finishes.
+    pub fn redraw_request(&self, at: Instant) -> Option<window::RedrawRequest> {
+        if !self.is_animating(at) {
+            return None;
+        }
+
+        let start = self.transition?.started_at + self.delay;
+
+        Some(if at < start {
+            window::RedrawRequest::At(start)
+        } else {
+            window::RedrawRequest::NextFrame
+        })
+    }
+}
+
+impl<T> Default for Animation<T>
+where
+    T: Interpolable + PartialEq + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// An easing curve, mapping linear progress to eased progress.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly and accelerates (cubic).
+    EaseIn,
+    /// Starts quickly and decelerates (cubic).
+    EaseOut,
+    /// Accelerates and then decelerates (cubic).
+    #[default]
+    EaseInOut,
+    /// Starts slowly and accelerates (quadratic).
+    EaseInQuad,
+    /// Starts quickly and decelerates (quadratic).
+    EaseOutQuad,
+    /// Accelerates and then decelerates (quadratic).
+    EaseInOutQuad,
+    /// Decelerates, overshooting the target slightly before settling.
+    EaseOutBack,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the given control
+    /// points, like CSS `cubic-bezier(x1, y1, x2, y2)`.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the given linear progress.
+    ///
+    /// The progress is clamped between `0.0` and `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseOutBack => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1, y1, x2, y2, t)
+            }
+        }
+    }
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let bezier = |a: f32, b: f32, t: f32| {
+        3.0 * a * (1.0 - t).powi(2) * t
+            + 3.0 * b * (1.0 - t) * t * t
+            + t.powi(3)
+    };
+
+    let derivative = |a: f32, b: f32, t: f32| {
+        3.0 * a * (1.0 - t).powi(2)
+            + 6.0 * (b - a) * (1.0 - t) * t
+            + 3.0 * (1.0 - b) * t * t
+    };
+
+    // Find the curve parameter for `x` with Newton's method, falling back
+    // to bisection when the slope is too flat to converge
+    let mut t = x;
+
+    for _ in 0..8 {
+        let error = bezier(x1, x2, t) - x;
+
+        if error.abs() < 1e-5 {
+            return bezier(y1, y2, t);
+        }
+
+        let slope = derivative(x1, x2, t);
+
+        if slope.abs() < 1e-6 {
+            break;
+        }
+
+        t -= error / slope;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x;
+
+    for _ in 0..32 {
+        let value = bezier(x1, x2, t);
+
+        if (value - x).abs() < 1e-5 {
+            break;
+        }
+
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        t = (low + high) / 2.0;
+    }
+
+    bezier(y1, y2, t)
+}
+
+/// A physical spring driving the transitions of an [`Animation`].
+///
+/// Depending on its damping, a [`Spring`] may overshoot its target and
+/// oscillate around it before settling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+}
+
+impl Spring {
+    /// The displacement under which a [`Spring`] is considered settled, as a
+    /// fraction of the distance travelled.
+    const REST_THRESHOLD: f32 = 0.001;
+
+    /// The longest time a [`Spring`] is allowed to take to settle.
+    const MAX_DURATION: Duration = Duration::from_secs(10);
+
+    /// A balanced [`Spring`] without noticeable overshoot.
+    pub const DEFAULT: Self = Self::new(170.0, 26.0);
+
+    /// A soft and slow [`Spring`].
+    pub const GENTLE: Self = Self::new(120.0, 14.0);
+
+    /// A bouncy [`Spring`] that overshoots noticeably.
+    pub const WOBBLY: Self = Self::new(180.0, 12.0);
+
+    /// A quick and stiff [`Spring`].
+    pub const STIFF: Self = Self::new(210.0, 20.0);
+
+    /// Creates a new [`Spring`] with the given stiffness and damping, and a
+    /// mass of `1.0`.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Sets the mass of the [`Spring`].
+    pub const fn mass(self, mass: f32) -> Self {
+        Self { mass, ..self }
+    }
+
+    /// Returns the progress of a [`Spring`] released from rest after the
+    /// given amount of seconds.
+    ///
+    /// The progress starts at `0.0` and settles at `1.0`, possibly
+    /// overshooting it.
+    pub fn progress(&self, t: f32) -> f32 {
+        1.0 - self.displacement(t)
+    }
+
+    /// Returns the time the [`Spring`] takes to settle.
+    pub fn duration(&self) -> Duration {
+        const STEP: f32 = 1.0 / 240.0;
+
+        let max = Self::MAX_DURATION.as_secs_f32();
+        let mut settled_at = 0.0;
+        let mut t = 0.0;
+
+        // Keep the last time the displacement was over the threshold
+        while t < max {
+            if self.displacement(t).abs() >= Self::REST_THRESHOLD {
+                settled_at = t + STEP;
+            } else if t - settled_at > 1.0 {
+                break;
+            }
+
+            t += STEP;
+        }
+
+        Duration::from_secs_f32(settled_at.min(max))
+    }
+
+    /// The displacement of the [`Spring`] from its target after `t` seconds,
+    /// starting at `1.0` with no velocity.
+    fn displacement(&self, t: f32) -> f32 {
+        let mass = self.mass.max(f32::EPSILON);
+        let omega = (self.stiffness.max(f32::EPSILON) / mass).sqrt();
+        let zeta = self.damping.max(0.0)
+            / (2.0 * (self.stiffness.max(f32::EPSILON) * mass).sqrt());
+
+        if (zeta - 1.0).abs() < 1e-4 {
+            // Critically damped
+            (1.0 + omega * t) * (-omega * t).exp()
+        } else if zeta < 1.0 {
+            // Underdamped
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+
+            (-zeta * omega * t).exp()
+                * ((omega_d * t).cos()
+                    + (zeta * omega / omega_d) * (omega_d * t).sin())
+        } else {
+            // Overdamped
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolable: Clone {
+    /// Interpolates between `self` and `other` by the given ratio.
+    ///
+    /// A ratio of `0.0` produces `self` and a ratio of `1.0` produces
+    /// `other`. Ratios outside of this range may be produced by some easing
+    /// curves and springs.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self;
+}
+
+impl Interpolable for f32 {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolable for Color {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, ratio),
+            g: self.g.interpolate(&other.g, ratio),
+            b: self.b.interpolate(&other.b, ratio),
+            a: self.a.interpolate(&other.a, ratio).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Interpolable for Point {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Size {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, ratio),
+            self.height.interpolate(&other.height, ratio),
+        )
+    }
+}
+
+impl Interpolable for Rectangle {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&other.position(), ratio),
+            self.size().interpolate(&other.size(), ratio),
+        )
+    }
+}
+
+impl Interpolable for border::Radius {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        border::Radius {
+            top_left: self.top_left.interpolate(&other.top_left, ratio),
+            top_right: self.top_right.interpolate(&other.top_right, ratio),
+            bottom_right: self
+                .bottom_right
+                .interpolate(&other.bottom_right, ratio),
+            bottom_left: self
+                .bottom_left
+                .interpolate(&other.bottom_left, ratio),
+        }
+    }
+}
+
+impl Interpolable for Border {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Border {
+            color: self.color.interpolate(&other.color, ratio),
+            width: self.width.interpolate(&other.width, ratio).max(0.0),
+            radius: self.radius.interpolate(&other.radius, ratio),
+        }
+    }
+}
+
+impl Interpolable for Shadow {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, ratio),
+            offset: self.offset.interpolate(&other.offset, ratio),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, ratio)
+                .max(0.0),
+        }
+    }
+}
+
+/// Solid colors are blended; any other pair of backgrounds switches halfway
+/// through.
+impl Interpolable for Background {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, ratio))
+            }
+            _ if ratio < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eased_animation_reaches_target() {
+        let now = Instant::now();
+        let animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(10.0, now);
+
+        assert_eq!(animation.value(now), 0.0);
+        assert!(
+            (animation.value(now + Duration::from_millis(50)) - 5.0).abs()
+                < 1e-3
+        );
+        assert_eq!(animation.value(now + Duration::from_millis(100)), 10.0);
+        assert!(!animation.is_animating(now + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn retargeting_starts_from_current_value() {
+        let now = Instant::now();
+        let halfway = now + Duration::from_millis(50);
+
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(10.0, now);
+
+        animation.go_mut(0.0, halfway);
+
+        assert!((animation.value(halfway) - 5.0).abs() < 1e-3);
+        assert_eq!(*animation.target(), 0.0);
+    }
+
+    #[test]
+    fn delayed_animation_requests_redraw_at_start() {
+        let now = Instant::now();
+        let delay = Duration::from_millis(300);
+        let animation = Animation::new(0.0).delay(delay).go(1.0, now);
+
+        assert_eq!(
+            animation.redraw_request(now),
+            Some(window::RedrawRequest::At(now + delay))
+        );
+        assert_eq!(
+            animation.redraw_request(now + delay + Duration::from_millis(1)),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        assert_eq!(
+            animation.redraw_request(now + Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn springs_settle_at_target() {
+        for spring in
+            [Spring::DEFAULT, Spring::WOBBLY, Spring::new(100.0, 40.0)]
+        {
+            let duration = spring.duration();
+
+            assert!(duration > Duration::ZERO);
+            assert!(duration < Spring::MAX_DURATION);
+            assert!(
+                (spring.progress(duration.as_secs_f32()) - 1.0).abs() < 0.01
+            );
+        }
+
+        assert!((0..200)
+            .map(|i| Spring::WOBBLY.progress(i as f32 / 100.0))
+            .any(|progress| progress > 1.0));
+    }
+
+    #[test]
+    fn cubic_bezier_matches_endpoints() {
+        let ease = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+
+        assert!(ease.apply(0.0).abs() < 1e-3);
+        assert!((ease.apply(1.0) - 1.0).abs() < 1e-3);
+        assert!(ease.apply(0.5) > 0.5);
+    }
+}
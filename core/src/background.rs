@@ -1,14 +1,19 @@
+//! Fill the background of some element.
 use crate::gradient::{self, Gradient};
-use crate::Color;
+use crate::image;
+use crate::{Color, ContentFit, Rectangle, Size};
+
+use std::sync::Arc;
 
 /// The background of some element.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Background {
     /// A solid color.
     Color(Color),
     /// Linearly interpolate between several colors.
     Gradient(Gradient),
-    // TODO: Add image variant
+    /// A raster image.
+    Image(Image),
 }
 
 impl Background {
@@ -20,6 +25,7 @@ impl Background {
             Self::Gradient(gradient) => {
                 Self::Gradient(gradient.scale_alpha(factor))
             }
+            Self::Image(image) => Self::Image(image.scale_alpha(factor)),
         }
     }
 }
@@ -41,3 +47,187 @@ impl From<gradient::Linear> for Background {
         Background::Gradient(Gradient::Linear(gradient))
     }
 }
+
+impl From<gradient::Radial> for Background {
+    fn from(gradient: gradient::Radial) -> Self {
+        Background::Gradient(Gradient::Radial(gradient))
+    }
+}
+
+impl From<gradient::Conic> for Background {
+    fn from(gradient: gradient::Conic) -> Self {
+        Background::Gradient(Gradient::Conic(gradient))
+    }
+}
+
+impl From<Image> for Background {
+    fn from(image: Image) -> Self {
+        Background::Image(image)
+    }
+}
+
+/// A raster image used as a [`Background`].
+///
+/// The image is clipped to the bounds of the element, including the
+/// radius of its border.
+///
+/// The [`image::Handle`] is shared, so cloning an [`Image`] is cheap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    /// The handle of the image.
+    pub handle: Arc<image::Handle>,
+    /// How the image is fitted to the bounds of the element.
+    pub content_fit: ContentFit,
+    /// How the image is repeated to fill the bounds of the element.
+    pub repeat: Repeat,
+    /// The opacity of the image, from `0.0` to `1.0`.
+    pub opacity: f32,
+    /// The filter method used to scale the image.
+    pub filter_method: image::FilterMethod,
+}
+
+impl Image {
+    /// Creates a new [`Image`] with the given [`image::Handle`].
+    ///
+    /// By default, the image covers the bounds of the element without
+    /// repeating.
+    pub fn new(handle: impl Into<image::Handle>) -> Self {
+        Self {
+            handle: Arc::new(handle.into()),
+            content_fit: ContentFit::Cover,
+            repeat: Repeat::default(),
+            opacity: 1.0,
+            filter_method: image::FilterMethod::default(),
+        }
+    }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`Repeat`] mode of the [`Image`].
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`].
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets the [`image::FilterMethod`] of the [`Image`].
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Scales the opacity of the [`Image`] by the given factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        self.opacity *= factor;
+        self
+    }
+
+    /// Returns the bounds of every copy of an image of the given size that
+    /// needs to be drawn to fill the given bounds.
+    ///
+    /// The copies may exceed the bounds and must be clipped to them.
+    pub fn tiles(&self, size: Size<u32>, bounds: Rectangle) -> Vec<Rectangle> {
+        let size = Size::new(size.width as f32, size.height as f32);
+        let fitted = self.content_fit.fit(size, bounds.size());
+
+        if !(fitted.width > 0.0 && fitted.height > 0.0) {
+            return Vec::new();
+        }
+
+        let x = bounds.center_x() - fitted.width / 2.0;
+        let y = bounds.center_y() - fitted.height / 2.0;
+
+        let (repeat_x, repeat_y) = match self.repeat {
+            Repeat::NoRepeat => (false, false),
+            Repeat::Repeat => (true, true),
+            Repeat::RepeatX => (true, false),
+            Repeat::RepeatY => (false, true),
+        };
+
+        let columns = if repeat_x {
+            offsets(x, fitted.width, bounds.x, bounds.x + bounds.width)
+        } else {
+            vec![x]
+        };
+
+        let rows = if repeat_y {
+            offsets(y, fitted.height, bounds.y, bounds.y + bounds.height)
+        } else {
+            vec![y]
+        };
+
+        rows.iter()
+            .flat_map(|&y| {
+                columns.iter().map(move |&x| Rectangle {
+                    x,
+                    y,
+                    width: fitted.width,
+                    height: fitted.height,
+                })
+            })
+            .collect()
+    }
+}
+
+/// How a background [`Image`] is repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Repeat {
+    /// The image is drawn once.
+    #[default]
+    NoRepeat,
+    /// The image is repeated in both axes.
+    Repeat,
+    /// The image is repeated horizontally.
+    RepeatX,
+    /// The image is repeated vertically.
+    RepeatY,
+}
+
+/// Returns the offsets of the copies of a segment of the given length,
+/// aligned with the given start, that cover the range from `min` to `max`.
+fn offsets(start: f32, length: f32, min: f32, max: f32) -> Vec<f32> {
+    let first = start - ((start - min) / length).ceil() * length;
+
+    let mut offsets = Vec::new();
+    let mut offset = first;
+
+    while offset < max {
+        offsets.push(offset);
+        offset += length;
+    }
+
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_tiles_cover_bounds() {
+        let image = Image::new(image::Handle::from_rgba(1, 1, vec![0; 4]))
+            .content_fit(ContentFit::None)
+            .repeat(Repeat::RepeatX);
+
+        let bounds = Rectangle::new(
+            crate::Point::new(0.0, 0.0),
+            Size::new(100.0, 100.0),
+        );
+
+        let tiles = image.tiles(Size::new(30, 30), bounds);
+
+        assert_eq!(tiles.len(), 5);
+        assert_eq!(tiles[0].x, -25.0);
+        assert_eq!(tiles[2].x, 35.0);
+        assert!(tiles.iter().all(|tile| tile.y == 35.0));
+    }
+}
@@ -2,8 +2,10 @@
 use dnd::DndEvent;
 use dnd::DndSurface;
 
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
+use crate::pen;
 use crate::touch;
 use crate::window;
 #[cfg(feature = "wayland")]
@@ -29,6 +31,12 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// A pen event
+    Pen(pen::Event),
+
+    /// An input method event
+    InputMethod(input_method::Event),
+
     #[cfg(feature = "a11y")]
     /// An Accesskit event for a specific Accesskit Node in an accessible widget
     A11y(
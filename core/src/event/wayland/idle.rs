@@ -0,0 +1,8 @@
+/// idle notification events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleEvent {
+    /// The user has been idle for the requested timeout
+    Idled,
+    /// The user is no longer idle
+    Resumed,
+}
@@ -1,3 +1,4 @@
+mod idle;
 mod layer;
 mod output;
 mod overlap_notify;
@@ -11,6 +12,7 @@ use cctk::sctk::reexports::client::protocol::{
     wl_output::WlOutput, wl_seat::WlSeat, wl_surface::WlSurface,
 };
 
+pub use idle::*;
 pub use layer::*;
 pub use output::*;
 pub use overlap_notify::*;
@@ -36,6 +38,8 @@ pub enum Event {
     Seat(SeatEvent, WlSeat),
     /// Session lock events
     SessionLock(SessionLockEvent),
+    /// Idle notification events
+    Idle(IdleEvent),
     /// Frame events
     Frame(Instant, WlSurface, Id),
     /// Request Resize
@@ -1,14 +1,18 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from a center.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around a center.
+    Conic(Conic),
 }
 
 impl Gradient {
@@ -18,6 +22,12 @@ impl Gradient {
             Gradient::Linear(linear) => {
                 Gradient::Linear(linear.scale_alpha(factor))
             }
+            Gradient::Radial(radial) => {
+                Gradient::Radial(radial.scale_alpha(factor))
+            }
+            Gradient::Conic(conic) => {
+                Gradient::Conic(conic.scale_alpha(factor))
+            }
         }
     }
 }
@@ -28,6 +38,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -66,19 +88,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.total_cmp(&offset),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -100,10 +110,175 @@ impl Linear {
     /// Scales the alpha channel of the [`Linear`] gradient by the given
     /// factor.
     pub fn scale_alpha(mut self, factor: f32) -> Self {
-        for stop in self.stops.iter_mut().flatten() {
-            stop.color.a *= factor;
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+}
+
+/// A radial gradient.
+///
+/// Offset `0.0` is at the [`center`] and offset `1.0` is at the [`radius`].
+///
+/// [`center`]: Self::center
+/// [`radius`]: Self::radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The center of the gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner of the bounds and `(1.0, 1.0)` is
+    /// the bottom-right one.
+    pub center: Point,
+    /// The radius of the gradient, relative to half of the shortest side of
+    /// its bounds.
+    ///
+    /// A radius of `1.0` around the middle of some bounds touches their
+    /// closest sides.
+    pub radius: f32,
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient with the given relative radius,
+    /// centered in its bounds.
+    pub fn new(radius: f32) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            radius,
+            stops: [None; 8],
+        }
+    }
+
+    /// Sets the relative center of the [`Radial`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
         }
 
         self
     }
+
+    /// Scales the alpha channel of the [`Radial`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+}
+
+/// A conic gradient.
+///
+/// Colors sweep clockwise around the [`center`], starting at the [`angle`]
+/// with offset `0.0` and finishing a full turn later with offset `1.0`.
+///
+/// [`center`]: Self::center
+/// [`angle`]: Self::angle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The center of the gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner of the bounds and `(1.0, 1.0)` is
+    /// the bottom-right one.
+    pub center: Point,
+    /// Where the gradient starts, clockwise from the top.
+    pub angle: Radians,
+    /// [`ColorStop`]s around the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient starting at the given angle in
+    /// [`Radians`], centered in its bounds.
+    pub fn new(angle: impl Into<Radians>) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Sets the relative center of the [`Conic`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Conic`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.total_cmp(&offset),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn scale_alpha(stops: &mut [Option<ColorStop>; 8], factor: f32) {
+    for stop in stops.iter_mut().flatten() {
+        stop.color.a *= factor;
+    }
 }
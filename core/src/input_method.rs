@@ -0,0 +1,142 @@
+//! Compose text with an input method editor (IME).
+use crate::{Pixels, Rectangle};
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input methods let users compose text that cannot be typed directly with
+/// their keyboard, like CJK characters or accented letters.
+///
+/// A focused editable widget receives these events instead of plain key
+/// presses while a composition is in progress.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The input method was enabled.
+    ///
+    /// A focused widget may receive [`Event::Preedit`] and [`Event::Commit`]
+    /// events from now on.
+    Opened,
+
+    /// The text being composed changed.
+    ///
+    /// The first value is the text that is not committed yet, which should be
+    /// displayed in place at the caret. An empty string means the
+    /// composition was cleared.
+    ///
+    /// The second value is the byte range of the text that is currently
+    /// selected inside the composition, if any.
+    Preedit(String, Option<Range<usize>>),
+
+    /// The composition was committed and the given text must be inserted at
+    /// the caret.
+    Commit(String),
+
+    /// The input method was disabled.
+    ///
+    /// Any ongoing composition must be discarded.
+    Closed,
+}
+
+/// The input method requested by a user interface.
+///
+/// Focused editable widgets request an input method with
+/// [`Shell::request_input_method`] every time they are redrawn, so the shell
+/// can place the candidate window of the platform next to the caret.
+///
+/// [`Shell::request_input_method`]: crate::Shell::request_input_method
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum InputMethod {
+    /// No input method is allowed.
+    #[default]
+    Disabled,
+
+    /// Input methods are allowed.
+    Enabled {
+        /// The area of the caret, in window coordinates.
+        ///
+        /// The candidate window of the platform will avoid covering it.
+        cursor: Rectangle,
+
+        /// The [`Purpose`] of the input.
+        purpose: Purpose,
+
+        /// The [`Preedit`] currently displayed by the widget, if any.
+        preedit: Option<Preedit>,
+    },
+}
+
+impl InputMethod {
+    /// Returns true if the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+
+    /// Merges two [`InputMethod`] strategies, prioritizing the first one when
+    /// both are enabled.
+    ///
+    /// ```
+    /// # use iced_core::input_method::{InputMethod, Purpose};
+    /// # use iced_core::Rectangle;
+    /// let enabled = InputMethod::Enabled {
+    ///     cursor: Rectangle::with_size(iced_core::Size::new(1.0, 16.0)),
+    ///     purpose: Purpose::Normal,
+    ///     preedit: None,
+    /// };
+    ///
+    /// let mut ime = InputMethod::Disabled;
+    /// ime.merge(&enabled);
+    ///
+    /// assert_eq!(ime, enabled);
+    ///
+    /// ime.merge(&InputMethod::Disabled);
+    ///
+    /// assert_eq!(ime, enabled);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        if let (Self::Disabled, Self::Enabled { .. }) = (&self, other) {
+            *self = other.clone();
+        }
+    }
+}
+
+/// The purpose of an input, which may change the behavior of the input
+/// method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Purpose {
+    /// Regular text.
+    #[default]
+    Normal,
+
+    /// Sensitive text that should not be remembered or displayed, like a
+    /// password.
+    Secure,
+
+    /// Input for a terminal emulator.
+    Terminal,
+}
+
+/// The text being composed by an input method, but not committed yet.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Preedit {
+    /// The text being composed.
+    pub content: String,
+
+    /// The byte range of the selection inside the `content`, if any.
+    pub selection: Option<Range<usize>>,
+
+    /// The text size of the widget displaying the [`Preedit`], if known.
+    pub text_size: Option<Pixels>,
+}
+
+impl Preedit {
+    /// Creates an empty [`Preedit`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if there is no text being composed.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+}
@@ -10,17 +10,21 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 pub mod alignment;
+pub mod animation;
+pub mod background;
 pub mod border;
 pub mod clipboard;
 pub mod event;
 pub mod font;
 pub mod gradient;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod padding;
+pub mod pen;
 pub mod renderer;
 pub mod svg;
 pub mod text;
@@ -31,7 +35,6 @@ pub mod widget;
 pub mod window;
 
 mod angle;
-mod background;
 mod color;
 mod content_fit;
 mod element;
@@ -51,6 +54,7 @@ mod vector;
 
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
+pub use animation::Animation;
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -64,6 +68,7 @@ pub use hasher::Hasher;
 #[cfg(feature = "a11y")]
 pub use iced_accessibility::id;
 pub use image::Image;
+pub use input_method::InputMethod;
 pub use layout::Layout;
 pub use length::Length;
 pub use overlay::Overlay;
@@ -1,5 +1,6 @@
 //! Handle mouse events.
 pub mod click;
+pub mod gesture;
 
 mod button;
 mod cursor;
@@ -10,4 +11,5 @@ pub use button::Button;
 pub use click::Click;
 pub use cursor::Cursor;
 pub use event::{Event, ScrollDelta};
+pub use gesture::Gesture;
 pub use interaction::Interaction;
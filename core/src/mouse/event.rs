@@ -1,6 +1,6 @@
-use crate::Point;
+use crate::{Point, Vector};
 
-use super::Button;
+use super::{Button, Gesture};
 
 /// A mouse event.
 ///
@@ -9,6 +9,7 @@ use super::Button;
 ///
 /// [open an issue]: https://github.com/iced-rs/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -33,10 +34,29 @@ pub enum Event {
         /// The scroll movement.
         delta: ScrollDelta,
     },
+
+    /// The mouse moved by the given distance.
+    ///
+    /// Unlike [`CursorMoved`], the motion is unaccelerated and keeps being
+    /// reported while the pointer is locked or at the edge of the screen.
+    ///
+    /// It is only reported to windows that have grabbed the pointer with a
+    /// [`PointerGrab`].
+    ///
+    /// [`CursorMoved`]: Self::CursorMoved
+    /// [`PointerGrab`]: crate::window::PointerGrab
+    RawMotion {
+        /// The relative movement.
+        delta: Vector,
+    },
+
+    /// A touchpad gesture was performed.
+    Gesture(Gesture),
 }
 
 /// A scroll movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
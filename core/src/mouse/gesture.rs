@@ -0,0 +1,86 @@
+//! Track touchpad gestures.
+use crate::Vector;
+
+/// A gesture performed with multiple fingers on a touchpad.
+///
+/// Every gesture starts with [`Phase::Started`], followed by any number of
+/// [`Phase::Moved`] updates, and finishes with either [`Phase::Ended`] or
+/// [`Phase::Cancelled`].
+///
+/// ## Platform-specific
+///
+/// - **Wayland:** Requires the `zwp_pointer_gestures_v1` protocol. Hold
+///   gestures require version 3.
+/// - **Others:** Unsupported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gesture {
+    /// Fingers were swiped across the touchpad.
+    Swipe {
+        /// The phase of the gesture.
+        phase: Phase,
+        /// The number of fingers.
+        fingers: u32,
+        /// The logical distance moved since the last update.
+        delta: Vector,
+    },
+
+    /// Fingers were pinched or rotated on the touchpad.
+    Pinch {
+        /// The phase of the gesture.
+        phase: Phase,
+        /// The number of fingers.
+        fingers: u32,
+        /// The logical distance the center of the fingers moved since the
+        /// last update.
+        delta: Vector,
+        /// The change in scale since the last update, as a factor.
+        ///
+        /// It is greater than `1.0` when the fingers move apart.
+        scale: f32,
+        /// The rotation since the last update, in degrees clockwise.
+        rotation: f32,
+    },
+
+    /// Fingers were held still on the touchpad.
+    Hold {
+        /// The phase of the gesture.
+        phase: Phase,
+        /// The number of fingers.
+        fingers: u32,
+    },
+}
+
+impl Gesture {
+    /// Returns the [`Phase`] of the [`Gesture`].
+    pub fn phase(&self) -> Phase {
+        match self {
+            Self::Swipe { phase, .. }
+            | Self::Pinch { phase, .. }
+            | Self::Hold { phase, .. } => *phase,
+        }
+    }
+
+    /// Returns the number of fingers involved in the [`Gesture`].
+    pub fn fingers(&self) -> u32 {
+        match self {
+            Self::Swipe { fingers, .. }
+            | Self::Pinch { fingers, .. }
+            | Self::Hold { fingers, .. } => *fingers,
+        }
+    }
+}
+
+/// The phase of a [`Gesture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    /// The gesture started.
+    Started,
+    /// The gesture is ongoing and the fingers moved.
+    Moved,
+    /// The gesture finished.
+    Ended,
+    /// The gesture was cancelled (e.g. the compositor took it over).
+    Cancelled,
+}
@@ -0,0 +1,116 @@
+//! Handle pen and drawing tablet events.
+//!
+//! A pen also moves the mouse cursor and touching the tablet with it presses
+//! the left mouse button, so widgets that only handle mouse events keep
+//! working with a pen.
+use crate::{Point, Vector};
+
+/// A pen interaction.
+///
+/// ## Platform-specific
+///
+/// - **Wayland:** Requires the `zwp_tablet_manager_v2` protocol.
+/// - **Others:** Unsupported. Pens are reported as a mouse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// The pen came within range of the tablet over the window.
+    Entered(Pen),
+
+    /// The pen moved, or any of its axes changed.
+    Moved(Pen),
+
+    /// The pen touched the tablet.
+    Touched(Pen),
+
+    /// The pen was lifted from the tablet.
+    Lifted(Pen),
+
+    /// A button of the pen was pressed.
+    ButtonPressed(Pen, Button),
+
+    /// A button of the pen was released.
+    ButtonReleased(Pen, Button),
+
+    /// The pen went out of range of the tablet, or left the window.
+    Left(Pen),
+}
+
+impl Event {
+    /// Returns the state of the [`Pen`] when the [`Event`] happened.
+    pub fn pen(&self) -> &Pen {
+        match self {
+            Self::Entered(pen)
+            | Self::Moved(pen)
+            | Self::Touched(pen)
+            | Self::Lifted(pen)
+            | Self::ButtonPressed(pen, _)
+            | Self::ButtonReleased(pen, _)
+            | Self::Left(pen) => pen,
+        }
+    }
+}
+
+/// The state of a pen.
+///
+/// Axes that are not supported by the tablet keep their default value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pen {
+    /// The kind of tool in use.
+    pub tool: Tool,
+
+    /// The position of the pen in the window.
+    pub position: Point,
+
+    /// The pressure of the pen on the tablet, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// The distance between the pen and the tablet, from `0.0` (touching)
+    /// to `1.0` (out of range).
+    pub distance: f32,
+
+    /// The tilt of the pen along each axis, in degrees.
+    ///
+    /// Positive values tilt the pen towards the right and the bottom of the
+    /// tablet, respectively.
+    pub tilt: Vector,
+
+    /// Whether the pen is touching the tablet.
+    pub is_touching: bool,
+}
+
+/// The kind of tool used on a tablet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tool {
+    /// A pen.
+    #[default]
+    Pen,
+    /// The eraser end of a pen.
+    Eraser,
+    /// A paintbrush.
+    Brush,
+    /// A pencil.
+    Pencil,
+    /// An airbrush.
+    Airbrush,
+    /// A mouse bound to the tablet.
+    Mouse,
+    /// A lens cursor bound to the tablet.
+    Lens,
+}
+
+/// A button of a pen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Button {
+    /// The button closest to the tip.
+    Primary,
+    /// The second button.
+    Secondary,
+    /// The third button.
+    Tertiary,
+    /// Some other button.
+    Other(u16),
+}
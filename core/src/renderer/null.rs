@@ -26,6 +26,19 @@ impl Renderer for () {
     }
 }
 
+impl renderer::Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Self {}
+
+    fn screenshot(
+        &mut self,
+        _size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        vec![]
+    }
+}
+
 impl text::Renderer for () {
     type Font = Font;
     type Paragraph = ();
@@ -140,6 +153,17 @@ impl text::Editor for () {
         None
     }
 
+    fn anchor_position(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    fn set_cursor(
+        &mut self,
+        _position: (usize, usize),
+        _anchor: Option<(usize, usize)>,
+    ) {
+    }
+
     fn line(&self, _index: usize) -> Option<&str> {
         None
     }
@@ -1,4 +1,6 @@
+use crate::widget::operation::focusable;
 use crate::window;
+use crate::InputMethod;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +12,8 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
+    focus_request: Option<focusable::Direction>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +24,8 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
+            focus_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +59,32 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`InputMethod`] strategy.
+    ///
+    /// Focused editable widgets should call this every time they are redrawn.
+    /// If multiple widgets request an [`InputMethod`], the first enabled one
+    /// wins.
+    pub fn request_input_method(&mut self, ime: &InputMethod) {
+        self.input_method.merge(ime);
+    }
+
+    /// Returns the current [`InputMethod`] strategy.
+    pub fn input_method(&self) -> &InputMethod {
+        &self.input_method
+    }
+
+    /// Requests the keyboard focus to move in the given
+    /// [`focusable::Direction`] once the current event has been processed.
+    pub fn request_focus(&mut self, direction: focusable::Direction) {
+        self.focus_request = Some(direction);
+    }
+
+    /// Returns the requested [`focusable::Direction`] of the keyboard focus,
+    /// if any.
+    pub fn focus_request(&self) -> Option<focusable::Direction> {
+        self.focus_request
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +131,12 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.request_input_method(&other.input_method);
+
+        if let Some(direction) = other.focus_request {
+            self.request_focus(direction);
+        }
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
@@ -24,15 +24,60 @@ pub trait Editor: Sized + Default {
     /// Line and column, respectively.
     fn cursor_position(&self) -> (usize, usize);
 
+    /// Returns the [`Cursor`] the [`Editor`] would have with its cursor at
+    /// the given position and the selection anchor at the given one, if any.
+    ///
+    /// This is useful to draw additional cursors. Line and column,
+    /// respectively.
+    ///
+    /// By default, only the current cursor of the [`Editor`] is known and any
+    /// other position produces an empty selection, which draws nothing.
+    fn cursor_at(
+        &self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) -> Cursor {
+        if anchor.is_none() && position == self.cursor_position() {
+            self.cursor()
+        } else {
+            Cursor::Selection(Vec::new())
+        }
+    }
+
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the position of the anchor of the current selection of the
+    /// [`Editor`], if any.
+    ///
+    /// The anchor is the end of the selection opposite to the cursor. Line
+    /// and column, respectively.
+    fn anchor_position(&self) -> Option<(usize, usize)>;
+
+    /// Moves the cursor of the [`Editor`] to the given position, selecting
+    /// the text between it and the given anchor, if any.
+    ///
+    /// Line and column, respectively.
+    fn set_cursor(
+        &mut self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    );
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the lines of the [`Editor`] that are currently visible, in
+    /// order.
+    ///
+    /// By default, no lines are known to be visible.
+    fn visible_lines(&self) -> Vec<VisibleLine> {
+        Vec::new()
+    }
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
@@ -87,12 +132,60 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Move the cursor to the given position, selecting the text between it
+    /// and the given anchor, if any.
+    ///
+    /// Positions are given as line and column, respectively.
+    SetCursor {
+        /// The new position of the cursor.
+        position: (usize, usize),
+        /// The new position of the selection anchor.
+        anchor: Option<(usize, usize)>,
+    },
+    /// Add a cursor at the given [`Point`], keeping the existing ones.
+    ///
+    /// The new cursor becomes the main one.
+    AddCursor(Point),
+    /// Select the block of text between the main cursor, at the time the
+    /// block selection started, and the given [`Point`]; placing a cursor on
+    /// each of its lines.
+    ///
+    /// Columns are counted in characters, which makes blocks rectangular
+    /// for monospaced fonts.
+    SelectBlock(Point),
+    /// Add a cursor selecting the next occurrence of the current selection,
+    /// or select the word at the cursor if nothing is selected.
+    SelectNextOccurrence,
+    /// Revert the last group of edits.
+    Undo,
+    /// Reapply the last group of reverted edits.
+    Redo,
 }
 
 impl Action {
+    /// Creates an [`Action`] that moves the cursor to the given line and
+    /// column, clearing the selection.
+    pub fn move_to(position: (usize, usize)) -> Self {
+        Self::SetCursor {
+            position,
+            anchor: None,
+        }
+    }
+
+    /// Creates an [`Action`] that selects the text between the given
+    /// positions, leaving the cursor at the `end`.
+    ///
+    /// Positions are given as line and column, respectively.
+    pub fn select_range(start: (usize, usize), end: (usize, usize)) -> Self {
+        Self::SetCursor {
+            position: end,
+            anchor: Some(start),
+        }
+    }
+
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
@@ -180,6 +273,20 @@ pub enum Direction {
     Right,
 }
 
+/// A line of an [`Editor`] that is currently visible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisibleLine {
+    /// The index of the line.
+    pub index: usize,
+    /// The vertical offset of the line, relative to the top of the
+    /// [`Editor`] and taking its scroll into account.
+    pub y: f32,
+    /// The height of the line.
+    ///
+    /// A wrapped line spans multiple visual lines.
+    pub height: f32,
+}
+
 /// The cursor of an [`Editor`].
 #[derive(Debug, Clone)]
 pub enum Cursor {
@@ -47,6 +47,9 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that contains some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
     /// Operates on a custom widget.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -94,6 +97,10 @@ where
         self.as_mut().text_input(state, id);
     }
 
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        self.as_mut().text(id, bounds, text);
+    }
+
     fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
         self.as_mut().custom(state, id);
     }
@@ -176,6 +183,10 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -266,6 +277,15 @@ where
                     self.operation.text_input(state, id);
                 }
 
+                fn text(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    text: &str,
+                ) {
+                    self.operation.text(id, bounds, text);
+                }
+
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
@@ -301,6 +321,10 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -387,6 +411,10 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
         fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
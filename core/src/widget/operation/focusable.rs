@@ -26,6 +26,15 @@ pub struct Count {
     pub total: usize,
 }
 
+/// The direction the keyboard focus moves in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Towards the next focusable widget, like [`focus_next`].
+    Next,
+    /// Towards the previous focusable widget, like [`focus_previous`].
+    Previous,
+}
+
 /// Produces an [`Operation`] that focuses the widget with the given [`Id`].
 pub fn focus<T>(target: Id) -> impl Operation<T> {
     struct Focus {
@@ -17,6 +17,12 @@ pub trait Scrollable {
         bounds: Rectangle,
         content_bounds: Rectangle,
     );
+
+    /// Scrolls the widget to the item at the given `index`.
+    ///
+    /// Only widgets that lay out their contents as indexed items (e.g. a
+    /// virtualized list) know where an item is; the rest ignore it.
+    fn scroll_to_index(&mut self, _index: usize) {}
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -124,6 +130,54 @@ pub fn scroll_by<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
     ScrollBy { target, offset }
 }
 
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`],
+/// or the first scrollable widget inside of it, to the item at the given
+/// `index`.
+pub fn scroll_to_index<T>(target: Id, index: usize) -> impl Operation<T> {
+    struct ScrollToIndex {
+        target: Id,
+        index: usize,
+        is_pending: bool,
+    }
+
+    impl<T> Operation<T> for ScrollToIndex {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if Some(&self.target) == id {
+                self.is_pending = true;
+                operate_on_children(self);
+                self.is_pending = false;
+            } else {
+                operate_on_children(self);
+            }
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            _translation: Vector,
+        ) {
+            if self.is_pending || Some(&self.target) == id {
+                self.is_pending = false;
+                state.scroll_to_index(self.index);
+            }
+        }
+    }
+
+    ScrollToIndex {
+        target,
+        index,
+        is_pending: false,
+    }
+}
+
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct AbsoluteOffset {
@@ -27,6 +27,7 @@ use crate::renderer;
 use crate::text::paragraph::{self, Paragraph};
 use crate::text::{self, Fragment};
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{
     Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
     Widget,
@@ -270,6 +271,16 @@ where
         draw(renderer, defaults, layout, state.0.raw(), style, viewport);
     }
 
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.text(Some(&self.id), layout.bounds(), &self.fragment);
+    }
+
     #[cfg(feature = "a11y")]
     fn a11y_nodes(
         &self,
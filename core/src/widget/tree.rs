@@ -452,8 +452,11 @@ pub fn diff_children_custom_with_search<T>(
 }
 
 /// The identifier of some widget state.
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub struct Tag(any::TypeId);
+///
+/// Only the type of the state identifies a [`Tag`]; its name is kept for
+/// display purposes.
+#[derive(Debug, Clone, Copy)]
+pub struct Tag(any::TypeId, &'static str);
 
 impl Tag {
     /// Creates a [`Tag`] for a state of type `T`.
@@ -461,13 +464,47 @@ impl Tag {
     where
         T: 'static,
     {
-        Self(any::TypeId::of::<T>())
+        Self(any::TypeId::of::<T>(), any::type_name::<T>())
     }
 
     /// Creates a [`Tag`] for a stateless widget.
     pub fn stateless() -> Self {
         Self::of::<()>()
     }
+
+    /// Returns the name of the type of state identified by the [`Tag`].
+    ///
+    /// The name is meant for debugging purposes and its exact contents
+    /// are not guaranteed to be stable.
+    pub fn name(&self) -> &'static str {
+        self.1
+    }
+}
+
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Tag {}
+
+impl PartialOrd for Tag {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tag {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Hash for Tag {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
 }
 
 /// The internal [`State`] of a widget.
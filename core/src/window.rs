@@ -2,19 +2,23 @@
 pub mod icon;
 pub mod settings;
 
+mod decorations;
 mod event;
 mod id;
 mod level;
 mod mode;
+mod pointer_grab;
 mod position;
 mod redraw_request;
 mod user_attention;
 
+pub use decorations::Decorations;
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
 pub use level::Level;
 pub use mode::Mode;
+pub use pointer_grab::PointerGrab;
 pub use position::Position;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
@@ -0,0 +1,16 @@
+/// The decorations of a window, i.e. its title bar and borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Decorations {
+    /// The window has no decorations.
+    #[default]
+    None,
+
+    /// The decorations are drawn by the window system.
+    ServerSide,
+
+    /// The decorations are drawn by iced itself.
+    ///
+    /// This is used on Wayland when the compositor does not support
+    /// server-side decorations.
+    ClientSide,
+}
@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::Decorations;
 use crate::{Point, Size};
 
 use std::path::PathBuf;
@@ -71,4 +72,11 @@ pub enum Event {
     ///
     /// - **Wayland:** Not implemented.
     FilesHoveredLeft,
+
+    /// The [`Decorations`] of a window have changed.
+    ///
+    /// This is emitted once the decorations of a new window are known and
+    /// whenever they are toggled or the window system changes them
+    /// afterwards.
+    DecorationsChanged(Decorations),
 }
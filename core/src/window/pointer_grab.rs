@@ -0,0 +1,27 @@
+use crate::Rectangle;
+
+/// A constraint on the pointer while it is over a window.
+///
+/// ## Platform-specific
+///
+/// - **Wayland:** Regions are only honored on layer surfaces, popups and
+///   lock surfaces.
+/// - **macOS:** The pointer cannot be confined, so it is locked instead.
+/// - **Windows:** The pointer cannot be locked, so it is confined instead.
+/// - **Web / iOS / Android:** Unsupported.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PointerGrab {
+    /// The pointer moves freely.
+    #[default]
+    None,
+
+    /// The pointer cannot leave the window or, if given, the region of the
+    /// window in logical coordinates.
+    Confined(Option<Rectangle>),
+
+    /// The pointer stays where it is and only reports relative motion
+    /// through [`mouse::Event::RawMotion`].
+    ///
+    /// [`mouse::Event::RawMotion`]: crate::mouse::Event::RawMotion
+    Locked,
+}
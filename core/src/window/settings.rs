@@ -53,6 +53,12 @@ pub struct Settings {
     pub resizable: bool,
 
     /// Whether the window should have a border, a title bar, etc. or not.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Server-side decorations are negotiated with the
+    ///   compositor. If it does not support them, iced draws its own header
+    ///   bar instead.
     pub decorations: bool,
 
     /// Whether the window should be transparent.
@@ -76,6 +82,19 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// The identifier under which the geometry of the window is persisted.
+    ///
+    /// If set, the size, position and maximized state of the window are
+    /// saved when it is closed, and restored the next time a window with the
+    /// same identifier is opened—overriding [`size`] and [`position`]. The
+    /// restored geometry is clamped to the monitors currently available.
+    ///
+    /// By default, the geometry is not persisted.
+    ///
+    /// [`size`]: Self::size
+    /// [`position`]: Self::position
+    pub geometry_id: Option<String>,
 }
 
 impl Default for Settings {
@@ -94,6 +113,7 @@ impl Default for Settings {
             icon: None,
             exit_on_close_request: true,
             platform_specific: PlatformSpecific::default(),
+            geometry_id: None,
         }
     }
 }
@@ -375,7 +375,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Appearance {
     /// The [`Background`] of the progress indicator.
     pub background: Option<Background>,
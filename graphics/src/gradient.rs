@@ -3,20 +3,25 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
+use std::f32::consts::FRAC_PI_2;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a center or around it.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors from its `center` to its `radius`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +30,58 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        match self {
-            Gradient::Linear(linear) => linear.pack(),
+        let (colors, offsets) = pack_stops(self.stops());
+
+        let (direction, kind) = match self {
+            Gradient::Linear(linear) => (
+                [linear.start.x, linear.start.y, linear.end.x, linear.end.y],
+                Kind::Linear,
+            ),
+            Gradient::Radial(radial) => (
+                [
+                    radial.center.x,
+                    radial.center.y,
+                    // Shaders divide by the radius
+                    radial.radius.max(f32::EPSILON),
+                    0.0,
+                ],
+                Kind::Radial,
+            ),
+            Gradient::Conic(conic) => (
+                [conic.center.x, conic.center.y, conic.start_angle(), 0.0],
+                Kind::Conic,
+            ),
+        };
+
+        Packed {
+            colors,
+            offsets,
+            direction,
+            kind: kind as u32,
         }
     }
 }
@@ -63,19 +115,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.total_cmp(&offset),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -96,40 +136,132 @@ impl Linear {
 
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        Gradient::Linear(*self).pack()
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute position of the center of the gradient.
+    pub center: Point,
 
-        for (index, stop) in self.stops.iter().enumerate() {
-            let [r, g, b, a] =
-                color::pack(stop.map_or(Color::default(), |s| s.color))
-                    .components();
+    /// The absolute radius of the gradient.
+    pub radius: f32,
 
-            colors[index] = [
-                pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-            ];
+    /// [`ColorStop`]s from the center of the gradient to its radius.
+    pub stops: [Option<ColorStop>; 8],
+}
 
-            offsets[index] =
-                stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; 8],
         }
+    }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+        self
+    }
 
-        Packed {
-            colors,
-            offsets,
-            direction,
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
         }
+
+        self
     }
 }
 
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute position of the center of the gradient.
+    pub center: Point,
+
+    /// Where the gradient starts, clockwise from the top.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s around the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Returns the offset of the [`Conic`] gradient at the given position,
+    /// within `0.0..1.0`.
+    pub fn offset(&self, position: Point) -> f32 {
+        let angle =
+            f32::atan2(position.y - self.center.y, position.x - self.center.x);
+
+        ((angle - self.start_angle()) / std::f32::consts::TAU).rem_euclid(1.0)
+    }
+
+    /// Returns the starting angle of the [`Conic`] gradient, clockwise from
+    /// the positive x axis.
+    fn start_angle(&self) -> f32 {
+        self.angle.0 - FRAC_PI_2
+    }
+}
+
+/// The kind of a [`Packed`] gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+enum Kind {
+    Linear = 0,
+    Radial = 1,
+    Conic = 2,
+}
+
 /// Packed [`Gradient`] data for use in shader code.
 #[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
 #[repr(C)]
@@ -138,48 +270,91 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // linear: start & end, radial: center & radius, conic: center & angle
     direction: [f32; 4],
+    kind: u32,
 }
 
-/// Creates a new [`Packed`] gradient for use in shader code.
-pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
+/// Resolves a [`core::Gradient`] into an absolute [`Gradient`] that fills the
+/// given bounds.
+pub fn resolve(gradient: &core::Gradient, bounds: Rectangle) -> Gradient {
+    let position = |relative: Point| {
+        Point::new(
+            bounds.x + relative.x * bounds.width,
+            bounds.y + relative.y * bounds.height,
+        )
+    };
+
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
-
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
-
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
-
             let (start, end) = linear.angle.to_distance(&bounds);
 
-            let direction = [start.x, start.y, end.x, end.y];
+            Gradient::Linear(Linear {
+                start,
+                end,
+                stops: linear.stops,
+            })
+        }
+        core::Gradient::Radial(radial) => Gradient::Radial(Radial {
+            center: position(radial.center),
+            radius: radial.radius * bounds.width.min(bounds.height) / 2.0,
+            stops: radial.stops,
+        }),
+        core::Gradient::Conic(conic) => Gradient::Conic(Conic {
+            center: position(conic.center),
+            angle: conic.angle,
+            stops: conic.stops,
+        }),
+    }
+}
+
+/// Creates a new [`Packed`] gradient for use in shader code.
+pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
+    resolve(gradient, bounds).pack()
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.total_cmp(&offset),
+            });
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
         }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn pack_stops(stops: &[Option<ColorStop>; 8]) -> ([[u32; 2]; 8], [u32; 4]) {
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
+
+    for (index, stop) in stops.iter().enumerate() {
+        let [r, g, b, a] =
+            color::pack(stop.map_or(Color::default(), |s| s.color))
+                .components();
+
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
+
+        offsets[index] =
+            stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
     }
+
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
+
+    (colors, offsets)
 }
 
 /// Packs two f16s into one u32.
@@ -189,3 +364,39 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conic_offset_starts_at_angle_and_goes_clockwise() {
+        let conic = Conic::new(Point::ORIGIN, 0.0);
+
+        let top = conic.offset(Point::new(0.0, -1.0));
+        let right = conic.offset(Point::new(1.0, 0.0));
+        let bottom = conic.offset(Point::new(0.0, 1.0));
+        let left = conic.offset(Point::new(-1.0, 0.0));
+
+        assert!(top.abs() < 1e-5 || (top - 1.0).abs() < 1e-5);
+        assert!((right - 0.25).abs() < 1e-5);
+        assert!((bottom - 0.5).abs() < 1e-5);
+        assert!((left - 0.75).abs() < 1e-5);
+    }
+
+    #[test]
+    fn radial_radius_is_never_packed_as_zero() {
+        for radius in [0.0, -1.0, f32::NAN] {
+            let packed = Gradient::from(
+                Radial::new(Point::ORIGIN, radius).add_stop(0.0, Color::BLACK),
+            )
+            .pack();
+
+            assert!(packed.direction[2] > 0.0);
+        }
+
+        let packed = Gradient::from(Radial::new(Point::ORIGIN, 10.0)).pack();
+
+        assert_eq!(packed.direction[2], 10.0);
+    }
+}
@@ -2,6 +2,7 @@
 #[cfg(feature = "image")]
 pub use ::image as image_rs;
 
+use crate::core::background;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::Rectangle;
@@ -25,6 +26,17 @@ pub enum Image {
         /// The bounds of the image.
         bounds: Rectangle,
     },
+    /// A raster image filling some bounds, like a background.
+    Background {
+        /// The background image.
+        image: background::Image,
+
+        /// The bounds filled by the image.
+        bounds: Rectangle,
+
+        /// The border radius of the bounds, used for clipping.
+        border_radius: [f32; 4],
+    },
 }
 
 impl Image {
@@ -35,6 +47,7 @@ impl Image {
             Image::Vector { handle, bounds, .. } => {
                 bounds.rotate(handle.rotation)
             }
+            Image::Background { bounds, .. } => *bounds,
         }
     }
 }
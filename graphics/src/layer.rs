@@ -9,6 +9,9 @@ pub trait Layer: Default {
     /// Creates a new [`Layer`] with the given bounds.
     fn with_bounds(bounds: Rectangle) -> Self;
 
+    /// Returns the bounds of the [`Layer`].
+    fn bounds(&self) -> Rectangle;
+
     /// Flushes and settles any pending group of primitives in the [`Layer`].
     ///
     /// This will be called when a [`Layer`] is finished. It allows layers to efficiently
@@ -85,6 +88,26 @@ impl<T: Layer> Stack<T> {
         self.current = self.previous.pop().unwrap();
     }
 
+    /// Starts a new layer in the [`Stack`] with the same clipping region as
+    /// the current one.
+    ///
+    /// Anything drawn afterwards will be rendered on top of all the
+    /// primitives already drawn in the current layer.
+    pub fn split(&mut self) {
+        self.flush();
+
+        let bounds = self.layers[self.current].bounds();
+
+        self.current = self.active_count;
+        self.active_count += 1;
+
+        if self.current == self.layers.len() {
+            self.layers.push(T::with_bounds(bounds));
+        } else {
+            self.layers[self.current].resize(bounds);
+        }
+    }
+
     /// Pushes a new [`Transformation`] in the [`Stack`].
     ///
     /// Future drawing operations will be affected by this new [`Transformation`] until
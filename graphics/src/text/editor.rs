@@ -100,6 +100,35 @@ impl editor::Editor for Editor {
         self.buffer().lines.len()
     }
 
+    fn visible_lines(&self) -> Vec<editor::VisibleLine> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let line_height = buffer.metrics().line_height;
+        let scroll = buffer.scroll();
+
+        let mut y = -scroll.vertical;
+        let mut lines = Vec::new();
+
+        for (index, line) in buffer.lines.iter().enumerate().skip(scroll.line) {
+            if y >= internal.bounds.height {
+                break;
+            }
+
+            // Lines that have not been shaped yet span a single visual line
+            let visual_lines =
+                line.layout_opt().map_or(1, |layout| layout.len().max(1));
+
+            let height = visual_lines as f32 * line_height;
+
+            lines.push(editor::VisibleLine { index, y, height });
+
+            y += height;
+        }
+
+        lines
+    }
+
     fn selection(&self) -> Option<String> {
         self.internal().editor.copy_selection()
     }
@@ -107,134 +136,74 @@ impl editor::Editor for Editor {
     fn cursor(&self) -> editor::Cursor {
         let internal = self.internal();
 
-        let cursor = internal.editor.cursor();
+        cursor(
+            buffer_from_editor(&internal.editor),
+            internal.editor.cursor(),
+            internal.editor.selection_bounds(),
+        )
+    }
+
+    fn cursor_at(
+        &self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) -> editor::Cursor {
+        let internal = self.internal();
         let buffer = buffer_from_editor(&internal.editor);
 
-        match internal.editor.selection_bounds() {
-            Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
-
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height
-                                    - buffer.scroll().vertical,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        let position = to_cursor(buffer, position);
+        let selection = anchor
+            .map(|anchor| to_cursor(buffer, anchor))
+            .filter(|anchor| *anchor != position)
+            .map(|anchor| {
+                if (anchor.line, anchor.index) < (position.line, position.index)
+                {
+                    (anchor, position)
+                } else {
+                    (position, anchor)
+                }
+            });
 
-                Cursor::Selection(regions)
-            }
-            _ => {
-                let line_height = buffer.metrics().line_height;
+        cursor(buffer, position, selection)
+    }
 
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
+    fn cursor_position(&self) -> (usize, usize) {
+        let cursor = self.internal().editor.cursor();
 
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
+        (cursor.line, cursor.index)
+    }
 
-                let layout =
-                    line.layout_opt().expect("Line layout should be cached");
+    fn anchor_position(&self) -> Option<(usize, usize)> {
+        let editor = &self.internal().editor;
+        let cursor = editor.cursor();
 
-                let mut lines = layout.iter().enumerate();
+        let (start, end) = editor.selection_bounds()?;
 
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
+        let anchor = if start.line == cursor.line && start.index == cursor.index
+        {
+            end
+        } else {
+            start
+        };
 
-                        let is_cursor_before_start = start > cursor.index;
+        Some((anchor.line, anchor.index))
+    }
 
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
+    fn set_cursor(
+        &mut self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) {
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
 
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height
-                        - buffer.scroll().vertical,
-                ))
-            }
-        }
-    }
+        // TODO: Handle multiple strong references somehow
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
 
-    fn cursor_position(&self) -> (usize, usize) {
-        let cursor = self.internal().editor.cursor();
+        set_cursor(&mut internal.editor, position, anchor);
 
-        (cursor.line, cursor.index)
+        self.0 = Some(Arc::new(internal));
     }
 
     fn perform(&mut self, action: Action) {
@@ -414,6 +383,17 @@ impl editor::Editor for Editor {
                     cosmic_text::Action::Scroll { lines },
                 );
             }
+
+            Action::SetCursor { position, anchor } => {
+                set_cursor(editor, position, anchor);
+            }
+
+            // History and multiple cursors are kept by the owner of the editor
+            Action::Undo
+            | Action::Redo
+            | Action::AddCursor(_)
+            | Action::SelectBlock(_)
+            | Action::SelectNextOccurrence => {}
         }
 
         self.0 = Some(Arc::new(internal));
@@ -746,6 +726,163 @@ fn to_motion(motion: Motion) -> cosmic_text::Motion {
     }
 }
 
+/// Computes the [`Cursor`] of the given cosmic-text cursor and selection
+/// bounds in a [`cosmic_text::Buffer`].
+fn cursor(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+    selection: Option<(cosmic_text::Cursor, cosmic_text::Cursor)>,
+) -> Cursor {
+    match selection {
+        Some((start, end)) => {
+            let line_height = buffer.metrics().line_height;
+            let selected_lines = end.line - start.line + 1;
+
+            let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+            let regions = buffer
+                .lines
+                .iter()
+                .skip(start.line)
+                .take(selected_lines)
+                .enumerate()
+                .flat_map(|(i, line)| {
+                    highlight_line(
+                        line,
+                        if i == 0 { start.index } else { 0 },
+                        if i == selected_lines - 1 {
+                            end.index
+                        } else {
+                            line.text().len()
+                        },
+                    )
+                })
+                .enumerate()
+                .filter_map(|(visual_line, (x, width))| {
+                    if width > 0.0 {
+                        Some(Rectangle {
+                            x,
+                            width,
+                            y: (visual_line as i32 + visual_lines_offset)
+                                as f32
+                                * line_height
+                                - buffer.scroll().vertical,
+                            height: line_height,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Cursor::Selection(regions)
+        }
+        _ => {
+            let line_height = buffer.metrics().line_height;
+
+            let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+            let line = buffer
+                .lines
+                .get(cursor.line)
+                .expect("Cursor line should be present");
+
+            // Lines out of view may not be shaped yet (e.g. the carets of
+            // other selections), so there is nothing to draw for them
+            let Some(layout) = line.layout_opt() else {
+                return Cursor::Selection(Vec::new());
+            };
+
+            let mut lines = layout.iter().enumerate();
+
+            let (visual_line, offset) = lines
+                .find_map(|(i, line)| {
+                    let start = line
+                        .glyphs
+                        .first()
+                        .map(|glyph| glyph.start)
+                        .unwrap_or(0);
+                    let end =
+                        line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+                    let is_cursor_before_start = start > cursor.index;
+
+                    let is_cursor_before_end = match cursor.affinity {
+                        cosmic_text::Affinity::Before => cursor.index <= end,
+                        cosmic_text::Affinity::After => cursor.index < end,
+                    };
+
+                    if is_cursor_before_start {
+                        // Sometimes, the glyph we are looking for is right
+                        // between lines. This can happen when a line wraps
+                        // on a space.
+                        // In that case, we can assume the cursor is at the
+                        // end of the previous line.
+                        // i is guaranteed to be > 0 because `start` is always
+                        // 0 for the first line, so there is no way for the
+                        // cursor to be before it.
+                        Some((i - 1, layout[i - 1].w))
+                    } else if is_cursor_before_end {
+                        let offset = line
+                            .glyphs
+                            .iter()
+                            .take_while(|glyph| cursor.index > glyph.start)
+                            .map(|glyph| glyph.w)
+                            .sum();
+
+                        Some((i, offset))
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or((
+                    layout.len().saturating_sub(1),
+                    layout.last().map(|line| line.w).unwrap_or(0.0),
+                ));
+
+            Cursor::Caret(Point::new(
+                offset,
+                (visual_lines_offset + visual_line as i32) as f32 * line_height
+                    - buffer.scroll().vertical,
+            ))
+        }
+    }
+}
+
+fn set_cursor(
+    editor: &mut cosmic_text::Editor<'static>,
+    position: (usize, usize),
+    anchor: Option<(usize, usize)>,
+) {
+    let buffer = buffer_from_editor(editor);
+
+    let cursor = to_cursor(buffer, position);
+    let selection = match anchor.map(|anchor| to_cursor(buffer, anchor)) {
+        Some(anchor) if anchor != cursor => {
+            cosmic_text::Selection::Normal(anchor)
+        }
+        _ => cosmic_text::Selection::None,
+    };
+
+    editor.set_cursor(cursor);
+    editor.set_selection(selection);
+}
+
+/// Converts a line and column into a [`cosmic_text::Cursor`], clamping it
+/// to the contents of the buffer.
+fn to_cursor(
+    buffer: &cosmic_text::Buffer,
+    (line, index): (usize, usize),
+) -> cosmic_text::Cursor {
+    let line = line.min(buffer.lines.len().saturating_sub(1));
+    let index = buffer
+        .lines
+        .get(line)
+        .map_or(0, |text| index.min(text.text().len()));
+
+    cosmic_text::Cursor::new(line, index)
+}
+
 fn buffer_from_editor<'a, 'b>(
     editor: &'a impl cosmic_text::Edit<'b>,
 ) -> &'a cosmic_text::Buffer
@@ -771,3 +908,109 @@ where
         cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use editor::{Editor as _, VisibleLine};
+
+    const ICON: char = '\u{f00c}';
+
+    /// Creates an [`Editor`] one pixel wide, so every glyph of a line
+    /// wraps into its own visual line of 10 pixels.
+    fn wrapped(text: &str, height: f32) -> Editor {
+        let mut editor = Editor::with_text(text);
+
+        // Every line is shaped first, so none of them falls back to a
+        // single visual line once the editor shrinks
+        for height in [1000.0, height] {
+            editor.update(
+                Size::new(1.0, height),
+                Font::with_name("Iced-Icons"),
+                Pixels(10.0),
+                LineHeight::Absolute(Pixels(10.0)),
+                Wrapping::Glyph,
+                &mut highlighter::PlainText,
+            );
+        }
+
+        editor
+    }
+
+    fn scroll(editor: &mut Editor, line: usize, vertical: f32) {
+        let internal = Arc::get_mut(editor.0.as_mut().unwrap()).unwrap();
+
+        buffer_mut_from_editor(&mut internal.editor)
+            .set_scroll(cosmic_text::Scroll::new(line, vertical, 0.0));
+    }
+
+    fn line(index: usize, y: f32, height: f32) -> VisibleLine {
+        VisibleLine { index, y, height }
+    }
+
+    #[test]
+    fn visible_lines_span_their_visual_lines() {
+        let editor = wrapped(
+            &format!("{ICON}{ICON}{ICON}\n{ICON}\n{ICON}{ICON}"),
+            100.0,
+        );
+
+        assert_eq!(
+            editor.visible_lines(),
+            vec![line(0, 0.0, 30.0), line(1, 30.0, 10.0), line(2, 40.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn visible_lines_follow_scrolling() {
+        let mut editor = wrapped(
+            &format!("{ICON}{ICON}{ICON}\n{ICON}\n{ICON}{ICON}\n{ICON}"),
+            25.0,
+        );
+
+        // Scrolled into the middle of a wrapped line
+        scroll(&mut editor, 0, 20.0);
+
+        assert_eq!(
+            editor.visible_lines(),
+            vec![
+                line(0, -20.0, 30.0),
+                line(1, 10.0, 10.0),
+                line(2, 20.0, 20.0)
+            ]
+        );
+
+        // Scrolled past the first line, the last one is out of bounds
+        scroll(&mut editor, 1, 0.0);
+
+        assert_eq!(
+            editor.visible_lines(),
+            vec![line(1, 0.0, 10.0), line(2, 10.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn cursor_at_unshaped_line_is_empty() {
+        let mut editor = Editor::with_text(&"line\n".repeat(100));
+
+        editor.update(
+            Size::new(100.0, 10.0),
+            Font::default(),
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::Word,
+            &mut highlighter::PlainText,
+        );
+
+        assert!(matches!(
+            editor.cursor_at((0, 2), None),
+            editor::Cursor::Caret(_)
+        ));
+
+        assert!(matches!(
+            editor.cursor_at((99, 2), None),
+            editor::Cursor::Selection(regions) if regions.is_empty()
+        ));
+    }
+}
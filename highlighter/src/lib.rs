@@ -3,30 +3,112 @@ use iced_core as core;
 
 use crate::core::font::{self, Font};
 use crate::core::text::highlighter::{self, Format};
+use crate::core::theme::Palette;
 use crate::core::Color;
 
 use once_cell::sync::Lazy;
+use std::fmt;
+use std::io;
 use std::ops::Range;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock, Weak};
 use syntect::highlighting;
 use syntect::parsing;
 
-static SYNTAXES: Lazy<parsing::SyntaxSet> =
-    Lazy::new(parsing::SyntaxSet::load_defaults_nonewlines);
+static SYNTAXES: Lazy<RwLock<Arc<parsing::SyntaxSet>>> = Lazy::new(|| {
+    RwLock::new(Arc::new(parsing::SyntaxSet::load_defaults_nonewlines()))
+});
 
 static THEMES: Lazy<highlighting::ThemeSet> =
     Lazy::new(highlighting::ThemeSet::load_defaults);
 
+static PALETTE_THEMES: Lazy<Mutex<Vec<(Palette, Weak<highlighting::Theme>)>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
 const LINES_PER_SNAPSHOT: usize = 50;
 
+/// Registers a syntax definition in the `.sublime-syntax` format.
+///
+/// Once registered, the syntax can be selected with the [`Settings::token`]
+/// of a [`Highlighter`] by using its name or any of its file extensions.
+/// Existing highlighters keep using the syntaxes they were created with.
+pub fn register_syntax(definition: &str) -> Result<(), Error> {
+    add_syntax(definition, None)
+}
+
+/// Registers the syntax definition in the `.sublime-syntax` file at the
+/// given path.
+///
+/// If the definition has no name, the name of the file is used instead.
+///
+/// See [`register_syntax`] for more details.
+pub fn register_syntax_file(path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let definition = std::fs::read_to_string(path)?;
+
+    add_syntax(
+        &definition,
+        path.file_stem().and_then(std::ffi::OsStr::to_str),
+    )
+}
+
+fn add_syntax(
+    definition: &str,
+    fallback_name: Option<&str>,
+) -> Result<(), Error> {
+    let definition = parsing::SyntaxDefinition::load_from_str(
+        definition,
+        false,
+        fallback_name,
+    )
+    .map_err(|error| Error::InvalidSyntax(Arc::new(error)))?;
+
+    let mut syntaxes = SYNTAXES.write().expect("Write syntaxes");
+
+    let mut builder = parsing::SyntaxSet::clone(&syntaxes).into_builder();
+    builder.add(definition);
+
+    *syntaxes = Arc::new(builder.build());
+
+    Ok(())
+}
+
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: usize,
+    styles: Styles,
+    theme: Theme,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
 }
 
+impl Highlighter {
+    /// Finds the syntax for the given token in the registered syntaxes,
+    /// falling back to plain text.
+    fn find_syntax(token: &str) -> (Arc<parsing::SyntaxSet>, usize) {
+        let syntaxes = Arc::clone(&SYNTAXES.read().expect("Read syntaxes"));
+
+        let syntax = syntaxes
+            .find_syntax_by_token(token)
+            .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+
+        let index = syntaxes
+            .syntaxes()
+            .iter()
+            .position(|candidate| std::ptr::eq(candidate, syntax))
+            .unwrap_or_default();
+
+        (syntaxes, index)
+    }
+
+    fn syntax(&self) -> &parsing::SyntaxReference {
+        &self.syntaxes.syntaxes()[self.syntax]
+    }
+}
+
 impl highlighter::Highlighter for Highlighter {
     type Settings = Settings;
     type Highlight = Highlight;
@@ -35,33 +117,28 @@ impl highlighter::Highlighter for Highlighter {
         Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        let (syntaxes, syntax) = Self::find_syntax(&settings.token);
 
-        let highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[settings.theme.key()],
-        );
-
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntaxes.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            syntaxes,
             syntax,
-            highlighter,
+            styles: Styles::new(&settings.theme),
+            theme: settings.theme.clone(),
             caches: vec![(parser, stack)],
             current_line: 0,
         }
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        (self.syntaxes, self.syntax) = Self::find_syntax(&new_settings.token);
 
-        self.highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[new_settings.theme.key()],
-        );
+        if self.theme != new_settings.theme {
+            self.styles = Styles::new(&new_settings.theme);
+            self.theme = new_settings.theme.clone();
+        }
 
         // Restart the highlighter
         self.change_line(0);
@@ -81,7 +158,7 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last().cloned().unwrap_or_else(|| {
                 (
-                    parsing::ParseState::new(self.syntax),
+                    parsing::ParseState::new(self.syntax()),
                     parsing::ScopeStack::new(),
                 )
             });
@@ -102,9 +179,9 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser.parse_line(line, &self.syntaxes).unwrap_or_default();
 
-        let highlighter = &self.highlighter;
+        let highlighter = &self.styles.0;
 
         Box::new(
             ScopeRangeIterator {
@@ -135,6 +212,30 @@ impl highlighter::Highlighter for Highlighter {
     }
 }
 
+/// The syntect highlighter of a [`Theme`].
+///
+/// Building it resolves every scope selector of the theme, so it is only
+/// done when the [`Theme`] of a [`Highlighter`] changes.
+struct Styles(highlighting::Highlighter<'static>);
+
+impl Styles {
+    fn new(theme: &Theme) -> Self {
+        let theme: *const highlighting::Theme = theme.highlighting();
+
+        // SAFETY: built-in themes are static and custom themes live behind
+        // an `Arc`, which the `Theme` stored next to these `Styles` keeps
+        // alive and in place. `Styles` are declared before their `Theme`,
+        // so they are dropped first and replaced whenever it changes.
+        Self(highlighting::Highlighter::new(unsafe { &*theme }))
+    }
+}
+
+impl fmt::Debug for Styles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Styles").finish_non_exhaustive()
+    }
+}
+
 /// The settings of a [`Highlighter`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Settings {
@@ -207,13 +308,15 @@ impl Highlight {
 
 /// A highlighting theme.
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Theme {
     SolarizedDark,
     Base16Mocha,
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A theme loaded at runtime.
+    Custom(Custom),
 }
 
 impl Theme {
@@ -226,40 +329,237 @@ impl Theme {
         Self::InspiredGitHub,
     ];
 
+    /// Loads a [`Theme`] from the contents of a `.tmTheme` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let theme = highlighting::ThemeSet::load_from_reader(
+            &mut io::Cursor::new(bytes),
+        )
+        .map_err(|error| Error::InvalidTheme(Arc::new(error)))?;
+
+        Ok(Self::Custom(Custom::new(theme)))
+    }
+
+    /// Loads a [`Theme`] from the `.tmTheme` file at the given path.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Creates a [`Theme`] with the colors of the given [`Palette`], so
+    /// highlighted code matches the rest of an application.
+    ///
+    /// Themes created from the same [`Palette`] are shared while any of
+    /// them is alive, so they compare as equal.
+    pub fn from_palette(palette: Palette) -> Self {
+        let mut themes = PALETTE_THEMES.lock().expect("Lock palette themes");
+
+        themes.retain(|(_, theme)| theme.strong_count() > 0);
+
+        if let Some(theme) = themes
+            .iter()
+            .find(|(candidate, _)| *candidate == palette)
+            .and_then(|(_, theme)| theme.upgrade())
+        {
+            return Self::Custom(Custom::from_arc(theme));
+        }
+
+        let custom = Custom::new(palette_theme(palette));
+        themes.push((palette, Arc::downgrade(&custom.theme)));
+
+        Self::Custom(custom)
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
-    pub fn is_dark(self) -> bool {
+    pub fn is_dark(&self) -> bool {
         match self {
             Self::SolarizedDark
             | Self::Base16Mocha
             | Self::Base16Ocean
             | Self::Base16Eighties => true,
             Self::InspiredGitHub => false,
+            Self::Custom(custom) => custom.is_dark,
         }
     }
 
-    fn key(self) -> &'static str {
-        match self {
+    fn highlighting(&self) -> &highlighting::Theme {
+        let key = match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
-        }
+            Theme::Custom(custom) => return &custom.theme,
+        };
+
+        &THEMES.themes[key]
     }
 }
 
-impl std::fmt::Display for Theme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Theme::SolarizedDark => write!(f, "Solarized Dark"),
             Theme::Base16Mocha => write!(f, "Mocha"),
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Custom(custom) => {
+                write!(
+                    f,
+                    "{}",
+                    custom.theme.name.as_deref().unwrap_or("Custom")
+                )
+            }
         }
     }
 }
 
+/// A highlighting [`Theme`] loaded at runtime.
+#[derive(Clone)]
+pub struct Custom {
+    theme: Arc<highlighting::Theme>,
+    is_dark: bool,
+}
+
+impl Custom {
+    fn new(theme: highlighting::Theme) -> Self {
+        Self::from_arc(Arc::new(theme))
+    }
+
+    fn from_arc(theme: Arc<highlighting::Theme>) -> Self {
+        let is_dark = theme.settings.background.is_some_and(|background| {
+            let luminance = 0.2126 * f32::from(background.r)
+                + 0.7152 * f32::from(background.g)
+                + 0.0722 * f32::from(background.b);
+
+            luminance < 128.0
+        });
+
+        Self { theme, is_dark }
+    }
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.theme, &other.theme)
+    }
+}
+
+impl Eq for Custom {}
+
+impl fmt::Debug for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Custom")
+            .field("name", &self.theme.name)
+            .field("is_dark", &self.is_dark)
+            .finish()
+    }
+}
+
+/// An error produced when loading syntaxes or themes.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// An IO operation failed.
+    #[error("an IO operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// The syntax definition is invalid.
+    #[error("the syntax definition is invalid: {0}")]
+    InvalidSyntax(Arc<parsing::ParseSyntaxError>),
+
+    /// The theme is invalid.
+    #[error("the theme is invalid: {0}")]
+    InvalidTheme(Arc<syntect::LoadingError>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+/// Builds a syntect theme with the colors of a [`Palette`].
+fn palette_theme(palette: Palette) -> highlighting::Theme {
+    let color = |color: Color| {
+        let [r, g, b, a] = color.into_rgba8();
+
+        highlighting::Color { r, g, b, a }
+    };
+
+    let item =
+        |scope: &str, foreground: Color, font_style| highlighting::ThemeItem {
+            scope: highlighting::ScopeSelectors::from_str(scope)
+                .expect("Scope selector must be valid"),
+            style: highlighting::StyleModifier {
+                foreground: Some(color(foreground)),
+                background: None,
+                font_style,
+            },
+        };
+
+    let comment = mix(palette.text, palette.background, 0.45);
+    let constant = mix(palette.primary, palette.danger, 0.5);
+    let kind = mix(palette.primary, palette.success, 0.5);
+
+    highlighting::Theme {
+        name: Some(String::from("Palette")),
+        author: None,
+        settings: highlighting::ThemeSettings {
+            foreground: Some(color(palette.text)),
+            background: Some(color(palette.background)),
+            caret: Some(color(palette.primary)),
+            selection: Some(color(Color {
+                a: 0.3,
+                ..palette.primary
+            })),
+            ..highlighting::ThemeSettings::default()
+        },
+        scopes: vec![
+            item(
+                "comment, punctuation.definition.comment",
+                comment,
+                Some(highlighting::FontStyle::ITALIC),
+            ),
+            item("keyword, storage", palette.primary, None),
+            item("string, constant.character", palette.success, None),
+            item("constant.numeric, constant.language", constant, None),
+            item(
+                "entity.name.function, support.function",
+                palette.primary,
+                None,
+            ),
+            item(
+                "entity.name.type, entity.name.class, support.type",
+                kind,
+                None,
+            ),
+            item("markup.heading", palette.primary, None),
+            item(
+                "markup.bold",
+                palette.text,
+                Some(highlighting::FontStyle::BOLD),
+            ),
+            item(
+                "markup.italic",
+                palette.text,
+                Some(highlighting::FontStyle::ITALIC),
+            ),
+            item("invalid", palette.danger, None),
+        ],
+    }
+}
+
+fn mix(a: Color, b: Color, factor: f32) -> Color {
+    let b_amount = factor.clamp(0.0, 1.0);
+    let a_amount = 1.0 - b_amount;
+
+    Color::from_rgba(
+        a.r * a_amount + b.r * b_amount,
+        a.g * a_amount + b.g * b_amount,
+        a.b * a_amount + b.b * b_amount,
+        a.a * a_amount + b.a * b_amount,
+    )
+}
+
 struct ScopeRangeIterator {
     ops: Vec<(usize, parsing::ScopeStackOp)>,
     line_length: usize,
@@ -294,3 +594,91 @@ impl Iterator for ScopeRangeIterator {
         Some((range, op))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::highlighter::Highlighter as _;
+
+    #[test]
+    fn registered_syntax_is_selected_by_token() {
+        register_syntax(
+            "\
+name: Greeting
+file_extensions: [greet]
+scope: source.greet
+contexts:
+  main:
+    - match: '\\bhello\\b'
+      scope: keyword.greet
+",
+        )
+        .expect("Register syntax");
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme: Theme::from_palette(Palette::DARK),
+            token: String::from("greet"),
+        });
+
+        assert_eq!(highlighter.syntax().name, "Greeting");
+
+        let keyword = highlighter
+            .highlight_line("hello world")
+            .find(|(range, _)| *range == (0..5))
+            .and_then(|(_, highlight)| highlight.color())
+            .map(Color::into_rgba8);
+
+        assert_eq!(keyword, Some(Palette::DARK.primary.into_rgba8()));
+    }
+
+    #[test]
+    fn updating_theme_changes_highlights() {
+        let keyword = |highlighter: &mut Highlighter| {
+            highlighter
+                .highlight_line("fn main() {}")
+                .find(|(range, _)| *range == (0..2))
+                .and_then(|(_, highlight)| highlight.color())
+                .map(Color::into_rgba8)
+        };
+
+        let red = Palette {
+            primary: Color::from_rgb(1.0, 0.0, 0.0),
+            ..Palette::DARK
+        };
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme: Theme::from_palette(Palette::DARK),
+            token: String::from("rs"),
+        });
+
+        assert_eq!(
+            keyword(&mut highlighter),
+            Some(Palette::DARK.primary.into_rgba8())
+        );
+
+        highlighter.update(&Settings {
+            theme: Theme::from_palette(red),
+            token: String::from("rs"),
+        });
+
+        assert_eq!(keyword(&mut highlighter), Some(red.primary.into_rgba8()));
+    }
+
+    #[test]
+    fn palette_themes_are_shared_while_alive() {
+        let theme = Theme::from_palette(Palette::LIGHT);
+
+        assert_eq!(theme, Theme::from_palette(Palette::LIGHT));
+        assert_ne!(theme, Theme::from_palette(Palette::DARK));
+
+        drop(theme);
+
+        let themes = PALETTE_THEMES.lock().expect("Lock palette themes");
+
+        assert!(themes
+            .iter()
+            .filter(|(palette, _)| *palette == Palette::LIGHT)
+            .all(|(_, theme)| theme.strong_count() == 0));
+    }
+}
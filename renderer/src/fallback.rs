@@ -70,6 +70,39 @@ where
     }
 }
 
+/// A [`Renderer`] is created headlessly by using its fallback strategy, since
+/// the primary option may need a window to be initialized.
+impl<A, B> renderer::Headless for Renderer<A, B>
+where
+    A: core::Renderer,
+    B: renderer::Headless,
+{
+    fn new(default_font: core::Font, default_text_size: core::Pixels) -> Self {
+        Self::Secondary(B::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        match self {
+            Self::Primary(_) => {
+                log::warn!(
+                    "Screenshots are only supported by the secondary \
+                    renderer in headless mode"
+                );
+
+                Vec::new()
+            }
+            Self::Secondary(renderer) => {
+                renderer.screenshot(size, scale_factor, background_color)
+            }
+        }
+    }
+}
+
 impl<A, B> core::text::Renderer for Renderer<A, B>
 where
     A: core::text::Renderer,
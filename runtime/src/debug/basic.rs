@@ -1,7 +1,8 @@
 #![allow(missing_docs)]
-use crate::core::time;
+use crate::core::{mouse, renderer, time};
+use crate::inspector::Inspection;
 
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
@@ -31,6 +32,11 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    is_inspecting: bool,
+    inspection: Option<Inspection>,
+    hovered: Option<Vec<usize>>,
+    collapsed: HashSet<Vec<usize>>,
 }
 
 impl Debug {
@@ -63,6 +69,11 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            is_inspecting: false,
+            inspection: None,
+            hovered: None,
+            collapsed: HashSet::new(),
         }
     }
 
@@ -136,13 +147,86 @@ impl Debug {
         self.message_count += 1;
     }
 
-    pub fn overlay(&self) -> Vec<String> {
-        if !self.is_enabled {
-            return Vec::new();
+    pub fn is_inspecting(&self) -> bool {
+        self.is_inspecting
+    }
+
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+
+        if !self.is_inspecting {
+            self.inspection = None;
+            self.hovered = None;
+        }
+    }
+
+    /// Collapses or expands the innermost widget with children under the
+    /// cursor in the inspector tree.
+    pub fn toggle_collapsed(&mut self) {
+        let (Some(inspection), Some(hovered)) =
+            (&self.inspection, &self.hovered)
+        else {
+            return;
+        };
+
+        let Some(path) = (0..=hovered.len())
+            .rev()
+            .map(|length| &hovered[..length])
+            .find(|path| {
+                inspection
+                    .get(path)
+                    .is_some_and(|node| !node.children.is_empty())
+            })
+        else {
+            return;
+        };
+
+        if !self.collapsed.remove(path) {
+            let _ = self.collapsed.insert(path.to_vec());
         }
+    }
+
+    pub fn inspect(&mut self, inspection: Inspection, cursor: mouse::Cursor) {
+        self.hovered = cursor
+            .position()
+            .and_then(|position| inspection.path_at(position));
+        self.inspection = Some(inspection);
+    }
 
+    pub fn draw_inspector<Renderer: renderer::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+    ) {
+        let Some(inspection) = &self.inspection else {
+            return;
+        };
+
+        if let Some(hovered) = self
+            .hovered
+            .as_deref()
+            .and_then(|path| inspection.get(path))
+        {
+            renderer.with_layer(inspection.bounds, |renderer| {
+                hovered.highlight(renderer);
+            });
+        }
+    }
+
+    pub fn overlay(&self) -> Vec<String> {
         let mut lines = Vec::new();
 
+        if self.is_enabled {
+            self.timings(&mut lines);
+        }
+
+        if self.is_inspecting {
+            self.inspector(&mut lines);
+        }
+
+        lines
+    }
+
+    fn timings(&self, lines: &mut Vec<String>) {
         fn key_value<T: std::fmt::Debug>(key: &str, value: T) -> String {
             format!("{key} {value:?}")
         }
@@ -175,8 +259,69 @@ impl Debug {
                 format!("    {msg:.100}...")
             }
         }));
+    }
 
-        lines
+    fn inspector(&self, lines: &mut Vec<String>) {
+        lines.push(String::from(
+            "Inspector (Shift+F12 to close, Ctrl+F12 to collapse):",
+        ));
+
+        let Some(inspection) = &self.inspection else {
+            return;
+        };
+
+        if let Some(hovered) = self
+            .hovered
+            .as_deref()
+            .and_then(|path| inspection.get(path))
+        {
+            lines.push(format!("Hovered: {}", hovered.describe()));
+            lines.push(format!("Padding: {:?}", hovered.padding()));
+        }
+
+        let mut path = Vec::new();
+        self.tree(inspection, &mut path, lines);
+    }
+
+    fn tree(
+        &self,
+        inspection: &Inspection,
+        path: &mut Vec<usize>,
+        lines: &mut Vec<String>,
+    ) {
+        let is_collapsed = self.collapsed.contains(path);
+        let is_hovered = self.hovered.as_ref().is_some_and(|hovered| {
+            if is_collapsed {
+                hovered.starts_with(path)
+            } else {
+                hovered == path
+            }
+        });
+
+        let marker = if inspection.children.is_empty() {
+            ' '
+        } else if is_collapsed {
+            '+'
+        } else {
+            '-'
+        };
+
+        lines.push(format!(
+            "{}{}{marker} {}",
+            "  ".repeat(path.len()),
+            if is_hovered { '>' } else { ' ' },
+            inspection.describe(),
+        ));
+
+        if is_collapsed {
+            return;
+        }
+
+        for (i, child) in inspection.children.iter().enumerate() {
+            path.push(i);
+            self.tree(child, path, lines);
+            let _ = path.pop();
+        }
     }
 }
 
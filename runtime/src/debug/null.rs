@@ -1,4 +1,7 @@
 #![allow(missing_docs)]
+use crate::core::{mouse, renderer};
+use crate::inspector::Inspection;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -41,6 +44,23 @@ impl Debug {
     ) {
     }
 
+    pub fn is_inspecting(&self) -> bool {
+        false
+    }
+
+    pub fn toggle_inspector(&mut self) {}
+
+    pub fn toggle_collapsed(&mut self) {}
+
+    pub fn inspect(&mut self, _inspection: Inspection, _cursor: mouse::Cursor) {
+    }
+
+    pub fn draw_inspector<Renderer: renderer::Renderer>(
+        &self,
+        _renderer: &mut Renderer,
+    ) {
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
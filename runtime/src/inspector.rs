@@ -0,0 +1,293 @@
+//! Inspect the widget hierarchy of a [`UserInterface`].
+//!
+//! [`UserInterface`]: crate::UserInterface
+use crate::core::renderer;
+use crate::core::widget::{self, tree};
+use crate::core::{
+    Background, Border, Color, Layout, Padding, Point, Rectangle,
+};
+
+/// A snapshot of a widget and its descendants, pairing each
+/// [`widget::Tree`] with its [`layout::Node`].
+///
+/// [`layout::Node`]: crate::core::layout::Node
+#[derive(Debug, Clone)]
+pub struct Inspection {
+    /// The [`Tag`] of the state of the widget, if it has a [`widget::Tree`].
+    ///
+    /// [`Tag`]: tree::Tag
+    pub tag: Option<tree::Tag>,
+    /// The [`widget::Id`] of the widget, if any.
+    pub id: Option<widget::Id>,
+    /// The absolute layout bounds of the widget.
+    pub bounds: Rectangle,
+    /// The [`Inspection`] of the children of the widget.
+    pub children: Vec<Inspection>,
+}
+
+impl Inspection {
+    /// Creates a new [`Inspection`] of the given [`widget::Tree`] and its
+    /// [`Layout`].
+    ///
+    /// Widgets may lay out a different number of children than they keep
+    /// state for, in which case there is no way to tell which state belongs
+    /// to which node. The children of such widgets are inspected without a
+    /// [`Tag`](tree::Tag).
+    pub fn new(tree: Option<&widget::Tree>, layout: Layout<'_>) -> Self {
+        let children = tree
+            .map(|tree| tree.children.as_slice())
+            .filter(|children| children.len() == layout.children().count());
+
+        Self {
+            tag: tree.map(|tree| tree.tag),
+            id: tree.and_then(|tree| tree.id.clone()),
+            bounds: layout.bounds(),
+            children: layout
+                .children()
+                .enumerate()
+                .map(|(i, layout)| {
+                    Self::new(
+                        children.and_then(|children| children.get(i)),
+                        layout,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the [`Inspection`] at the given path of child indices.
+    pub fn get(&self, path: &[usize]) -> Option<&Self> {
+        path.iter()
+            .try_fold(self, |inspection, &i| inspection.children.get(i))
+    }
+
+    /// Returns the path of the innermost widget containing the given point.
+    pub fn path_at(&self, point: Point) -> Option<Vec<usize>> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let mut current = self;
+
+        // Later children are drawn on top, so they take precedence.
+        while let Some((i, child)) = current
+            .children
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, child)| child.bounds.contains(point))
+        {
+            path.push(i);
+            current = child;
+        }
+
+        Some(path)
+    }
+
+    /// Returns the bounds enclosing all the children of the widget.
+    pub fn content_bounds(&self) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .map(|child| child.bounds)
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Returns the space between the bounds of the widget and its content.
+    ///
+    /// Widgets without children have no discernible padding.
+    pub fn padding(&self) -> Padding {
+        let Some(content) = self.content_bounds() else {
+            return Padding::ZERO;
+        };
+
+        Padding {
+            top: (content.y - self.bounds.y).max(0.0),
+            right: (self.bounds.x + self.bounds.width
+                - content.x
+                - content.width)
+                .max(0.0),
+            bottom: (self.bounds.y + self.bounds.height
+                - content.y
+                - content.height)
+                .max(0.0),
+            left: (content.x - self.bounds.x).max(0.0),
+        }
+    }
+
+    /// Returns a short, human-readable name of the widget.
+    pub fn name(&self) -> String {
+        match self.tag {
+            Some(tag) if tag != tree::Tag::stateless() => {
+                short_name(tag.name())
+            }
+            Some(_) => String::from("stateless"),
+            None => String::from("unknown"),
+        }
+    }
+
+    /// Describes the widget in a single line.
+    pub fn describe(&self) -> String {
+        let mut description = format!(
+            "{} {}x{} @ ({}, {})",
+            self.name(),
+            self.bounds.width,
+            self.bounds.height,
+            self.bounds.x,
+            self.bounds.y,
+        );
+
+        if let Some(id) = &self.id {
+            description.push_str(&format!(" {id:?}"));
+        }
+
+        description
+    }
+
+    /// Draws the bounds and the padding of the widget.
+    pub fn highlight<Renderer>(&self, renderer: &mut Renderer)
+    where
+        Renderer: renderer::Renderer,
+    {
+        const BOUNDS: Color = Color::from_rgb(0.2, 0.5, 1.0);
+        const PADDING: Color = Color::from_rgba(0.3, 0.8, 0.4, 0.35);
+        const CONTENT: Color = Color::from_rgba(0.2, 0.5, 1.0, 0.2);
+
+        let Padding {
+            top,
+            right,
+            bottom,
+            left,
+        } = self.padding();
+
+        let content = self.bounds.shrink(self.padding());
+
+        let padding = [
+            Rectangle {
+                height: top,
+                ..self.bounds
+            },
+            Rectangle {
+                y: self.bounds.y + self.bounds.height - bottom,
+                height: bottom,
+                ..self.bounds
+            },
+            Rectangle {
+                y: content.y,
+                width: left,
+                height: content.height,
+                ..self.bounds
+            },
+            Rectangle {
+                x: self.bounds.x + self.bounds.width - right,
+                y: content.y,
+                width: right,
+                height: content.height,
+            },
+        ];
+
+        for bounds in padding {
+            if bounds.width > 0.0 && bounds.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    PADDING,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: content,
+                ..renderer::Quad::default()
+            },
+            CONTENT,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.bounds,
+                border: Border {
+                    color: BOUNDS,
+                    width: 1.0,
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+    }
+}
+
+/// Strips the module paths and generic parameters of a type name.
+fn short_name(name: &str) -> String {
+    let name = name.split('<').next().unwrap_or(name);
+    let mut segments = name.rsplit("::");
+
+    match (segments.next(), segments.next()) {
+        (Some(ty), Some(module)) => format!("{module}::{ty}"),
+        (Some(ty), None) => ty.to_owned(),
+        _ => name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::layout;
+    use crate::core::Size;
+
+    #[test]
+    fn hovered_widget_and_padding_are_found() {
+        let node = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![layout::Node::new(Size::new(80.0, 60.0))
+                .move_to(Point::new(10.0, 20.0))],
+        );
+
+        let inspection = Inspection::new(None, Layout::new(&node));
+
+        assert_eq!(inspection.path_at(Point::new(50.0, 50.0)), Some(vec![0]));
+        assert_eq!(inspection.path_at(Point::new(5.0, 5.0)), Some(vec![]));
+        assert_eq!(inspection.path_at(Point::new(150.0, 5.0)), None);
+        assert_eq!(
+            inspection.padding(),
+            Padding {
+                top: 20.0,
+                right: 10.0,
+                bottom: 20.0,
+                left: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn mismatched_children_are_unknown() {
+        let node = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![
+                layout::Node::new(Size::new(50.0, 100.0)),
+                layout::Node::new(Size::new(50.0, 100.0))
+                    .move_to(Point::new(50.0, 0.0)),
+            ],
+        );
+
+        let mut tree = widget::Tree::empty();
+        tree.children.push(widget::Tree::empty());
+
+        let inspection = Inspection::new(Some(&tree), Layout::new(&node));
+
+        assert_eq!(inspection.tag, Some(tree.tag));
+        assert!(inspection.children.iter().all(|child| child.tag.is_none()));
+        assert_eq!(inspection.children[0].name(), "unknown");
+
+        tree.children.push(widget::Tree::empty());
+
+        let inspection = Inspection::new(Some(&tree), Layout::new(&node));
+
+        assert!(inspection.children.iter().all(|child| child.tag.is_some()));
+    }
+}
@@ -12,10 +12,12 @@
 pub mod clipboard;
 pub mod dnd;
 pub mod font;
+pub mod inspector;
 pub mod keyboard;
 pub mod overlay;
 pub mod platform_specific;
 pub mod program;
+pub mod record;
 pub mod system;
 pub mod task;
 pub mod user_interface;
@@ -156,16 +158,78 @@ pub struct Appearance {
     pub icon_color: iced_core::Color,
 }
 
+/// The appearance of the header bar a [`Program`] draws for windows that
+/// the window system does not decorate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeaderAppearance {
+    /// The background [`iced_core::Color`] of the header bar.
+    pub background_color: iced_core::Color,
+
+    /// The [`iced_core::Color`] of the title and the button icons.
+    pub text_color: iced_core::Color,
+
+    /// The [`iced_core::Color`] of the line below the header bar.
+    pub border_color: iced_core::Color,
+
+    /// The background [`iced_core::Color`] of a hovered button.
+    pub hovered_button_color: iced_core::Color,
+
+    /// The background [`iced_core::Color`] of the hovered close button.
+    pub hovered_close_color: iced_core::Color,
+
+    /// The icon [`iced_core::Color`] of the hovered close button.
+    pub hovered_close_icon_color: iced_core::Color,
+}
+
 /// The default style of a [`Program`].
 pub trait DefaultStyle {
     /// Returns the default style of a [`Program`].
     fn default_style(&self) -> Appearance;
+
+    /// Returns the style of the header bar of windows that the window
+    /// system does not decorate.
+    ///
+    /// By default, it is derived from the [`DefaultStyle::default_style`].
+    fn header_style(&self) -> HeaderAppearance {
+        default_header(self.default_style())
+    }
 }
 
 impl DefaultStyle for iced_core::Theme {
     fn default_style(&self) -> Appearance {
         default(self)
     }
+
+    fn header_style(&self) -> HeaderAppearance {
+        let palette = self.extended_palette();
+
+        HeaderAppearance {
+            hovered_close_color: palette.danger.base.color,
+            hovered_close_icon_color: palette.danger.base.text,
+            ..default_header(default(self))
+        }
+    }
+}
+
+/// The [`HeaderAppearance`] derived from the given [`Appearance`].
+pub fn default_header(appearance: Appearance) -> HeaderAppearance {
+    let text_color = appearance.text_color;
+    let mix = |a: f32, b: f32| a + (b - a) * 0.06;
+    let background = appearance.background_color;
+
+    HeaderAppearance {
+        background_color: iced_core::Color {
+            r: mix(background.r, text_color.r),
+            g: mix(background.g, text_color.g),
+            b: mix(background.b, text_color.b),
+            a: mix(background.a, text_color.a),
+        },
+        text_color,
+        border_color: text_color.scale_alpha(0.15),
+        hovered_button_color: text_color.scale_alpha(0.1),
+        hovered_close_color: iced_core::Color::from_rgb8(0xE0, 0x1B, 0x24),
+        hovered_close_icon_color: iced_core::Color::WHITE,
+    }
 }
 
 /// The default [`Appearance`] of a [`Program`] with the built-in [`iced_core::Theme`].
@@ -1,6 +1,7 @@
 //! Wayland specific actions
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use iced_core::window::Id;
 
@@ -26,6 +27,8 @@ pub enum Action {
     SessionLock(session_lock::Action),
     /// Overlap Notify
     OverlapNotify(Id, bool),
+    /// Idle notify, with the timeout after which the user is idle
+    IdleNotify(Option<Duration>),
 }
 
 impl Debug for Action {
@@ -44,6 +47,9 @@ impl Debug for Action {
             Action::OverlapNotify(id, _) => {
                 f.debug_tuple("OverlapNotify").field(id).finish()
             }
+            Action::IdleNotify(timeout) => {
+                f.debug_tuple("IdleNotify").field(timeout).finish()
+            }
         }
     }
 }
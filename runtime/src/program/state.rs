@@ -2,7 +2,9 @@ use crate::core::event::{self, Event};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::operation::{self, Operation};
+use crate::core::window;
 use crate::core::{Clipboard, Size};
+use crate::record::{self, Input, Recorder};
 use crate::user_interface::{self, UserInterface};
 use crate::{Debug, Program, Task};
 
@@ -18,6 +20,9 @@ where
     queued_events: Vec<Event>,
     queued_messages: Vec<P::Message>,
     mouse_interaction: mouse::Interaction,
+    bounds: Size,
+    recorder: Option<Recorder<P::Message>>,
+    recording_error: Option<record::Error>,
 }
 
 impl<P> State<P>
@@ -50,6 +55,9 @@ where
             queued_events: Vec::new(),
             queued_messages: Vec::new(),
             mouse_interaction: mouse::Interaction::None,
+            bounds,
+            recorder: None,
+            recording_error: None,
         }
     }
 
@@ -62,6 +70,10 @@ where
     ///
     /// [`update`]: Self::update
     pub fn queue_event(&mut self, event: Event) {
+        if let Some(input) = Input::from_event(&event) {
+            self.record(input);
+        }
+
         self.queued_events.push(event);
     }
 
@@ -69,9 +81,47 @@ where
     ///
     /// [`update`]: Self::update
     pub fn queue_message(&mut self, message: P::Message) {
+        self.record(Input::Message(&message));
         self.queued_messages.push(message);
     }
 
+    /// Starts recording every event, message and resize of the [`State`]
+    /// with the given [`Recorder`].
+    ///
+    /// The recording can be fed back to the [`Program`] with a
+    /// [`record::Replay`].
+    pub fn start_recording(&mut self, recorder: Recorder<P::Message>) {
+        self.recorder = Some(recorder);
+        self.recording_error = None;
+
+        self.record(Input::Resized(self.bounds));
+    }
+
+    /// Stops the current recording of the [`State`], if any.
+    ///
+    /// Returns the error that interrupted the recording, if one occurred.
+    pub fn stop_recording(&mut self) -> Result<(), record::Error> {
+        if let Some(error) = self.recording_error.take() {
+            return Err(error);
+        }
+
+        self.recorder
+            .take()
+            .map(|mut recorder| recorder.flush())
+            .unwrap_or(Ok(()))
+    }
+
+    fn record(&mut self, input: Input<&P::Message>) {
+        let Some(recorder) = &mut self.recorder else {
+            return;
+        };
+
+        if let Err(error) = recorder.record(window::Id::NONE, input) {
+            self.recorder = None;
+            self.recording_error = Some(error);
+        }
+    }
+
     /// Returns whether the event queue of the [`State`] is empty or not.
     pub fn is_queue_empty(&self) -> bool {
         self.queued_events.is_empty() && self.queued_messages.is_empty()
@@ -99,6 +149,11 @@ where
         clipboard: &mut dyn Clipboard,
         debug: &mut Debug,
     ) -> (Vec<Event>, Option<Task<P::Message>>) {
+        if bounds != self.bounds {
+            self.bounds = bounds;
+            self.record(Input::Resized(bounds));
+        }
+
         let mut user_interface = build_user_interface(
             id.clone(),
             &mut self.program,
@@ -0,0 +1,604 @@
+//! Record the inputs of a program and replay them deterministically.
+//!
+//! A [`Recorder`] writes every [`Input`] of a program to a file, one
+//! [`Entry`] per line. A [`Replay`] reads them back and feeds them to a
+//! [`Program`] headlessly, so a session can be re-run and bisected.
+//!
+//! Since a `Message` is only required to implement [`Debug`], recordings
+//! are encoded by a pluggable [`Serializer`]. With the `serde` feature
+//! enabled, [`Entry`] implements `Serialize` and `Deserialize` whenever the
+//! `Message` does, which makes any line-based serde format a good fit.
+//!
+//! [`Debug`]: std::fmt::Debug
+use crate::core::clipboard;
+use crate::core::event::Event;
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::pen;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::window;
+use crate::core::Size;
+use crate::program::{self, Program};
+use crate::Debug;
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// An input of a program.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Input<Message> {
+    /// A keyboard event.
+    Keyboard(keyboard::Event),
+    /// A mouse event.
+    Mouse(mouse::Event),
+    /// A touch event.
+    Touch(touch::Event),
+    /// A pen event.
+    Pen(pen::Event),
+    /// The window was resized to the given logical size.
+    Resized(Size),
+    /// The window gained focus.
+    Focused,
+    /// The window lost focus.
+    Unfocused,
+    /// The window was redrawn at the time of its [`Entry`].
+    Redrawn,
+    /// A message that did not originate from the widgets of the program
+    /// (e.g. the output of a `Task` or a `Subscription`).
+    Message(Message),
+}
+
+impl<Message> Input<Message> {
+    /// Returns the [`Input`] of the given [`Event`], if it can be replayed.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::Keyboard(event) => Some(Self::Keyboard(event.clone())),
+            Event::Mouse(event) => Some(Self::Mouse(*event)),
+            Event::Touch(event) => Some(Self::Touch(*event)),
+            Event::Pen(event) => Some(Self::Pen(*event)),
+            Event::Window(window::Event::Opened { size, .. })
+            | Event::Window(window::Event::Resized(size)) => {
+                Some(Self::Resized(*size))
+            }
+            Event::Window(window::Event::Focused) => Some(Self::Focused),
+            Event::Window(window::Event::Unfocused) => Some(Self::Unfocused),
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                Some(Self::Redrawn)
+            }
+            _ => None,
+        }
+    }
+
+    /// Turns the [`Input`] into an [`Event`] happening at the given time,
+    /// unless it is a message.
+    pub fn into_event(self, time: Instant) -> Option<Event> {
+        match self {
+            Self::Keyboard(event) => Some(Event::Keyboard(event)),
+            Self::Mouse(event) => Some(Event::Mouse(event)),
+            Self::Touch(event) => Some(Event::Touch(event)),
+            Self::Pen(event) => Some(Event::Pen(event)),
+            Self::Resized(size) => {
+                Some(Event::Window(window::Event::Resized(size)))
+            }
+            Self::Focused => Some(Event::Window(window::Event::Focused)),
+            Self::Unfocused => Some(Event::Window(window::Event::Unfocused)),
+            Self::Redrawn => {
+                Some(Event::Window(window::Event::RedrawRequested(time)))
+            }
+            Self::Message(_) => None,
+        }
+    }
+}
+
+/// A recorded [`Input`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry<Message> {
+    /// The time elapsed since the recording started.
+    pub time: Duration,
+    /// The window that received the [`Input`].
+    pub window: window::Id,
+    /// The recorded [`Input`].
+    pub input: Input<Message>,
+}
+
+/// An encoding of the entries of a recording.
+///
+/// Entries are stored one per line, so a serialized [`Entry`] must not
+/// contain any newlines.
+///
+/// A pair of closures is a [`Serializer`] as well.
+pub trait Serializer<Message> {
+    /// Serializes an [`Entry`] into a single line.
+    fn serialize(&mut self, entry: &Entry<&Message>) -> Result<String, Error>;
+
+    /// Deserializes an [`Entry`] from a single line.
+    fn deserialize(&mut self, line: &str) -> Result<Entry<Message>, Error>;
+}
+
+impl<Message, S, D> Serializer<Message> for (S, D)
+where
+    S: FnMut(&Entry<&Message>) -> Result<String, Error>,
+    D: FnMut(&str) -> Result<Entry<Message>, Error>,
+{
+    fn serialize(&mut self, entry: &Entry<&Message>) -> Result<String, Error> {
+        (self.0)(entry)
+    }
+
+    fn deserialize(&mut self, line: &str) -> Result<Entry<Message>, Error> {
+        (self.1)(line)
+    }
+}
+
+/// An error that occurred while recording or replaying.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The recording could not be read or written.
+    #[error("the recording could not be read or written: {0}")]
+    IOFailed(Arc<io::Error>),
+    /// The [`Serializer`] failed to encode or decode an [`Entry`].
+    #[error("invalid entry at line {line}: {reason}")]
+    InvalidEntry {
+        /// The line of the [`Entry`], starting at 1.
+        line: usize,
+        /// The reason of the failure.
+        reason: String,
+    },
+}
+
+impl Error {
+    /// Creates an [`Error::InvalidEntry`] with the given reason.
+    ///
+    /// The line is filled in by the [`Recorder`] or the [`Replay`].
+    pub fn invalid(reason: impl ToString) -> Self {
+        Self::InvalidEntry {
+            line: 0,
+            reason: reason.to_string(),
+        }
+    }
+
+    fn at(self, line: usize) -> Self {
+        match self {
+            Self::InvalidEntry { reason, .. } => {
+                Self::InvalidEntry { line, reason }
+            }
+            error => error,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+/// A recorder of the inputs of a program.
+#[allow(missing_debug_implementations)]
+pub struct Recorder<Message> {
+    writer: Box<dyn Write + Send>,
+    serializer: Box<dyn Serializer<Message> + Send>,
+    start: Instant,
+    lines: usize,
+}
+
+impl<Message> Recorder<Message> {
+    /// Creates a new [`Recorder`] that writes to the given writer.
+    pub fn new(
+        writer: impl Write + Send + 'static,
+        serializer: impl Serializer<Message> + Send + 'static,
+    ) -> Self {
+        Self {
+            writer: Box::new(writer),
+            serializer: Box::new(serializer),
+            start: Instant::now(),
+            lines: 0,
+        }
+    }
+
+    /// Creates a new [`Recorder`] that writes to the file at the given path,
+    /// truncating it if it exists.
+    pub fn create(
+        path: impl AsRef<Path>,
+        serializer: impl Serializer<Message> + Send + 'static,
+    ) -> Result<Self, Error> {
+        let file = fs::File::create(path)?;
+
+        Ok(Self::new(io::BufWriter::new(file), serializer))
+    }
+
+    /// Records an [`Input`] received by the given window.
+    pub fn record(
+        &mut self,
+        window: window::Id,
+        input: Input<&Message>,
+    ) -> Result<(), Error> {
+        let number = self.lines + 1;
+
+        let entry = Entry {
+            time: self.start.elapsed(),
+            window,
+            input,
+        };
+
+        let line = self
+            .serializer
+            .serialize(&entry)
+            .map_err(|error| error.at(number))?;
+
+        if line.contains('\n') {
+            let error = Error::invalid("serialized entry spans multiple lines");
+
+            return Err(error.at(number));
+        }
+
+        writeln!(self.writer, "{line}")?;
+        self.lines = number;
+
+        Ok(())
+    }
+
+    /// Records an [`Event`] received by the given window.
+    ///
+    /// Events that cannot be replayed are ignored.
+    pub fn event(
+        &mut self,
+        window: window::Id,
+        event: &Event,
+    ) -> Result<(), Error> {
+        match Input::from_event(event) {
+            Some(input) => self.record(window, input),
+            None => Ok(()),
+        }
+    }
+
+    /// Records a message that did not originate from any widgets.
+    pub fn message(&mut self, message: &Message) -> Result<(), Error> {
+        self.record(window::Id::NONE, Input::Message(message))
+    }
+
+    /// Flushes the recorded entries to the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl<Message> Drop for Recorder<Message> {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+/// A recording that can be fed back to a [`Program`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay<Message> {
+    entries: Vec<Entry<Message>>,
+}
+
+impl<Message> Replay<Message> {
+    /// Creates a [`Replay`] of the given entries.
+    pub fn new(entries: Vec<Entry<Message>>) -> Self {
+        Self { entries }
+    }
+
+    /// Reads a [`Replay`] from the given reader.
+    pub fn read(
+        reader: impl BufRead,
+        mut serializer: impl Serializer<Message>,
+    ) -> Result<Self, Error> {
+        let mut entries = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            entries.push(
+                serializer
+                    .deserialize(&line)
+                    .map_err(|error| error.at(i + 1))?,
+            );
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Reads a [`Replay`] from the file at the given path.
+    pub fn open(
+        path: impl AsRef<Path>,
+        serializer: impl Serializer<Message>,
+    ) -> Result<Self, Error> {
+        let file = fs::File::open(path)?;
+
+        Self::read(io::BufReader::new(file), serializer)
+    }
+
+    /// Returns the entries of the [`Replay`].
+    pub fn entries(&self) -> &[Entry<Message>] {
+        &self.entries
+    }
+
+    /// Shortens the [`Replay`], keeping only its first `len` entries.
+    ///
+    /// This is useful to bisect the [`Input`] that causes an issue.
+    pub fn truncate(&mut self, len: usize) {
+        self.entries.truncate(len);
+    }
+
+    /// Feeds the entries of the given window and all the messages of the
+    /// [`Replay`] to the given [`Program`], one at a time, and returns its
+    /// final [`program::State`].
+    ///
+    /// The entries are replayed as fast as possible and any `Task` produced
+    /// by the [`Program`] is discarded, since its output messages are part
+    /// of the recording. Redraws happen at their recorded times, relative to
+    /// the start of the replay, so animations progress as they did.
+    ///
+    /// A [`program::State`] records its entries for [`window::Id::NONE`].
+    pub fn run<P>(
+        self,
+        window: window::Id,
+        program: P,
+        bounds: Size,
+        renderer: &mut P::Renderer,
+        theme: &P::Theme,
+        style: &renderer::Style,
+    ) -> program::State<P>
+    where
+        P: Program<Message = Message> + 'static,
+    {
+        let id = crate::core::id::Id::unique();
+        let mut debug = Debug::new();
+        let mut bounds = bounds;
+        let mut cursor = mouse::Cursor::Unavailable;
+        let start = Instant::now();
+
+        let mut state = program::State::new(
+            id.clone(),
+            program,
+            bounds,
+            renderer,
+            &mut debug,
+        );
+
+        for entry in self.entries {
+            if entry.window != window && entry.window != window::Id::NONE {
+                continue;
+            }
+
+            match entry.input {
+                Input::Message(message) => state.queue_message(message),
+                input => {
+                    match &input {
+                        Input::Resized(size) => bounds = *size,
+                        Input::Mouse(mouse::Event::CursorMoved {
+                            position,
+                        })
+                        | Input::Touch(
+                            touch::Event::FingerPressed { position, .. }
+                            | touch::Event::FingerMoved { position, .. },
+                        ) => cursor = mouse::Cursor::Available(*position),
+                        Input::Mouse(mouse::Event::CursorLeft)
+                        | Input::Touch(
+                            touch::Event::FingerLifted { .. }
+                            | touch::Event::FingerLost { .. },
+                        ) => cursor = mouse::Cursor::Unavailable,
+                        _ => {}
+                    }
+
+                    if let Some(event) = input.into_event(start + entry.time) {
+                        state.queue_event(event);
+                    }
+                }
+            }
+
+            let _ = state.update(
+                id.clone(),
+                bounds,
+                cursor,
+                renderer,
+                theme,
+                style,
+                &mut clipboard::Null,
+                &mut debug,
+            );
+        }
+
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::layout::{self, Layout};
+    use crate::core::widget::{tree, Tree, Widget};
+    use crate::core::{Clipboard, Element, Length, Rectangle, Shell};
+    use crate::Task;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Message(u32);
+
+    struct Numbers;
+
+    impl Serializer<Message> for Numbers {
+        fn serialize(
+            &mut self,
+            entry: &Entry<&Message>,
+        ) -> Result<String, Error> {
+            match &entry.input {
+                Input::Message(Message(n)) => Ok(n.to_string()),
+                _ => Err(Error::invalid("only messages are supported")),
+            }
+        }
+
+        fn deserialize(&mut self, line: &str) -> Result<Entry<Message>, Error> {
+            Ok(Entry {
+                time: Duration::ZERO,
+                window: window::Id::NONE,
+                input: Input::Message(Message(
+                    line.parse().map_err(Error::invalid)?,
+                )),
+            })
+        }
+    }
+
+    #[test]
+    fn recorded_messages_are_replayed() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        struct Channel(std::sync::mpsc::Sender<Vec<u8>>);
+
+        impl Write for Channel {
+            fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+                let _ = self.0.send(bytes.to_vec());
+                Ok(bytes.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut recorder = Recorder::new(Channel(sender), Numbers);
+
+        recorder.message(&Message(1)).unwrap();
+        recorder.message(&Message(2)).unwrap();
+        assert!(recorder
+            .event(window::Id::NONE, &Event::Mouse(mouse::Event::CursorLeft))
+            .is_err());
+        drop(recorder);
+
+        let recording: Vec<u8> = receiver.iter().flatten().collect();
+        let replay = Replay::read(recording.as_slice(), Numbers).unwrap();
+
+        assert_eq!(
+            replay
+                .entries()
+                .iter()
+                .map(|entry| entry.input.clone())
+                .collect::<Vec<_>>(),
+            vec![Input::Message(Message(1)), Input::Message(Message(2))]
+        );
+    }
+
+    /// Publishes the milliseconds elapsed since its first redraw on every
+    /// redraw.
+    struct Clock;
+
+    impl Widget<Message, (), ()> for Clock {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fill, Length::Fill)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn tag(&self) -> tree::Tag {
+            tree::Tag::of::<Option<Instant>>()
+        }
+
+        fn state(&self) -> tree::State {
+            tree::State::new(None::<Instant>)
+        }
+
+        fn on_event(
+            &mut self,
+            tree: &mut Tree,
+            event: Event,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _renderer: &(),
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+            _viewport: &Rectangle,
+        ) -> crate::core::event::Status {
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                let start = tree.state.downcast_mut::<Option<Instant>>();
+                let elapsed = now - *start.get_or_insert(now);
+
+                shell.publish(Message(elapsed.as_millis() as u32));
+            }
+
+            crate::core::event::Status::Ignored
+        }
+    }
+
+    #[derive(Default)]
+    struct Ticks(Vec<u32>);
+
+    impl Program for Ticks {
+        type Renderer = ();
+        type Theme = ();
+        type Message = Message;
+
+        fn update(&mut self, Message(tick): Message) -> Task<Message> {
+            self.0.push(tick);
+
+            Task::none()
+        }
+
+        fn view(&self) -> Element<'_, Message, (), ()> {
+            Element::new(Clock)
+        }
+    }
+
+    #[test]
+    fn redraws_are_replayed_at_their_time_in_their_window() {
+        let main = window::Id::unique();
+        let other = window::Id::unique();
+
+        let redrawn = |millis, window| Entry {
+            time: Duration::from_millis(millis),
+            window,
+            input: Input::Redrawn,
+        };
+
+        let replay = Replay::new(vec![
+            redrawn(0, main),
+            redrawn(10, other),
+            redrawn(16, main),
+            redrawn(50, other),
+            redrawn(40, main),
+        ]);
+
+        let state = replay.run(
+            main,
+            Ticks::default(),
+            Size::new(100.0, 100.0),
+            &mut (),
+            &(),
+            &renderer::Style::default(),
+        );
+
+        assert_eq!(state.program().0, vec![0, 16, 40]);
+    }
+}
@@ -8,8 +8,12 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget;
+use crate::core::widget::operation::{self, focusable};
 use crate::core::window;
-use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{
+    Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector,
+};
+use crate::inspector::Inspection;
 use crate::overlay;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -200,6 +204,8 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
+        let mut focus_request = None;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -244,6 +250,9 @@ where
                     _ => {}
                 }
 
+                input_method.merge(shell.input_method());
+                focus_request = shell.focus_request().or(focus_request);
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -310,6 +319,10 @@ where
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
 
+        if let Some(direction) = focus_request {
+            self.focus(renderer, direction);
+        }
+
         let event_statuses = events
             .iter()
             .cloned()
@@ -346,6 +359,8 @@ where
                     _ => {}
                 }
 
+                input_method.merge(shell.input_method());
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -360,6 +375,10 @@ where
                     outdated = true;
                 }
 
+                if let Some(direction) = shell.focus_request() {
+                    self.focus(renderer, direction);
+                }
+
                 event_status.merge(overlay_status)
             })
             .collect();
@@ -368,7 +387,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -611,6 +633,35 @@ where
         }
     }
 
+    /// Moves the keyboard focus of the [`UserInterface`] in the given
+    /// [`focusable::Direction`].
+    fn focus(&mut self, renderer: &Renderer, direction: focusable::Direction) {
+        let mut operation: Box<dyn widget::Operation> = match direction {
+            focusable::Direction::Next => Box::new(focusable::focus_next()),
+            focusable::Direction::Previous => {
+                Box::new(focusable::focus_previous())
+            }
+        };
+
+        loop {
+            self.operate(renderer, operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+                operation::Outcome::None | operation::Outcome::Some(()) => {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Layout`] of the root widget of the [`UserInterface`].
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.base)
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -636,6 +687,12 @@ where
         )
     }
 
+    /// Takes an [`Inspection`] of the widget hierarchy of the
+    /// [`UserInterface`].
+    pub fn inspect(&self) -> Inspection {
+        Inspection::new(Some(&self.state), Layout::new(&self.base))
+    }
+
     /// Find widget with given id
     pub fn find(&self, id: &widget::Id) -> Option<&widget::Tree> {
         self.state.find(id)
@@ -683,7 +740,7 @@ impl Default for Cache {
 }
 
 /// The resulting state after updating a [`UserInterface`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum State {
     /// The [`UserInterface`] is outdated and needs to be rebuilt.
     Outdated,
@@ -693,5 +750,8 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the focused widget, if any.
+        input_method: InputMethod,
     },
 }
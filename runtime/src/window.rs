@@ -5,9 +5,9 @@ pub use screenshot::Screenshot;
 
 use crate::core::time::Instant;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    Event, Icon, Id, Level, Mode, PointerGrab, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::Subscription;
@@ -165,6 +165,19 @@ pub enum Action {
 
     /// Disable window blur.
     DisableBlur(Id),
+
+    /// Constrain the pointer while it is over the given window.
+    GrabPointer(Id, PointerGrab),
+
+    /// Set or clear the idle inhibitor of the window.
+    ///
+    /// The window also covers layer surfaces, popups and lock surfaces.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Requires the `zwp_idle_inhibit_manager_v1` protocol.
+    /// - **Others:** Unsupported.
+    InhibitIdle(Id, bool),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -476,3 +489,44 @@ pub fn enable_blur<Message>(id: Id) -> Task<Message> {
 pub fn disable_blur<Message>(id: Id) -> Task<Message> {
     task::effect(crate::Action::Window(Action::DisableBlur(id)))
 }
+
+/// Locks the pointer in place while it is over the given window.
+///
+/// The window keeps receiving [`mouse::Event::RawMotion`], which makes it
+/// suitable for mouse look in 3D viewports.
+///
+/// [`mouse::Event::RawMotion`]: crate::core::mouse::Event::RawMotion
+pub fn lock_pointer<Message>(id: Id) -> Task<Message> {
+    task::effect(crate::Action::Window(Action::GrabPointer(
+        id,
+        PointerGrab::Locked,
+    )))
+}
+
+/// Confines the pointer to the given window or, if provided, to a region of
+/// it in logical coordinates.
+pub fn confine_pointer<Message>(
+    id: Id,
+    region: Option<Rectangle>,
+) -> Task<Message> {
+    task::effect(crate::Action::Window(Action::GrabPointer(
+        id,
+        PointerGrab::Confined(region),
+    )))
+}
+
+/// Releases any lock or confinement of the pointer of the given window.
+pub fn release_pointer<Message>(id: Id) -> Task<Message> {
+    task::effect(crate::Action::Window(Action::GrabPointer(
+        id,
+        PointerGrab::None,
+    )))
+}
+
+/// Sets or clears the idle inhibitor of the given window.
+///
+/// While inhibited and visible, the window prevents the screen from blanking
+/// or locking due to user inactivity.
+pub fn inhibit_idle<Message>(id: Id, inhibit: bool) -> Task<Message> {
+    task::effect(crate::Action::Window(Action::InhibitIdle(id, inhibit)))
+}
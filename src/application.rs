@@ -34,7 +34,7 @@ use crate::program::{self, Program};
 #[cfg(not(feature = "winit"))]
 use crate::runtime::{Appearance, DefaultStyle};
 #[cfg(feature = "winit")]
-pub use crate::shell::program::{Appearance, DefaultStyle};
+pub use crate::shell::program::{Appearance, DefaultStyle, HeaderAppearance};
 use crate::window;
 use crate::{Element, Executor, Font, Settings, Size, Subscription, Task};
 
@@ -307,6 +307,20 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::geometry_id`] of the [`Application`].
+    ///
+    /// The size, position and maximized state of the window will be
+    /// restored the next time the [`Application`] runs.
+    pub fn remember_geometry(self, id: impl Into<String>) -> Self {
+        Self {
+            window: window::Settings {
+                geometry_id: Some(id.into()),
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`Title`] of the [`Application`].
     pub(crate) fn title(
         self,
@@ -2,7 +2,7 @@
 use crate::application;
 use crate::program::{self, Program};
 #[cfg(feature = "winit")]
-pub use crate::shell::program::{Appearance, DefaultStyle};
+pub use crate::shell::program::{Appearance, DefaultStyle, HeaderAppearance};
 use crate::window;
 use crate::{Element, Executor, Font, Settings, Subscription, Task};
 
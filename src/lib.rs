@@ -516,6 +516,8 @@ pub use program::Program;
 pub mod advanced;
 
 pub use crate::core::alignment;
+pub use crate::core::animation::{self, Animation};
+pub use crate::core::background;
 pub use crate::core::border::{self, Radius};
 pub use crate::core::color;
 pub use crate::core::gradient;
@@ -581,6 +583,11 @@ pub mod event {
     pub use iced_futures::event::{listen, listen_raw, listen_with};
 }
 
+pub mod input_method {
+    //! Compose text with an input method editor (IME).
+    pub use crate::core::input_method::{Event, InputMethod, Preedit, Purpose};
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
@@ -3,12 +3,12 @@ use crate::graphics::compositor;
 #[cfg(feature = "winit")]
 use crate::shell;
 #[cfg(feature = "winit")]
-pub use crate::shell::program::{Appearance, DefaultStyle};
+pub use crate::shell::program::{Appearance, DefaultStyle, HeaderAppearance};
 use crate::window;
 use crate::{Element, Executor, Subscription, Task};
 
 #[cfg(not(feature = "winit"))]
-pub use crate::runtime::{Appearance, DefaultStyle};
+pub use crate::runtime::{Appearance, DefaultStyle, HeaderAppearance};
 
 /// The internal definition of a [`Program`].
 ///
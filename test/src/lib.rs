@@ -0,0 +1,611 @@
+//! Test your iced applications in headless mode.
+//!
+//! A [`Simulator`] builds a [`UserInterface`] out of an [`Element`] and lets
+//! you interact with it as a user would: clicking, typing and scrolling. It
+//! runs on top of a [`Headless`] renderer, so no window or GPU is needed.
+//!
+//! # Example
+//! ```no_run
+//! use iced_test::simulator;
+//! use iced_widget::{button, column, text};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! let mut ui = simulator(
+//!     column![text("0"), button("Increment").on_press(Message::Increment)],
+//! );
+//!
+//! let _ = ui.click("Increment").expect("Button should be visible");
+//!
+//! assert_eq!(
+//!     ui.into_messages().collect::<Vec<_>>(),
+//!     vec![Message::Increment]
+//! );
+//! ```
+//!
+//! [`Headless`]: core::renderer::Headless
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub mod selector;
+pub mod snapshot;
+
+pub use selector::{Selector, Target};
+pub use snapshot::Snapshot;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::{self as core_renderer, Headless};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::{Element, Font, Layout, Pixels, Point, Size};
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window;
+
+use std::io;
+use std::sync::Arc;
+
+/// The default [`Size`] of the viewport of a [`Simulator`].
+pub const DEFAULT_SIZE: Size = Size::new(1024.0, 768.0);
+
+/// The settings of a [`Simulator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The logical [`Size`] of the viewport.
+    pub size: Size,
+
+    /// The scale factor used when taking a [`Snapshot`].
+    pub scale_factor: f32,
+
+    /// The default [`Font`] of the renderer.
+    pub default_font: Font,
+
+    /// The default text size of the renderer.
+    pub default_text_size: Pixels,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            size: DEFAULT_SIZE,
+            scale_factor: 1.0,
+            default_font: Font::DEFAULT,
+            default_text_size: Pixels(16.0),
+        }
+    }
+}
+
+/// Creates a new [`Simulator`] for the given [`Element`] with the default
+/// [`Settings`].
+pub fn simulator<'a, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: Headless,
+{
+    Simulator::new(element)
+}
+
+/// A user interface that can be interacted with programmatically.
+///
+/// Every message produced by the widgets is collected and can be obtained
+/// with [`Simulator::into_messages`].
+#[allow(missing_debug_implementations)]
+pub struct Simulator<
+    'a,
+    Message,
+    Theme = core::Theme,
+    Renderer = renderer::Renderer,
+> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    settings: Settings,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: Headless,
+{
+    /// Creates a new [`Simulator`] for the given [`Element`] with the
+    /// default [`Settings`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(Settings::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with a
+    /// viewport of the given [`Size`].
+    pub fn with_size(
+        size: impl Into<Size>,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(
+            Settings {
+                size: size.into(),
+                ..Settings::default()
+            },
+            element,
+        )
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with the
+    /// provided [`Settings`].
+    pub fn with_settings(
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let renderer =
+            Renderer::new(settings.default_font, settings.default_text_size);
+
+        Self::with_renderer(renderer, settings, element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] using the
+    /// provided renderer and [`Settings`].
+    pub fn with_renderer(
+        mut renderer: Renderer,
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let raw = UserInterface::build(
+            element,
+            settings.size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            settings,
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Size`] of the viewport of the [`Simulator`].
+    pub fn size(&self) -> Size {
+        self.settings.size
+    }
+
+    /// Returns the current [`Layout`] of the user interface.
+    pub fn layout(&self) -> Layout<'_> {
+        self.raw.layout()
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let mut operation = selector::find(selector.clone());
+
+        self.raw.operate(&self.renderer, &mut operation);
+
+        operation.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Runs the given [`Operation`] on the user interface, following any
+    /// chained operations until it finishes.
+    ///
+    /// This can be used to move the keyboard focus, for instance, with
+    /// [`focus_next`] and [`focus_previous`].
+    ///
+    /// [`focus_next`]: core::widget::operation::focusable::focus_next
+    /// [`focus_previous`]: core::widget::operation::focusable::focus_previous
+    pub fn operate(&mut self, operation: impl Operation + 'static) {
+        let mut operation: Box<dyn Operation> = Box::new(operation);
+
+        loop {
+            self.raw.operate(&self.renderer, operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+                operation::Outcome::None | operation::Outcome::Some(()) => {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Clicks the center of the first widget matching the given
+    /// [`Selector`] with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Scrolls the first widget matching the given [`Selector`] by the
+    /// given [`mouse::ScrollDelta`].
+    pub fn scroll(
+        &mut self,
+        selector: impl Into<Selector>,
+        delta: mouse::ScrollDelta,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::WheelScrolled { delta })]);
+
+        Ok(target)
+    }
+
+    /// Sets the [`keyboard::Modifiers`] used by subsequent key presses.
+    pub fn set_modifiers(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+
+        let _ = self.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers),
+        )]);
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        let key = key.into();
+
+        let text = match &key {
+            keyboard::Key::Character(c) if !self.modifiers.command() => {
+                Some(c.clone())
+            }
+            _ => None,
+        };
+
+        self.key(key, text)
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        text.chars()
+            .map(|c| {
+                let c = core::SmolStr::new(c.to_string());
+
+                self.key(keyboard::Key::Character(c.clone()), Some(c))
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Feeds the given events to the user interface and returns their
+    /// [`event::Status`].
+    ///
+    /// Cursor movements are tracked, so that subsequent events are delivered
+    /// with the latest cursor position.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        events
+            .into_iter()
+            .map(|event| {
+                match &event {
+                    Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                        self.cursor = mouse::Cursor::Available(*position);
+                    }
+                    Event::Mouse(mouse::Event::CursorLeft) => {
+                        self.cursor = mouse::Cursor::Unavailable;
+                    }
+                    _ => {}
+                }
+
+                let (_state, statuses) = self.raw.update(
+                    std::slice::from_ref(&event),
+                    self.cursor,
+                    &mut self.renderer,
+                    &mut clipboard::Null,
+                    &mut self.messages,
+                );
+
+                statuses
+                    .into_iter()
+                    .fold(event::Status::Ignored, event::Status::merge)
+            })
+            .collect()
+    }
+
+    /// Draws the user interface with the given `Theme` and captures the
+    /// result in a [`Snapshot`].
+    ///
+    /// The [`Snapshot`] has the physical size given by the viewport and the
+    /// scale factor of the [`Settings`] of the [`Simulator`].
+    pub fn snapshot(&mut self, theme: &Theme) -> Snapshot
+    where
+        Theme: runtime::DefaultStyle,
+    {
+        let appearance = theme.default_style();
+        let scale_factor = self.settings.scale_factor;
+
+        let _ = self.raw.draw(
+            &mut self.renderer,
+            theme,
+            &core_renderer::Style {
+                icon_color: appearance.icon_color,
+                text_color: appearance.text_color,
+                scale_factor: f64::from(scale_factor),
+            },
+            self.cursor,
+        );
+
+        let physical_size = Size::new(
+            (self.settings.size.width * scale_factor).round() as u32,
+            (self.settings.size.height * scale_factor).round() as u32,
+        );
+
+        let rgba = self.renderer.screenshot(
+            physical_size,
+            scale_factor,
+            appearance.background_color,
+        );
+
+        Snapshot::new(window::Screenshot::new(
+            rgba,
+            physical_size,
+            f64::from(scale_factor),
+        ))
+    }
+
+    /// Returns the messages produced so far, draining them from the
+    /// [`Simulator`].
+    pub fn messages(&mut self) -> impl Iterator<Item = Message> + '_ {
+        self.messages.drain(..)
+    }
+
+    /// Consumes the [`Simulator`] and returns all the messages produced.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+
+    fn key(
+        &mut self,
+        key: keyboard::Key,
+        text: Option<core::SmolStr>,
+    ) -> event::Status {
+        let physical_key = keyboard::key::Physical::Unidentified(
+            keyboard::key::NativeCode::Unidentified,
+        );
+
+        let statuses = self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key.clone(),
+                physical_key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+                text,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: key.clone(),
+                modified_key: key,
+                physical_key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+            }),
+        ]);
+
+        statuses[0]
+    }
+}
+
+/// An error produced by a [`Simulator`] or a [`Snapshot`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matching the [`Selector`] was found.
+    #[error("no widget matching the selector was found: {0:?}")]
+    NotFound(Selector),
+
+    /// An IO operation failed.
+    #[error("an IO operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// The decoding or encoding of some image failed.
+    #[error("the decoding or encoding of some image failed: {0}")]
+    ImageFailed(Arc<image::ImageError>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<image::ImageError> for Error {
+    fn from(error: image::ImageError) -> Self {
+        Self::ImageFailed(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{
+        button, column, container, scrollable, text, text_input,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        Decrement,
+        Input(String),
+        Scrolled,
+    }
+
+    #[test]
+    fn click_by_text_produces_message() {
+        let mut ui = simulator(column![
+            button("Increment").on_press(Message::Increment),
+            button("Decrement").on_press(Message::Decrement),
+        ]);
+
+        let _ = ui.click("Decrement").unwrap();
+        let _ = ui.click("Increment").unwrap();
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::Decrement, Message::Increment]
+        );
+    }
+
+    #[test]
+    fn missing_selector_is_an_error() {
+        let mut ui = simulator::<Message, core::Theme, renderer::Renderer>(
+            text("Hello"),
+        );
+
+        assert!(ui.find("Hello").is_ok());
+        assert!(matches!(ui.click("Goodbye"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn typewrite_into_focused_input() {
+        let id = core::widget::Id::new("input");
+
+        let mut ui = simulator(column![text_input("Name", "")
+            .id(text_input::Id::new("input"))
+            .on_input(Message::Input)]);
+
+        let _ = ui.click(id).unwrap();
+        let status = ui.typewrite("abc");
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(ui.into_messages().count(), 3);
+    }
+
+    #[test]
+    fn scroll_by_id() {
+        let id = core::widget::Id::new("scrollable");
+
+        let mut ui = simulator(
+            scrollable(column((0..100).map(|i| text(i).into())))
+                .id(id.clone())
+                .height(100)
+                .on_scroll(|_| Message::Scrolled),
+        );
+
+        let _ = ui
+            .scroll(id, mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 })
+            .unwrap();
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::Scrolled]
+        );
+    }
+
+    #[test]
+    fn layout_is_exposed() {
+        let ui = Simulator::<Message>::with_size(
+            Size::new(400.0, 300.0),
+            column![text("A"), text("B")].spacing(10).padding(5),
+        );
+
+        let layout = ui.layout();
+        let children: Vec<_> = layout.children().collect();
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].bounds().position(), Point::new(5.0, 5.0));
+        assert!(children[1].bounds().y >= children[0].bounds().height + 15.0);
+    }
+
+    #[test]
+    fn snapshot_matches_stored_image() {
+        let directory = std::env::temp_dir().join("iced_test_snapshot");
+        let path = directory.join("square.png");
+
+        let _ = std::fs::remove_dir_all(&directory);
+
+        let view = || {
+            container(text("")).width(20).height(10).style(|_theme| {
+                container::Style {
+                    background: Some(core::Color::BLACK.into()),
+                    ..container::Style::default()
+                }
+            })
+        };
+
+        let mut ui = Simulator::<Message>::with_settings(
+            Settings {
+                size: Size::new(40.0, 20.0),
+                scale_factor: 2.0,
+                ..Settings::default()
+            },
+            view(),
+        );
+
+        let theme = core::Theme::Light;
+        let snapshot = ui.snapshot(&theme);
+
+        assert_eq!(snapshot.size(), Size::new(80, 40));
+        assert_eq!(snapshot.rgba().len(), 80 * 40 * 4);
+
+        // The square covers the top-left quarter of the frame, in physical
+        // pixels
+        let background = image::Rgba(
+            runtime::DefaultStyle::default_style(&theme)
+                .background_color
+                .into_rgba8(),
+        );
+        let reference = image::RgbaImage::from_fn(80, 40, |x, y| {
+            if x < 40 && y < 20 {
+                image::Rgba([0, 0, 0, 255])
+            } else {
+                background
+            }
+        });
+
+        std::fs::create_dir_all(&directory).unwrap();
+        reference.save(&path).unwrap();
+
+        assert_eq!(snapshot.rgba(), reference.as_raw().as_slice());
+        assert!(snapshot.matches_image(&path).unwrap());
+        assert!(!directory.join("square.actual.png").exists());
+
+        let new_path = directory.join("new.png");
+
+        assert!(snapshot.matches_image(&new_path).unwrap());
+        assert_eq!(
+            image::open(&new_path).unwrap().into_rgba8().as_raw(),
+            reference.as_raw()
+        );
+
+        let white = image::RgbaImage::from_pixel(80, 40, image::Rgba([255; 4]));
+        white.save(&path).unwrap();
+
+        assert!(!snapshot.matches_image_with(&path, 8).unwrap());
+        assert!(directory.join("square.diff.png").exists());
+        assert!(directory.join("square.actual.png").exists());
+    }
+}
@@ -0,0 +1,147 @@
+//! Select widgets of a user interface.
+use crate::core::widget::operation::{Operation, Scrollable};
+use crate::core::widget::Id;
+use crate::core::{Rectangle, Vector};
+
+use std::borrow::Cow;
+
+/// A description of a widget in a user interface.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// A widget with the given [`Id`].
+    Id(Id),
+
+    /// A widget displaying the given text.
+    Text(Cow<'static, str>),
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&'static str> for Selector {
+    fn from(text: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(Cow::Owned(text))
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The bounds of the widget, in window coordinates.
+    ///
+    /// Any scrolling applied by the ancestors of the widget is already taken
+    /// into account.
+    pub bounds: Rectangle,
+}
+
+/// Produces an [`Operation`] that finds the first widget matching the given
+/// [`Selector`].
+///
+/// The result can be obtained with [`Find::target`] once the [`Operation`]
+/// has run.
+pub fn find(selector: Selector) -> Find {
+    Find {
+        selector,
+        translation: Vector::ZERO,
+        pending_translation: Vector::ZERO,
+        target: None,
+    }
+}
+
+/// An [`Operation`] that finds a widget matching a [`Selector`].
+#[derive(Debug)]
+pub struct Find {
+    selector: Selector,
+    translation: Vector,
+    pending_translation: Vector,
+    target: Option<Target>,
+}
+
+impl Find {
+    /// Returns the [`Target`] found, if any.
+    pub fn target(&self) -> Option<Target> {
+        self.target
+    }
+
+    fn matches_id(&self, id: Option<&Id>) -> bool {
+        matches!(&self.selector, Selector::Id(target) if Some(target) == id)
+    }
+
+    fn found(&mut self, bounds: Rectangle) {
+        self.target = Some(Target {
+            bounds: bounds - self.translation,
+        });
+    }
+}
+
+impl Operation for Find {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if self.matches_id(id) {
+            self.found(bounds);
+            return;
+        }
+
+        // A scrollable reports its translation right before operating on
+        // its contents as a container.
+        let translation = std::mem::take(&mut self.pending_translation);
+
+        self.translation = self.translation + translation;
+
+        operate_on_children(self);
+
+        self.translation = self.translation - translation;
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if self.matches_id(id) {
+            self.found(bounds);
+            return;
+        }
+
+        self.pending_translation = translation;
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        if self.target.is_some() {
+            return;
+        }
+
+        let is_match = match &self.selector {
+            Selector::Id(target) => Some(target) == id,
+            Selector::Text(target) => target == text,
+        };
+
+        if is_match {
+            self.found(bounds);
+        }
+    }
+}
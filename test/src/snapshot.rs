@@ -0,0 +1,188 @@
+//! Compare rendered frames of a user interface against stored images.
+use crate::core::Size;
+use crate::runtime::window::Screenshot;
+use crate::Error;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A rendered frame of a user interface.
+///
+/// A [`Snapshot`] can be obtained with [`Simulator::snapshot`].
+///
+/// [`Simulator::snapshot`]: crate::Simulator::snapshot
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] from the given [`Screenshot`].
+    pub fn new(screenshot: Screenshot) -> Self {
+        Self { screenshot }
+    }
+
+    /// Returns the physical [`Size`] of the [`Snapshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.screenshot.size
+    }
+
+    /// Returns the scale factor the [`Snapshot`] was rendered with.
+    pub fn scale_factor(&self) -> f64 {
+        self.screenshot.scale_factor
+    }
+
+    /// Returns the pixels of the [`Snapshot`], ordered as `RGBA` in the
+    /// `sRGB` color space.
+    pub fn rgba(&self) -> &[u8] {
+        &self.screenshot.bytes
+    }
+
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        image::save_buffer(
+            path,
+            self.rgba(),
+            self.size().width,
+            self.size().height,
+            image::ColorType::Rgba8,
+        )?;
+
+        Ok(())
+    }
+
+    /// Compares the [`Snapshot`] with the PNG image stored at the given path,
+    /// requiring every pixel to match exactly.
+    ///
+    /// See [`Snapshot::matches_image_with`] for more details.
+    pub fn matches_image(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        self.matches_image_with(path, 0)
+    }
+
+    /// Compares the [`Snapshot`] with the PNG image stored at the given path.
+    ///
+    /// Two pixels are considered equal if none of their channels differ by
+    /// more than the given `tolerance`.
+    ///
+    /// If no image exists at the given path, the [`Snapshot`] is saved there
+    /// and considered a match.
+    ///
+    /// When the comparison fails, the rendered frame is written next to the
+    /// stored image with an `.actual.png` extension. If both images have the
+    /// same size, a diff image highlighting the mismatching pixels in red is
+    /// written with a `.diff.png` extension, too.
+    pub fn matches_image_with(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: u8,
+    ) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        let expected = image::open(path)?.into_rgba8();
+        let size = self.size();
+
+        let actual_path = sibling(path, "actual");
+        let diff_path = sibling(path, "diff");
+
+        if expected.dimensions() != (size.width, size.height)
+            || expected.as_raw().len() != self.rgba().len()
+        {
+            self.save(&actual_path)?;
+            remove_stale(&diff_path)?;
+
+            return Ok(false);
+        }
+
+        let diff = diff(&expected, self.rgba(), tolerance);
+
+        if !diff.mismatches {
+            remove_stale(&actual_path)?;
+            remove_stale(&diff_path)?;
+
+            return Ok(true);
+        }
+
+        self.save(&actual_path)?;
+        diff.image.save(&diff_path)?;
+
+        Ok(false)
+    }
+}
+
+struct Diff {
+    image: image::RgbaImage,
+    mismatches: bool,
+}
+
+fn diff(expected: &image::RgbaImage, actual: &[u8], tolerance: u8) -> Diff {
+    let mut mismatches = false;
+
+    let pixels = expected
+        .as_raw()
+        .chunks_exact(4)
+        .zip(actual.chunks_exact(4))
+        .flat_map(|(expected, actual)| {
+            let is_mismatch = expected
+                .iter()
+                .zip(actual)
+                .any(|(a, b)| a.abs_diff(*b) > tolerance);
+
+            if is_mismatch {
+                mismatches = true;
+
+                [255, 0, 0, 255]
+            } else {
+                // Dim the expected image, so mismatches stand out
+                let luma = (u16::from(expected[0])
+                    + u16::from(expected[1])
+                    + u16::from(expected[2]))
+                    / 3;
+                let dimmed = (luma / 4) as u8 + 128;
+
+                [dimmed, dimmed, dimmed, 255]
+            }
+        })
+        .collect();
+
+    let image =
+        image::RgbaImage::from_raw(expected.width(), expected.height(), pixels)
+            .expect("Create diff image");
+
+    Diff { image, mismatches }
+}
+
+fn sibling(path: &Path, kind: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    path.with_file_name(format!("{stem}.{kind}.png"))
+}
+
+fn remove_stale(path: &Path) -> Result<(), Error> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => {
+            Err(error.into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -1,14 +1,16 @@
 use crate::core::renderer::Quad;
-use crate::core::{
-    Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
-};
-use crate::graphics::{Image, Text};
+use crate::core::{Background, Color, Rectangle, Size, Transformation, Vector};
+use crate::gradient::{self, Pattern};
+use crate::graphics::{self, Image, Text};
 use crate::text;
 use crate::Primitive;
 
+use std::borrow::Cow;
+
 #[derive(Debug)]
 pub struct Engine {
     text_pipeline: text::Pipeline,
+    patterns: gradient::Cache,
 
     #[cfg(feature = "image")]
     pub(crate) raster_pipeline: crate::raster::Pipeline,
@@ -20,6 +22,7 @@ impl Engine {
     pub fn new() -> Self {
         Self {
             text_pipeline: text::Pipeline::new(),
+            patterns: gradient::Cache::default(),
             #[cfg(feature = "image")]
             raster_pipeline: crate::raster::Pipeline::new(),
             #[cfg(feature = "svg")]
@@ -162,6 +165,8 @@ impl Engine {
             }
         }
 
+        let pattern;
+
         pixels.fill_path(
             &path,
             &tiny_skia::Paint {
@@ -169,47 +174,36 @@ impl Engine {
                     Background::Color(color) => {
                         tiny_skia::Shader::SolidColor(into_color(*color))
                     }
-                    Background::Gradient(Gradient::Linear(linear)) => {
-                        let (start, end) =
-                            linear.angle.to_distance(&quad.bounds);
-
-                        let stops: Vec<tiny_skia::GradientStop> = linear
-                            .stops
-                            .into_iter()
-                            .flatten()
-                            .map(|stop| {
-                                tiny_skia::GradientStop::new(
-                                    stop.offset,
-                                    tiny_skia::Color::from_rgba(
-                                        stop.color.b,
-                                        stop.color.g,
-                                        stop.color.r,
-                                        stop.color.a,
-                                    )
-                                    .expect("Create color"),
+                    Background::Gradient(gradient) => {
+                        match graphics::gradient::resolve(gradient, quad.bounds)
+                        {
+                            graphics::Gradient::Conic(conic) => {
+                                pattern = match physical_bounds
+                                    .intersection(&clip_bounds)
+                                {
+                                    Some(bounds) => self.patterns.conic(
+                                        &conic,
+                                        transformation,
+                                        bounds,
+                                    ),
+                                    None => None,
+                                };
+
+                                pattern.map_or(
+                                    tiny_skia::Shader::SolidColor(
+                                        tiny_skia::Color::TRANSPARENT,
+                                    ),
+                                    Pattern::shader,
                                 )
-                            })
-                            .collect();
-
-                        tiny_skia::LinearGradient::new(
-                            tiny_skia::Point {
-                                x: start.x,
-                                y: start.y,
-                            },
-                            tiny_skia::Point { x: end.x, y: end.y },
-                            if stops.is_empty() {
-                                vec![tiny_skia::GradientStop::new(
-                                    0.0,
-                                    tiny_skia::Color::BLACK,
-                                )]
-                            } else {
-                                stops
-                            },
-                            tiny_skia::SpreadMode::Pad,
-                            tiny_skia::Transform::identity(),
-                        )
-                        .expect("Create linear gradient")
+                            }
+                            resolved => gradient::into_shader(&resolved),
+                        }
                     }
+                    // Image backgrounds are drawn together with the images
+                    // of a layer
+                    Background::Image(_) => tiny_skia::Shader::SolidColor(
+                        tiny_skia::Color::TRANSPARENT,
+                    ),
                 },
                 anti_alias: true,
                 ..tiny_skia::Paint::default()
@@ -487,7 +481,12 @@ impl Engine {
         layer_bounds: Rectangle,
     ) {
         match primitive {
-            Primitive::Fill { path, paint, rule } => {
+            Primitive::Fill {
+                path,
+                paint,
+                rule,
+                conic,
+            } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
 
@@ -508,9 +507,16 @@ impl Engine {
                 let clip_mask =
                     (physical_bounds != clip_bounds).then_some(clip_mask as &_);
 
+                let pattern = match conic {
+                    Some(conic) => {
+                        self.patterns.conic(conic, transformation, clip_bounds)
+                    }
+                    None => None,
+                };
+
                 pixels.fill_path(
                     path,
-                    paint,
+                    &with_pattern(paint, pattern),
                     *rule,
                     into_transform(transformation),
                     clip_mask,
@@ -520,6 +526,7 @@ impl Engine {
                 path,
                 paint,
                 stroke,
+                conic,
             } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
@@ -541,9 +548,22 @@ impl Engine {
                 let clip_mask =
                     (physical_bounds != clip_bounds).then_some(clip_mask as &_);
 
+                // The stroke may be drawn outside of the bounds of its path
+                let stroke_bounds = layer_bounds.intersection(
+                    &physical_bounds
+                        .expand(stroke.width * transformation.scale_factor()),
+                );
+
+                let pattern = match (conic, stroke_bounds) {
+                    (Some(conic), Some(bounds)) => {
+                        self.patterns.conic(conic, transformation, bounds)
+                    }
+                    _ => None,
+                };
+
                 pixels.stroke_path(
                     path,
-                    paint,
+                    &with_pattern(paint, pattern),
                     stroke,
                     into_transform(transformation),
                     clip_mask,
@@ -624,8 +644,58 @@ impl Engine {
                     clip_mask,
                 );
             }
+            #[cfg(feature = "image")]
+            Image::Background {
+                image,
+                bounds,
+                border_radius,
+            } => {
+                let physical_bounds = *bounds * _transformation;
+
+                if !_clip_bounds.intersects(&physical_bounds) {
+                    return;
+                }
+
+                let size = self.raster_pipeline.dimensions(&image.handle);
+                let scale_factor = _transformation.scale_factor();
+
+                let border_radius = border_radius.map(|radius| {
+                    (radius * scale_factor)
+                        .min(physical_bounds.width / 2.0)
+                        .min(physical_bounds.height / 2.0)
+                });
+
+                // Clip the tiles to the rounded bounds of the background
+                let mut clip_mask = _clip_mask.clone();
+
+                clip_mask.intersect_path(
+                    &rounded_rectangle(physical_bounds, border_radius),
+                    tiny_skia::FillRule::EvenOdd,
+                    true,
+                    tiny_skia::Transform::default(),
+                );
+
+                for tile in image.tiles(size, *bounds) {
+                    let tile = tile * _transformation;
+
+                    if !_clip_bounds.intersects(&tile) {
+                        continue;
+                    }
+
+                    self.raster_pipeline.draw(
+                        &image.handle,
+                        image.filter_method,
+                        tile,
+                        image.opacity,
+                        _pixels,
+                        tiny_skia::Transform::default(),
+                        Some(&clip_mask),
+                        [0.0; 4],
+                    );
+                }
+            }
             #[cfg(not(feature = "image"))]
-            Image::Raster { .. } => {
+            Image::Raster { .. } | Image::Background { .. } => {
                 log::warn!(
                     "Unsupported primitive in `iced_tiny_skia`: {image:?}",
                 );
@@ -641,6 +711,7 @@ impl Engine {
 
     pub fn trim(&mut self) {
         self.text_pipeline.trim_cache();
+        self.patterns.trim();
 
         #[cfg(feature = "image")]
         self.raster_pipeline.trim_cache();
@@ -650,12 +721,26 @@ impl Engine {
     }
 }
 
+/// Replaces the shader of the given paint with the given [`Pattern`], if any.
+fn with_pattern<'a>(
+    paint: &'a tiny_skia::Paint<'static>,
+    pattern: Option<&'a Pattern>,
+) -> Cow<'a, tiny_skia::Paint<'a>> {
+    match pattern {
+        Some(pattern) => Cow::Owned(tiny_skia::Paint {
+            shader: pattern.shader(),
+            ..paint.clone()
+        }),
+        None => Cow::Borrowed(paint),
+    }
+}
+
 pub fn into_color(color: Color) -> tiny_skia::Color {
     tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
         .expect("Convert color from iced to tiny_skia")
 }
 
-fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
+pub fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
     tiny_skia::Transform {
@@ -4,7 +4,8 @@ use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{self, Path, Style};
-use crate::graphics::{self, Gradient, Image, Text};
+use crate::graphics::gradient::{self, Gradient};
+use crate::graphics::{self, Image, Text};
 use crate::Primitive;
 
 use std::rc::Rc;
@@ -81,6 +82,40 @@ impl Frame {
             ),
         }
     }
+
+    /// Returns the conic gradient of the given [`Style`], if any, with the
+    /// current transform applied.
+    fn conic(&self, style: Style) -> Option<gradient::Conic> {
+        let Style::Gradient(Gradient::Conic(conic)) = style else {
+            return None;
+        };
+
+        let (sin, cos) = conic.angle.0.sin_cos();
+
+        let mut points = [
+            tiny_skia::Point {
+                x: conic.center.x,
+                y: conic.center.y,
+            },
+            tiny_skia::Point {
+                x: conic.center.x + sin,
+                y: conic.center.y - cos,
+            },
+        ];
+
+        self.transform.map_points(&mut points);
+
+        let [center, direction] = points;
+
+        Some(gradient::Conic {
+            center: Point::new(center.x, center.y),
+            angle: Radians(f32::atan2(
+                direction.x - center.x,
+                center.y - direction.y,
+            )),
+            ..conic
+        })
+    }
 }
 
 impl geometry::frame::Backend for Frame {
@@ -118,6 +153,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: self.conic(fill.style),
         });
     }
 
@@ -145,6 +181,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: self.conic(fill.style),
         });
     }
 
@@ -165,6 +202,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             stroke: skia_stroke,
+            conic: self.conic(stroke.style),
         });
     }
 
@@ -421,49 +459,9 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                 tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
                     .expect("Create color"),
             ),
-            Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(
-                                0.0,
-                                tiny_skia::Color::BLACK,
-                            )]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
-                }
-            },
+            Style::Gradient(gradient) => {
+                crate::gradient::into_shader(&gradient)
+            }
         },
         anti_alias: true,
         ..Default::default()
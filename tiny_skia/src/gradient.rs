@@ -0,0 +1,225 @@
+//! Turn gradients into `tiny-skia` shaders.
+use crate::core::gradient::ColorStop;
+use crate::core::{Color, Point, Rectangle, Transformation};
+use crate::engine::{into_color, into_transform};
+use crate::graphics::gradient::{self, Gradient};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::hash::{Hash, Hasher};
+
+/// Converts a [`Gradient`] into a `tiny-skia` shader.
+///
+/// `tiny-skia` has no conic shader, so a [`Gradient::Conic`] produces a
+/// transparent shader and must be drawn with a [`Pattern`] instead.
+pub fn into_shader(gradient: &Gradient) -> tiny_skia::Shader<'static> {
+    let into_point = |point: Point| tiny_skia::Point {
+        x: point.x,
+        y: point.y,
+    };
+
+    match gradient {
+        Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+            into_point(linear.start),
+            into_point(linear.end),
+            into_stops(&linear.stops),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::Transform::identity(),
+        )
+        .expect("Create linear gradient"),
+        Gradient::Radial(radial) => tiny_skia::RadialGradient::new(
+            into_point(radial.center),
+            into_point(radial.center),
+            radial.radius,
+            into_stops(&radial.stops),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::Transform::identity(),
+        )
+        .unwrap_or(tiny_skia::Shader::SolidColor(
+            tiny_skia::Color::TRANSPARENT,
+        )),
+        Gradient::Conic(_) => {
+            tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT)
+        }
+    }
+}
+
+fn into_stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<tiny_skia::GradientStop> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(stop.offset, into_color(stop.color))
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+/// A [`gradient::Conic`] rasterized into a pixmap, so it can be used as a
+/// pattern shader.
+#[derive(Debug)]
+pub struct Pattern {
+    pixmap: tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+}
+
+impl Pattern {
+    /// Rasterizes the given conic gradient over the given physical bounds.
+    ///
+    /// The gradient is in the same coordinates as the path it fills, which is
+    /// drawn with the given [`Transformation`].
+    pub fn conic(
+        conic: &gradient::Conic,
+        transformation: Transformation,
+        bounds: Rectangle,
+    ) -> Option<Self> {
+        let x = bounds.x.floor();
+        let y = bounds.y.floor();
+        let width = ((bounds.x + bounds.width).ceil() - x) as u32;
+        let height = ((bounds.y + bounds.height).ceil() - y) as u32;
+
+        let conic = gradient::Conic {
+            center: conic.center * transformation,
+            ..*conic
+        };
+
+        let stops: Vec<ColorStop> =
+            conic.stops.iter().flatten().copied().collect();
+
+        let colors = (0..height)
+            .flat_map(|row| {
+                (0..width).map(move |column| {
+                    Point::new(x + column as f32 + 0.5, y + row as f32 + 0.5)
+                })
+            })
+            .map(|position| {
+                let color = color_at(&stops, conic.offset(position));
+
+                into_color(color).to_color_u8().premultiply()
+            })
+            .collect();
+
+        let pixmap = tiny_skia::Pixmap::from_vec(
+            bytemuck::cast_vec(colors),
+            tiny_skia::IntSize::from_wh(width, height)?,
+        )?;
+
+        let transform =
+            into_transform(transformation).invert()?.pre_translate(x, y);
+
+        Some(Self { pixmap, transform })
+    }
+
+    /// Returns the shader of the [`Pattern`].
+    pub fn shader(&self) -> tiny_skia::Shader<'_> {
+        tiny_skia::Pattern::new(
+            self.pixmap.as_ref(),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::FilterQuality::Nearest,
+            1.0,
+            self.transform,
+        )
+    }
+}
+
+/// A cache of rasterized conic [`Pattern`]s.
+///
+/// Patterns that are not used during a frame are dropped when the cache is
+/// trimmed.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: FxHashMap<u64, Option<Pattern>>,
+    hits: FxHashSet<u64>,
+}
+
+impl Cache {
+    /// Returns the [`Pattern`] of the given conic gradient over the given
+    /// physical bounds, rasterizing it only if it is not cached already.
+    pub fn conic(
+        &mut self,
+        conic: &gradient::Conic,
+        transformation: Transformation,
+        bounds: Rectangle,
+    ) -> Option<&Pattern> {
+        let key = {
+            let mut hasher = FxHasher::default();
+
+            for value in [conic.center.x, conic.center.y, conic.angle.0]
+                .into_iter()
+                .chain(conic.stops.iter().flatten().flat_map(|stop| {
+                    [
+                        stop.offset,
+                        stop.color.r,
+                        stop.color.g,
+                        stop.color.b,
+                        stop.color.a,
+                    ]
+                }))
+                .chain(transformation.as_ref().iter().copied())
+                .chain([
+                    bounds.x.floor(),
+                    bounds.y.floor(),
+                    (bounds.x + bounds.width).ceil(),
+                    (bounds.y + bounds.height).ceil(),
+                ])
+            {
+                value.to_bits().hash(&mut hasher);
+            }
+
+            hasher.finish()
+        };
+
+        let _ = self.hits.insert(key);
+
+        self.entries
+            .entry(key)
+            .or_insert_with(|| Pattern::conic(conic, transformation, bounds))
+            .as_ref()
+    }
+
+    /// Drops the patterns that were not used since the last trim.
+    pub fn trim(&mut self) {
+        self.entries.retain(|key, _| self.hits.contains(key));
+        self.hits.clear();
+    }
+}
+
+/// Interpolates the color of the given sorted stops at the given offset.
+fn color_at(stops: &[ColorStop], offset: f32) -> Color {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Color::BLACK;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    for pair in stops.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+
+        if offset <= to.offset {
+            let span = to.offset - from.offset;
+
+            let factor = if span > 0.0 {
+                (offset - from.offset) / span
+            } else {
+                1.0
+            };
+
+            let mix = |a: f32, b: f32| a + (b - a) * factor;
+
+            return Color {
+                r: mix(from.color.r, to.color.r),
+                g: mix(from.color.g, to.color.g),
+                b: mix(from.color.b, to.color.b),
+                a: mix(from.color.a, to.color.a),
+            };
+        }
+    }
+
+    last.color
+}
@@ -1,3 +1,4 @@
+use crate::core::background;
 use crate::core::renderer::Quad;
 use crate::core::{
     self, Background, Color, Point, Rectangle, Svg, Transformation,
@@ -132,6 +133,18 @@ impl Layer {
             Image::Vector { handle, bounds } => {
                 self.draw_svg(handle, bounds, transformation);
             }
+            Image::Background {
+                image,
+                bounds,
+                border_radius,
+            } => {
+                self.draw_background_image(
+                    image,
+                    bounds,
+                    border_radius,
+                    transformation,
+                );
+            }
         }
     }
 
@@ -156,6 +169,24 @@ impl Layer {
         self.images.push(image);
     }
 
+    pub fn draw_background_image(
+        &mut self,
+        image: background::Image,
+        bounds: Rectangle,
+        border_radius: [f32; 4],
+        transformation: Transformation,
+    ) {
+        let scale_factor = transformation.scale_factor();
+
+        let image = Image::Background {
+            image,
+            bounds: bounds * transformation,
+            border_radius: border_radius.map(|radius| radius * scale_factor),
+        };
+
+        self.images.push(image);
+    }
+
     pub fn draw_svg(
         &mut self,
         svg: Svg,
@@ -306,6 +337,10 @@ impl graphics::Layer for Layer {
         }
     }
 
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
     fn flush(&mut self) {}
 
     fn resize(&mut self, bounds: Rectangle) {
@@ -3,6 +3,7 @@
 pub mod window;
 
 mod engine;
+mod gradient;
 mod layer;
 mod primitive;
 mod settings;
@@ -29,7 +30,8 @@ pub use geometry::Geometry;
 
 use crate::core::renderer;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Transformation,
+    Background, Border, Color, Font, Pixels, Point, Rectangle, Shadow, Size,
+    Transformation,
 };
 use crate::engine::Engine;
 use crate::graphics::compositor;
@@ -249,7 +251,51 @@ impl core::Renderer for Renderer {
         background: impl Into<Background>,
     ) {
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+
+        match background.into() {
+            Background::Image(image) => {
+                if quad.shadow.color.a > 0.0 {
+                    layer.draw_quad(
+                        renderer::Quad {
+                            border: Border {
+                                width: 0.0,
+                                ..quad.border
+                            },
+                            ..quad
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                        transformation,
+                    );
+                }
+
+                layer.draw_background_image(
+                    image,
+                    quad.bounds,
+                    quad.border.radius.into(),
+                    transformation,
+                );
+
+                // Images are drawn after quads, so anything drawn
+                // afterwards needs a new layer to stay on top of the image
+                self.layers.split();
+
+                if quad.border.width > 0.0 {
+                    let (layer, transformation) = self.layers.current_mut();
+
+                    layer.draw_quad(
+                        renderer::Quad {
+                            shadow: Shadow::default(),
+                            ..quad
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                        transformation,
+                    );
+                }
+            }
+            background => {
+                layer.draw_quad(quad, background, transformation);
+            }
+        }
     }
 
     fn clear(&mut self) {
@@ -257,6 +303,29 @@ impl core::Renderer for Renderer {
     }
 }
 
+impl renderer::Headless for Renderer {
+    fn new(default_font: Font, default_text_size: Pixels) -> Self {
+        Self::new(default_font, default_text_size)
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        let viewport =
+            Viewport::with_physical_size(size, f64::from(scale_factor));
+
+        window::compositor::screenshot::<&str>(
+            self,
+            &viewport,
+            background_color,
+            &[],
+        )
+    }
+}
+
 impl core::text::Renderer for Renderer {
     type Font = Font;
     type Paragraph = Paragraph;
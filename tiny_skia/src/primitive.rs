@@ -1,4 +1,5 @@
 use crate::core::Rectangle;
+use crate::graphics::gradient;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Primitive {
@@ -10,6 +11,11 @@ pub enum Primitive {
         paint: tiny_skia::Paint<'static>,
         /// The fill rule to follow.
         rule: tiny_skia::FillRule,
+        /// The conic gradient to fill with instead of the shader of the
+        /// `paint`, if any.
+        ///
+        /// `tiny-skia` has no conic shader, so it is rasterized when drawn.
+        conic: Option<gradient::Conic>,
     },
     /// A path stroked with some paint.
     Stroke {
@@ -19,6 +25,11 @@ pub enum Primitive {
         paint: tiny_skia::Paint<'static>,
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
+        /// The conic gradient to stroke with instead of the shader of the
+        /// `paint`, if any.
+        ///
+        /// `tiny-skia` has no conic shader, so it is rasterized when drawn.
+        conic: Option<gradient::Conic>,
     },
 }
 
@@ -604,6 +604,21 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                radial.center = self.transform_point(radial.center);
+                radial.radius = self
+                    .0
+                    .transform_vector(euclid::Vector2D::new(radial.radius, 0.0))
+                    .length();
+            }
+            Gradient::Conic(conic) => {
+                let (sin, cos) = conic.angle.0.sin_cos();
+                let direction =
+                    self.0.transform_vector(euclid::Vector2D::new(sin, -cos));
+
+                conic.center = self.transform_point(conic.center);
+                conic.angle = Radians(f32::atan2(direction.x, -direction.y));
+            }
         }
 
         gradient
@@ -151,6 +151,10 @@ impl Pipeline {
                             7 => Sint32,
                             // Snap
                             8 => Uint32,
+                            // Clip bounds
+                            9 => Float32x4,
+                            // Border radius
+                            10 => Float32x4,
                         ),
                     }],
                     compilation_options:
@@ -235,6 +239,8 @@ impl Pipeline {
                             f32::from(handle.rotation),
                             handle.opacity,
                             handle.snap,
+                            *bounds,
+                            handle.border_radius,
                             atlas_entry,
                             match handle.filter_method {
                                 crate::core::image::FilterMethod::Nearest => {
@@ -250,6 +256,42 @@ impl Pipeline {
                 #[cfg(not(feature = "image"))]
                 Image::Raster { .. } => {}
 
+                #[cfg(feature = "image")]
+                Image::Background {
+                    image,
+                    bounds,
+                    border_radius,
+                } => {
+                    if let Some(atlas_entry) =
+                        cache.upload_raster(device, encoder, &image.handle)
+                    {
+                        let instances = match image.filter_method {
+                            crate::core::image::FilterMethod::Nearest => {
+                                &mut *nearest_instances
+                            }
+                            crate::core::image::FilterMethod::Linear => {
+                                &mut *linear_instances
+                            }
+                        };
+
+                        for tile in image.tiles(atlas_entry.size(), *bounds) {
+                            add_instances(
+                                [tile.x, tile.y],
+                                [tile.width, tile.height],
+                                0.0,
+                                image.opacity,
+                                false,
+                                *bounds,
+                                *border_radius,
+                                atlas_entry,
+                                instances,
+                            );
+                        }
+                    }
+                }
+                #[cfg(not(feature = "image"))]
+                Image::Background { .. } => {}
+
                 #[cfg(feature = "svg")]
                 Image::Vector { handle, bounds } => {
                     let size = [bounds.width, bounds.height];
@@ -268,6 +310,8 @@ impl Pipeline {
                             f32::from(handle.rotation),
                             handle.opacity,
                             true,
+                            *bounds,
+                            [0.0; 4],
                             atlas_entry,
                             nearest_instances,
                         );
@@ -497,6 +541,8 @@ struct Instance {
     _size_in_atlas: [f32; 2],
     _layer: u32,
     _snap: u32,
+    _clip_bounds: [f32; 4],
+    _border_radius: [f32; 4],
 }
 
 impl Instance {
@@ -513,12 +559,22 @@ struct Uniforms {
     _padding: [f32; 3],
 }
 
+/// The rounded bounds an image is clipped to, in the unrotated space of the
+/// image.
+#[derive(Debug, Clone, Copy)]
+struct Clip {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+}
+
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
     rotation: f32,
     opacity: f32,
     snap: bool,
+    clip_bounds: Rectangle,
+    border_radius: [f32; 4],
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
@@ -527,6 +583,16 @@ fn add_instances(
         image_position[1] + image_size[1] / 2.0,
     ];
 
+    let clip = Clip {
+        bounds: [
+            clip_bounds.x,
+            clip_bounds.y,
+            clip_bounds.width,
+            clip_bounds.height,
+        ],
+        border_radius,
+    };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
             add_instance(
@@ -536,6 +602,7 @@ fn add_instances(
                 rotation,
                 opacity,
                 snap,
+                clip,
                 allocation,
                 instances,
             );
@@ -565,7 +632,7 @@ fn add_instances(
                 ];
 
                 add_instance(
-                    position, center, size, rotation, opacity, snap,
+                    position, center, size, rotation, opacity, snap, clip,
                     allocation, instances,
                 );
             }
@@ -581,6 +648,7 @@ fn add_instance(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    clip: Clip,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -604,6 +672,8 @@ fn add_instance(
         ],
         _layer: layer as u32,
         _snap: snap as u32,
+        _clip_bounds: clip.bounds,
+        _border_radius: clip.border_radius,
     };
 
     instances.push(instance);
@@ -455,7 +455,69 @@ impl core::Renderer for Renderer {
         background: impl Into<Background>,
     ) {
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+
+        match background.into() {
+            #[cfg(feature = "image")]
+            Background::Image(image) => {
+                if quad.shadow.color.a > 0.0 {
+                    layer.draw_quad(
+                        core::renderer::Quad {
+                            border: core::Border {
+                                width: 0.0,
+                                ..quad.border
+                            },
+                            ..quad
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                        transformation,
+                    );
+                }
+
+                layer.draw_background_image(
+                    image,
+                    quad.bounds,
+                    quad.border.radius.into(),
+                    transformation,
+                );
+
+                // Images are drawn after quads, so anything drawn
+                // afterwards needs a new layer to stay on top of the image
+                self.layers.split();
+
+                if quad.border.width > 0.0 {
+                    let (layer, transformation) = self.layers.current_mut();
+
+                    layer.draw_quad(
+                        core::renderer::Quad {
+                            shadow: core::Shadow::default(),
+                            ..quad
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                        transformation,
+                    );
+                }
+            }
+            #[cfg(not(feature = "image"))]
+            Background::Image(_) => {
+                static WARNING: std::sync::Once = std::sync::Once::new();
+
+                WARNING.call_once(|| {
+                    log::warn!(
+                        "Image backgrounds need the `image` feature of \
+                        `iced_wgpu`; drawing them as transparent"
+                    );
+                });
+
+                layer.draw_quad(
+                    quad,
+                    Background::Color(Color::TRANSPARENT),
+                    transformation,
+                );
+            }
+            background => {
+                layer.draw_quad(quad, background, transformation);
+            }
+        }
     }
 
     fn clear(&mut self) {
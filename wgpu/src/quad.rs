@@ -273,6 +273,10 @@ impl Batch {
 
                 Kind::Gradient
             }
+            Background::Image(_) => {
+                // Image backgrounds are drawn by the image pipeline
+                return;
+            }
         };
 
         match self.order.last_mut() {
@@ -18,6 +18,8 @@ struct VertexInput {
     @location(6) atlas_scale: vec2<f32>,
     @location(7) layer: i32,
     @location(8) snap: u32,
+    @location(9) clip_bounds: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -25,6 +27,9 @@ struct VertexOutput {
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
     @location(2) opacity: f32,
+    @location(3) clip_position: vec2<f32>,
+    @location(4) clip_bounds: vec4<f32>,
+    @location(5) border_radius: vec4<f32>,
 }
 
 @vertex
@@ -39,6 +44,11 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.layer = f32(input.layer);
     out.opacity = input.opacity;
 
+    // Clip in the unrotated space of the image, in physical pixels
+    out.clip_position = (input.pos + v_pos * input.scale) * globals.scale_factor;
+    out.clip_bounds = input.clip_bounds * globals.scale_factor;
+    out.border_radius = input.border_radius * globals.scale_factor;
+
     // Calculate the vertex position and move the center to the origin
     v_pos = input.pos + v_pos * input.scale - input.center;
 
@@ -67,5 +77,38 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the texture at the given UV coordinate and layer.
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
+    let color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    // Clip the image to its rounded clip bounds.
+    let half_size = input.clip_bounds.zw / 2.0;
+    let center = input.clip_bounds.xy + half_size;
+
+    let radius = select_border_radius(
+        input.border_radius,
+        input.clip_position,
+        center
+    );
+
+    let distance = rounded_box_sdf(
+        input.clip_position - center,
+        half_size,
+        min(radius, min(half_size.x, half_size.y))
+    );
+
+    let coverage = clamp(0.5 - distance, 0.0, 1.0);
+
+    return color * vec4<f32>(1.0, 1.0, 1.0, input.opacity * coverage);
+}
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+fn select_border_radius(radii: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = radii.x;
+    var ry = radii.y;
+    rx = select(radii.x, radii.y, position.x > center.x);
+    ry = select(radii.w, radii.z, position.x > center.x);
+    rx = select(rx, ry, position.y > center.y);
+    return rx;
 }
@@ -6,10 +6,11 @@ struct GradientVertexInput {
     @location(3) @interpolate(flat) colors_4: vec4<u32>,
     @location(4) @interpolate(flat) offsets: vec4<u32>,
     @location(5) direction: vec4<f32>,
-    @location(6) position_and_scale: vec4<f32>,
-    @location(7) border_color: vec4<f32>,
-    @location(8) border_radius: vec4<f32>,
-    @location(9) border_width: f32,
+    @location(6) kind: u32,
+    @location(7) position_and_scale: vec4<f32>,
+    @location(8) border_color: vec4<f32>,
+    @location(9) border_radius: vec4<f32>,
+    @location(10) border_width: f32,
 }
 
 struct GradientVertexOutput {
@@ -24,6 +25,7 @@ struct GradientVertexOutput {
     @location(8) border_color: vec4<f32>,
     @location(9) border_radius: vec4<f32>,
     @location(10) border_width: f32,
+    @location(11) @interpolate(flat) kind: u32,
 }
 
 @vertex
@@ -54,7 +56,13 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_3 = input.colors_3;
     out.colors_4 = input.colors_4;
     out.offsets = input.offsets;
-    out.direction = input.direction * globals.scale;
+    // The angle of a conic gradient is not affected by scaling
+    out.direction = select(
+        input.direction * globals.scale,
+        vec4<f32>(input.direction.xy * globals.scale, input.direction.zw),
+        input.kind == 2u
+    );
+    out.kind = input.kind;
     out.position_and_scale = vec4<f32>(pos, scale);
     out.border_color = input.border_color;
     out.border_radius = border_radius * globals.scale;
@@ -67,21 +75,44 @@ fn random(coords: vec2<f32>) -> f32 {
     return fract(sin(dot(coords, vec2(12.9898,78.233))) * 43758.5453);
 }
 
+/// Returns the offset of the given position along a gradient of the given kind
+fn gradient_offset(raw_position: vec2<f32>, direction: vec4<f32>, kind: u32) -> f32 {
+    var offset: f32;
+
+    switch kind {
+        // Radial: direction holds the center and the radius
+        case 1u: {
+            offset = distance(raw_position, direction.xy) / direction.z;
+        }
+        // Conic: direction holds the center and the starting angle
+        case 2u: {
+            let v = raw_position - direction.xy;
+
+            offset = fract((atan2(v.y, v.x) - direction.z) / 6.2831855);
+        }
+        // Linear: direction holds the start and end points
+        default: {
+            let v1 = direction.zw - direction.xy;
+            let v2 = raw_position - direction.xy;
+            let unit = normalize(v1);
+
+            offset = dot(unit, v2) / length(v1);
+        }
+    }
+
+    return offset;
+}
+
 /// Returns the current interpolated color with a max 8-stop gradient
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    kind: u32,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    let coord_offset = gradient_offset(raw_position, direction, kind);
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -152,7 +183,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.kind, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
@@ -0,0 +1,267 @@
+//! Scroll a [`Scrollable`] on behalf of its content.
+//!
+//! [`Scrollable`]: crate::Scrollable
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{Id, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A scroll requested by the content of a [`Scrollable`].
+///
+/// [`Scrollable`]: crate::Scrollable
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scroll {
+    /// Scroll to the given vertical offset.
+    To(f32),
+    /// Scroll by the given vertical distance.
+    By(f32),
+}
+
+/// A [`Scrollable`] that scrolls whenever its content asks for it.
+///
+/// Widgets inside a [`Scrollable`] cannot change its offset, so they leave
+/// a [`Scroll`] in their state instead. Corrections are picked up right
+/// after every layout, while requested scrolls wait for the next redraw.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub struct Anchored<'a, Message, Theme, Renderer> {
+    scrollable: Element<'a, Message, Theme, Renderer>,
+    correction: fn(&mut Tree) -> Option<Scroll>,
+    request: fn(&mut Tree) -> Option<Scroll>,
+}
+
+impl<'a, Message, Theme, Renderer> Anchored<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Anchored`] scrollable, which takes the pending
+    /// [`Scroll`]s out of the [`Tree`] of its content with the given
+    /// functions: `correction` after every layout and `request` before every
+    /// redraw.
+    pub fn new(
+        scrollable: impl Into<Element<'a, Message, Theme, Renderer>>,
+        correction: fn(&mut Tree) -> Option<Scroll>,
+        request: fn(&mut Tree) -> Option<Scroll>,
+    ) -> Self {
+        Self {
+            scrollable: scrollable.into(),
+            correction,
+            request,
+        }
+    }
+
+    fn settle(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        pending: fn(&mut Tree) -> Option<Scroll>,
+    ) {
+        let Some(mut scroll) = pending(&mut tree.children[0].children[0])
+        else {
+            return;
+        };
+
+        self.scrollable.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut scroll,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Anchored<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.scrollable)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.scrollable));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.scrollable.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self.scrollable.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        self.settle(tree, Layout::new(&node), renderer, self.correction);
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.scrollable.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            self.settle(tree, layout, renderer, self.request);
+        }
+
+        self.scrollable.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.scrollable.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.scrollable.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.scrollable.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.scrollable.as_widget().a11y_nodes(
+            layout,
+            &state.children[0],
+            cursor,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Anchored<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(anchored: Anchored<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(anchored)
+    }
+}
+
+/// Scrolls the first scrollable widget it finds, without looking into its
+/// contents.
+impl Operation for Scroll {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        match *self {
+            Scroll::To(y) => state.scroll_to(AbsoluteOffset { x: 0.0, y }),
+            Scroll::By(y) => state.scroll_by(
+                AbsoluteOffset { x: 0.0, y },
+                bounds,
+                content_bounds,
+            ),
+        }
+    }
+}
@@ -23,6 +23,7 @@ use iced_runtime::{keyboard, task, Task};
 #[cfg(feature = "a11y")]
 use std::borrow::Cow;
 
+use crate::core::animation::{Animation, Easing, Interpolable};
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::layout;
@@ -30,13 +31,16 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
     Shadow, Shell, Size, Theme, Vector, Widget,
 };
+use crate::focus_ring;
 
 use iced_renderer::core::widget::operation;
 
@@ -261,11 +265,46 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The duration of the transition between two statuses of a [`Button`].
+const TRANSITION_DURATION: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct State {
     is_hovered: bool,
     is_pressed: bool,
     is_focused: bool,
+    status: Option<Status>,
+    transition: Option<(Status, Animation<f32>)>,
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            is_hovered: false,
+            is_pressed: false,
+            is_focused: false,
+            status: None,
+            transition: None,
+            now: Instant::now(),
+        }
+    }
+}
+
+impl State {
+    fn status(&self, is_enabled: bool, is_mouse_over: bool) -> Status {
+        if !is_enabled {
+            Status::Disabled
+        } else if is_mouse_over {
+            if self.is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -326,14 +365,24 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
-            self.content.as_widget().operate(
-                &mut tree.children[0],
-                layout.children().next().unwrap(),
-                renderer,
-                operation,
-            );
-        });
+        if self.on_press.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable(state, Some(&self.id));
+        }
+
+        operation.container(
+            Some(&self.id),
+            layout.bounds(),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            },
+        );
     }
 
     fn on_event(
@@ -361,18 +410,51 @@ where
         }
 
         match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let status = state.status(
+                    self.on_press.is_some(),
+                    cursor.is_over(layout.bounds()),
+                );
+
+                if let Some(previous) =
+                    state.status.filter(|previous| *previous != status)
+                {
+                    let animation = Animation::new(0.0)
+                        .duration(TRANSITION_DURATION)
+                        .easing(Easing::EaseOut)
+                        .go(1.0, now);
+
+                    state.transition = Some((previous, animation));
+                }
+
+                state.status = Some(status);
+                state.now = now;
+
+                if let Some((_, animation)) = state.transition {
+                    match animation.redraw_request(now) {
+                        Some(redraw_request) => {
+                            shell.request_redraw(redraw_request);
+                        }
+                        None => {
+                            state.transition = None;
+                        }
+                    }
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
-                    let bounds = layout.bounds();
+                let state = tree.state.downcast_mut::<State>();
+                let is_over = cursor.is_over(layout.bounds());
 
-                    if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
+                if !is_over {
+                    state.is_focused = false;
+                }
 
-                        state.is_pressed = true;
+                if self.on_press.is_some() && is_over {
+                    state.is_pressed = true;
 
-                        return event::Status::Captured;
-                    }
+                    return event::Status::Captured;
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -415,14 +497,18 @@ where
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
                 if let Some(on_press) = self.on_press.as_ref() {
                     let state = tree.state.downcast_mut::<State>();
+
                     if state.is_focused
                         && matches!(
                             key,
-                            keyboard::Key::Named(keyboard::key::Named::Enter)
+                            keyboard::Key::Named(
+                                keyboard::key::Named::Enter
+                                    | keyboard::key::Named::Space
+                            )
                         )
                     {
-                        state.is_pressed = true;
                         shell.publish(on_press.get());
+
                         return event::Status::Captured;
                     }
                 }
@@ -452,23 +538,20 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let is_mouse_over = cursor.is_over(bounds);
+        let state = tree.state.downcast_ref::<State>();
 
-        let status = if self.on_press.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            let state = tree.state.downcast_ref::<State>();
+        let status = state.status(self.on_press.is_some(), is_mouse_over);
 
-            if state.is_pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
+        let style = match state.transition {
+            Some((from, animation)) if animation.is_animating(state.now) => {
+                theme.style(&self.class, from).interpolate(
+                    &theme.style(&self.class, status),
+                    animation.value(state.now),
+                )
             }
-        } else {
-            Status::Active
+            _ => theme.style(&self.class, status),
         };
 
-        let style = theme.style(&self.class, status);
-
         if style.background.is_some()
             || style.border.width > 0.0
             || style.shadow.color.a > 0.0
@@ -506,6 +589,17 @@ where
             cursor,
             &viewport,
         );
+
+        if state.is_focused && self.on_press.is_some() {
+            if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    style.border.radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -662,7 +756,7 @@ pub enum Status {
 ///
 /// If not specified with [`Button::style`]
 /// the theme will provide the style.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the button.
     pub background: Option<Background>,
@@ -690,26 +784,6 @@ impl Style {
             ..self
         }
     }
-
-    // /// Returns whether the [`Button`] is currently focused or not.
-    // pub fn is_focused(&self) -> bool {
-    //     self.is_focused
-    // }
-
-    // /// Returns whether the [`Button`] is currently hovered or not.
-    // pub fn is_hovered(&self) -> bool {
-    //     self.is_hovered
-    // }
-
-    // /// Focuses the [`Button`].
-    // pub fn focus(&mut self) {
-    //     self.is_focused = true;
-    // }
-
-    // /// Unfocuses the [`Button`].
-    // pub fn unfocus(&mut self) {
-    //     self.is_focused = false;
-    // }
 }
 
 impl Default for Style {
@@ -727,6 +801,49 @@ impl Default for Style {
     }
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        let background = match (&self.background, &other.background) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, ratio)),
+            (Some(a), None) => {
+                Some(a.interpolate(&a.clone().scale_alpha(0.0), ratio))
+            }
+            (None, Some(b)) => {
+                Some(b.clone().scale_alpha(0.0).interpolate(b, ratio))
+            }
+            (None, None) => None,
+        };
+
+        let icon_color = match (self.icon_color, other.icon_color) {
+            (Some(a), Some(b)) => Some(a.interpolate(&b, ratio)),
+            (a, b) => {
+                if ratio < 0.5 {
+                    a
+                } else {
+                    b
+                }
+            }
+        };
+
+        Self {
+            background,
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, ratio),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, ratio),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, ratio),
+            icon_color,
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
+        }
+    }
+}
+
 /// The theme catalog of a [`Button`].
 ///
 /// All themes that can be used with [`Button`]
@@ -785,6 +902,15 @@ pub trait Catalog {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Button`] of the given class,
+    /// if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Button`].
@@ -800,6 +926,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// A primary button; denoting a main action.
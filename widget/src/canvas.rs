@@ -229,6 +229,7 @@ where
         let canvas_event = match event {
             core::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
             core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Pen(pen_event) => Some(Event::Pen(pen_event)),
             core::Event::Keyboard(keyboard_event) => {
                 Some(Event::Keyboard(keyboard_event))
             }
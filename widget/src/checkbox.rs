@@ -37,6 +37,7 @@ use std::borrow::Cow;
 
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -44,11 +45,13 @@ use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     id::Internal, Background, Border, Clipboard, Color, Element, Layout,
     Length, Pixels, Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::focus_ring;
 
 /// A box that can be checked.
 ///
@@ -297,11 +300,11 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -322,12 +325,11 @@ where
             self.spacing,
             |_| layout::Node::new(crate::core::Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -345,9 +347,23 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.on_toggle.is_some() {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+            operation.focusable(state, Some(&self.id));
+        }
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -356,6 +372,8 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -366,6 +384,21 @@ where
                         shell.publish((on_toggle)(!self.is_checked));
                         return event::Status::Captured;
                     }
+                } else {
+                    state.is_focused = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        keyboard::key::Named::Space
+                        | keyboard::key::Named::Enter,
+                    ),
+                ..
+            }) if state.is_focused => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish((on_toggle)(!self.is_checked));
+                    return event::Status::Captured;
                 }
             }
             _ => {}
@@ -402,6 +435,7 @@ where
         let is_mouse_over = cursor.is_over(layout.bounds());
         let is_disabled = self.on_toggle.is_none();
         let is_checked = self.is_checked;
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         let mut children = layout.children();
 
@@ -456,18 +490,27 @@ where
                     *viewport,
                 );
             }
+
+            if state.is_focused && !is_disabled {
+                if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                    focus_ring::draw(
+                        renderer,
+                        bounds,
+                        style.border.radius,
+                        focus_ring,
+                    );
+                }
+            }
         }
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -589,6 +632,27 @@ pub struct Icon<Font> {
     pub wrap: text::Wrapping,
 }
 
+/// The local state of a [`Checkbox`].
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    label: widget::text::State<P>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Checkbox`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -610,7 +674,7 @@ pub enum Status {
 }
 
 /// The style of a checkbox.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the checkbox.
     pub background: Background,
@@ -632,6 +696,15 @@ pub trait Catalog: Sized {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Checkbox`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Checkbox`].
@@ -649,6 +722,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// A primary checkbox; denoting a main toggle.
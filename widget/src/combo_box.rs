@@ -63,10 +63,11 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::Instant;
-use crate::core::widget::{self, Widget};
+use crate::core::widget::{self, Operation, Widget};
 use crate::core::{
     Clipboard, Element, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
 };
+use crate::focus_ring;
 use crate::overlay::menu;
 use crate::text::LineHeight;
 use crate::text_input::{self, TextInput};
@@ -74,6 +75,9 @@ use crate::text_input::{self, TextInput};
 use std::cell::RefCell;
 use std::fmt::Display;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A widget for searching and selecting a single value from a list of options.
 ///
 /// # Example
@@ -319,6 +323,30 @@ where
         self.menu_class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`ComboBox`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.text_input = self.text_input.name(name);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ComboBox`].
+    pub fn description_widget<D: iced_accessibility::Describes>(
+        mut self,
+        description: &D,
+    ) -> Self {
+        self.text_input = self.text_input.description_widget(description);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ComboBox`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.text_input = self.text_input.description(description);
+        self
+    }
 }
 
 /// The local state of a [`ComboBox`].
@@ -509,6 +537,22 @@ where
         vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        Widget::<TextInputEvent, Theme, Renderer>::operate(
+            &self.text_input,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
@@ -815,6 +859,21 @@ where
             selection,
             viewport,
         );
+
+        if is_focused {
+            if let Some(focus_ring) = <Theme as Catalog>::focus_ring(theme) {
+                let style = self
+                    .text_input
+                    .style_of(theme, text_input::Status::Focused);
+
+                focus_ring::draw(
+                    renderer,
+                    layout.bounds(),
+                    style.border.radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -885,6 +944,56 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::accesskit::Role;
+
+        let is_expanded = {
+            let text_input_state = state.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+            let menu = state.state.downcast_ref::<Menu<T>>();
+
+            text_input_state.is_focused()
+                && !menu.filtered_options.options.is_empty()
+        };
+
+        let mut tree = Widget::<TextInputEvent, Theme, Renderer>::a11y_nodes(
+            &self.text_input,
+            layout,
+            &state.children[0],
+            cursor,
+        );
+
+        // The input itself takes the combo box role, as in the ARIA
+        // 1.2 combobox pattern.
+        if let Some(root) = tree.root_mut().first_mut() {
+            let node = root.node_mut();
+            node.set_role(Role::ComboBox);
+            node.set_expanded(is_expanded);
+        }
+
+        tree
+    }
+
+    #[cfg(feature = "a11y")]
+    fn id(&self) -> Option<widget::Id> {
+        Widget::<TextInputEvent, Theme, Renderer>::id(&self.text_input)
+    }
+
+    #[cfg(feature = "a11y")]
+    fn set_id(&mut self, id: widget::Id) {
+        Widget::<TextInputEvent, Theme, Renderer>::set_id(
+            &mut self.text_input,
+            id,
+        );
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer>
@@ -912,9 +1021,18 @@ pub trait Catalog: text_input::Catalog + menu::Catalog {
     fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
         <Self as menu::Catalog>::default()
     }
+
+    /// The [`focus_ring::Style`] of a focused [`ComboBox`], if any.
+    fn focus_ring(&self) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
-impl Catalog for Theme {}
+impl Catalog for Theme {
+    fn focus_ring(&self) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
+}
 
 fn search<'a, T, A>(
     options: impl IntoIterator<Item = T> + 'a,
@@ -20,6 +20,7 @@
 //! }
 //! ```
 use crate::core::alignment::{self, Alignment};
+use crate::core::background;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::gradient::{self, Gradient};
@@ -486,6 +487,7 @@ pub fn draw_background<Renderer>(
             },
             style
                 .background
+                .clone()
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
@@ -582,7 +584,7 @@ pub fn visible_bounds(id: Id) -> Task<Option<Rectangle>> {
 }
 
 /// The appearance of a container.
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Style {
     /// The icon [`Color`] of the container.
     pub icon_color: Option<Color>,
@@ -650,6 +652,24 @@ impl From<gradient::Linear> for Style {
     }
 }
 
+impl From<gradient::Radial> for Style {
+    fn from(gradient: gradient::Radial) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
+impl From<gradient::Conic> for Style {
+    fn from(gradient: gradient::Conic) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
+impl From<background::Image> for Style {
+    fn from(image: background::Image) -> Self {
+        Self::default().background(image)
+    }
+}
+
 /// The theme catalog of a [`Container`].
 pub trait Catalog {
     /// The item class of the [`Catalog`].
@@ -667,7 +687,7 @@ pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 impl<'a, Theme> From<Style> for StyleFn<'a, Theme> {
     fn from(style: Style) -> Self {
-        Box::new(move |_theme| style)
+        Box::new(move |_theme| style.clone())
     }
 }
 
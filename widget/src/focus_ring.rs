@@ -0,0 +1,306 @@
+//! Outline widgets focused with the keyboard.
+//!
+//! Interactive widgets that can be focused with [`focus_next`] and
+//! [`focus_previous`] draw a focus ring around themselves while focused, so
+//! users can tell where keyboard input will go.
+//!
+//! [`focus_next`]: crate::core::widget::operation::focusable::focus_next
+//! [`focus_previous`]: crate::core::widget::operation::focusable::focus_previous
+use crate::core::border::{self, Border};
+use crate::core::renderer;
+use crate::core::{Color, Rectangle, Theme};
+
+/// The appearance of a focus ring.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The gap between the bounds of the widget and the ring.
+    pub offset: f32,
+}
+
+/// The default focus ring of a [`Theme`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        color: palette.primary.strong.color,
+        width: 2.0,
+        offset: 2.0,
+    }
+}
+
+/// Draws a focus ring with the given [`Style`] around the given bounds.
+///
+/// The `radius` is the border radius of the focused widget; the ring follows
+/// its corners.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    radius: border::Radius,
+    style: Style,
+) where
+    Renderer: renderer::Renderer,
+{
+    if style.width <= 0.0 || style.color.a == 0.0 {
+        return;
+    }
+
+    let spread = style.offset + style.width;
+    let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+        radius.into();
+
+    let grow = |radius: f32| {
+        if radius > 0.0 {
+            radius + spread
+        } else {
+            0.0
+        }
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: bounds.expand(spread),
+            border: Border {
+                color: style.color,
+                width: style.width,
+                radius: border::Radius {
+                    top_left: grow(top_left),
+                    top_right: grow(top_right),
+                    bottom_right: grow(bottom_right),
+                    bottom_left: grow(bottom_left),
+                },
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::clipboard;
+    use crate::core::event::{self, Event};
+    use crate::core::keyboard;
+    use crate::core::layout;
+    use crate::core::mouse;
+    use crate::core::widget::operation::{self, focusable, Operation};
+    use crate::core::widget::{self, Tree};
+    use crate::core::{Element, Layout, Rectangle, Shell, Size, Theme, Widget};
+    use crate::{button, column, Radio, Slider, VerticalSlider};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        Decrement,
+        Selected(u8),
+        Changed(f32),
+    }
+
+    fn layout(
+        element: &Element<'_, Message, Theme, ()>,
+        tree: &mut Tree,
+    ) -> crate::core::layout::Node {
+        element.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        )
+    }
+
+    fn operate(
+        element: &Element<'_, Message, Theme, ()>,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: impl Operation + 'static,
+    ) {
+        let mut operation: Box<dyn Operation> = Box::new(operation);
+
+        loop {
+            element
+                .as_widget()
+                .operate(tree, layout, &(), operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+                operation::Outcome::None | operation::Outcome::Some(()) => {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn press(
+        element: &mut Element<'_, Message, Theme, ()>,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        key: keyboard::key::Named,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let key = keyboard::Key::Named(key);
+        let mut shell = Shell::new(messages);
+
+        element.as_widget_mut().on_event(
+            tree,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: keyboard::key::Physical::Unidentified(
+                    keyboard::key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+            }),
+            layout,
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITY),
+        )
+    }
+
+    #[test]
+    fn keyboard_focus_traversal_activates_widgets() {
+        let mut element: Element<'_, Message, Theme, ()> = column![
+            button("Increment").on_press(Message::Increment),
+            button("Decrement").on_press(Message::Decrement),
+        ]
+        .into();
+
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &mut tree);
+        let layout = Layout::new(&node);
+        let mut messages = Vec::new();
+
+        operate(&element, &mut tree, layout, focusable::focus_next());
+        operate(&element, &mut tree, layout, focusable::focus_next());
+
+        let status = press(
+            &mut element,
+            &mut tree,
+            layout,
+            keyboard::key::Named::Space,
+            &mut messages,
+        );
+
+        operate(&element, &mut tree, layout, focusable::focus_previous());
+
+        let _ = press(
+            &mut element,
+            &mut tree,
+            layout,
+            keyboard::key::Named::Enter,
+            &mut messages,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Decrement, Message::Increment]);
+    }
+
+    #[test]
+    fn arrow_keys_select_radio_options_within_group() {
+        let radios: Vec<Radio<'_, Message, Theme, ()>> = Radio::group(
+            [("One", 1), ("Two", 2), ("Three", 3)],
+            Some(1),
+            Message::Selected,
+        );
+
+        let mut element: Element<'_, Message, Theme, ()> =
+            column(radios.into_iter().map(Element::from)).into();
+
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &mut tree);
+        let layout = Layout::new(&node);
+        let mut messages = Vec::new();
+        let mut press_key = |element: &mut Element<'_, Message, Theme, ()>,
+                             tree: &mut Tree,
+                             key| {
+            press(element, tree, layout, key, &mut messages)
+        };
+
+        operate(&element, &mut tree, layout, focusable::focus_next());
+
+        let first =
+            press_key(&mut element, &mut tree, keyboard::key::Named::ArrowUp);
+        let down =
+            press_key(&mut element, &mut tree, keyboard::key::Named::ArrowDown);
+
+        operate(&element, &mut tree, layout, focusable::focus_next());
+
+        let _ = press_key(
+            &mut element,
+            &mut tree,
+            keyboard::key::Named::ArrowRight,
+        );
+
+        operate(&element, &mut tree, layout, focusable::focus_next());
+
+        let last =
+            press_key(&mut element, &mut tree, keyboard::key::Named::ArrowDown);
+        let _ =
+            press_key(&mut element, &mut tree, keyboard::key::Named::ArrowLeft);
+
+        assert_eq!(first, event::Status::Ignored);
+        assert_eq!(down, event::Status::Captured);
+        assert_eq!(last, event::Status::Ignored);
+        assert_eq!(
+            messages,
+            vec![
+                Message::Selected(2),
+                Message::Selected(3),
+                Message::Selected(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_keys_change_focused_slider_values() {
+        let id = widget::Id::new("vertical");
+        let mut vertical_slider =
+            VerticalSlider::new(0.0..=10.0, 5.0, Message::Changed);
+        Widget::<Message, Theme, ()>::set_id(&mut vertical_slider, id.clone());
+
+        let mut element: Element<'_, Message, Theme, ()> = column![
+            Slider::new(0.0..=10.0, 5.0, Message::Changed),
+            vertical_slider,
+        ]
+        .into();
+
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &mut tree);
+        let layout = Layout::new(&node);
+        let mut messages = Vec::new();
+
+        operate(&element, &mut tree, layout, focusable::focus_next());
+
+        for key in [keyboard::key::Named::ArrowRight, keyboard::key::Named::End]
+        {
+            let _ = press(&mut element, &mut tree, layout, key, &mut messages);
+        }
+
+        operate(&element, &mut tree, layout, focusable::focus(id));
+
+        let _ = press(
+            &mut element,
+            &mut tree,
+            layout,
+            keyboard::key::Named::ArrowDown,
+            &mut messages,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Changed(6.0),
+                Message::Changed(10.0),
+                Message::Changed(4.0)
+            ]
+        );
+    }
+}
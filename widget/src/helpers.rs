@@ -18,12 +18,15 @@ use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
+use crate::virtual_list::VirtualList;
 use crate::{Column, MouseArea, Row, Space, Stack, Themer};
 
 use std::borrow::Borrow;
@@ -1902,3 +1905,118 @@ where
 {
     PaneGrid::new(state, view)
 }
+
+/// Creates a new [`Table`] for the given rows.
+///
+/// Add columns to it with [`Table::push`] and [`table::column`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{table, text};
+///
+/// struct Fruit {
+///     name: &'static str,
+///     price: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+/// }
+///
+/// fn view(fruits: &[Fruit]) -> Element<'_, Message> {
+///     table(fruits)
+///         .push(table::column("Fruit", |fruit| text(fruit.name)))
+///         .push(table::column("Price", |fruit| {
+///             text(format!("{:.2}", fruit.price))
+///         }))
+///         .on_select(Message::Select)
+///         .into()
+/// }
+/// ```
+pub fn table<'a, T, Message, Theme, Renderer>(
+    rows: &'a [T],
+) -> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: table::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Table::new(rows)
+}
+
+/// Creates a new [`TreeView`] with the given root items, a closure that
+/// identifies an item and a closure that produces its row.
+///
+/// Describe the hierarchy with [`TreeView::children`] and drive it with
+/// [`TreeView::expanded`] and [`TreeView::on_toggle`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::tree_view::Children;
+/// use iced::widget::{text, tree_view};
+///
+/// struct Node {
+///     name: String,
+///     children: Vec<Node>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle(String, bool),
+/// }
+///
+/// fn view<'a>(
+///     nodes: &'a [Node],
+///     expanded: &'a [String],
+/// ) -> Element<'a, Message> {
+///     tree_view(nodes, |node| node.name.clone(), |node| text(&node.name))
+///         .children(|node| Children::Loaded(&node.children))
+///         .expanded(|name| expanded.contains(name))
+///         .on_toggle(Message::Toggle)
+///         .into()
+/// }
+/// ```
+pub fn tree_view<'a, T, Key, Message, Theme, Renderer, E>(
+    roots: &'a [T],
+    key: impl Fn(&'a T) -> Key + 'a,
+    view: impl Fn(&'a T) -> E + 'a,
+) -> TreeView<'a, T, Key, Message, Theme, Renderer>
+where
+    E: Into<Element<'a, Message, Theme, Renderer>>,
+    Theme: tree_view::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TreeView::new(roots, key, view)
+}
+
+/// Creates a new [`VirtualList`] with the given amount of items, built by
+/// the given closure from their index.
+///
+/// Only the items visible inside of it are built and laid out.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, virtual_list};
+///
+/// enum Message {}
+///
+/// fn view(lines: &[String]) -> Element<'_, Message> {
+///     virtual_list(lines.len(), |index| text(&lines[index]).into()).into()
+/// }
+/// ```
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    len: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Theme: scrollable::Catalog,
+    Renderer: core::Renderer,
+{
+    VirtualList::new(len, view)
+}
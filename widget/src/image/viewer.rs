@@ -94,6 +94,51 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Scales the image of the [`Viewer`] to the given scale, keeping the
+    /// point under the cursor in place.
+    fn zoom<Renderer>(
+        &self,
+        state: &mut State,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+        scale: f32,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = state.scale;
+
+        state.scale = scale.clamp(self.min_scale, self.max_scale);
+
+        let scaled_size = scaled_image_size(
+            renderer,
+            &self.handle,
+            state,
+            bounds.size(),
+            self.content_fit,
+        );
+
+        let factor = state.scale / previous_scale - 1.0;
+
+        let cursor_to_center = cursor_position - bounds.center();
+
+        let adjustment =
+            cursor_to_center * factor + state.current_offset * factor;
+
+        state.current_offset = Vector::new(
+            if scaled_size.width > bounds.width {
+                state.current_offset.x + adjustment.x
+            } else {
+                0.0
+            },
+            if scaled_size.height > bounds.height {
+                state.current_offset.y + adjustment.y
+            } else {
+                0.0
+            },
+        );
+    }
 }
 
 impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
@@ -177,40 +222,18 @@ where
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            state.scale = (if y > 0.0 {
+                            let scale = if y > 0.0 {
                                 state.scale * (1.0 + self.scale_step)
                             } else {
                                 state.scale / (1.0 + self.scale_step)
-                            })
-                            .clamp(self.min_scale, self.max_scale);
+                            };
 
-                            let scaled_size = scaled_image_size(
-                                renderer,
-                                &self.handle,
+                            self.zoom(
                                 state,
-                                bounds.size(),
-                                self.content_fit,
-                            );
-
-                            let factor = state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center =
-                                cursor_position - bounds.center();
-
-                            let adjustment = cursor_to_center * factor
-                                + state.current_offset * factor;
-
-                            state.current_offset = Vector::new(
-                                if scaled_size.width > bounds.width {
-                                    state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if scaled_size.height > bounds.height {
-                                    state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
+                                renderer,
+                                bounds,
+                                cursor_position,
+                                scale,
                             );
                         }
                     }
@@ -218,6 +241,49 @@ where
 
                 event::Status::Captured
             }
+            Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Pinch {
+                phase,
+                scale,
+                ..
+            })) => {
+                let Some(cursor_position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if phase == mouse::gesture::Phase::Moved {
+                    let state = tree.state.downcast_mut::<State>();
+                    let scale = state.scale * scale;
+
+                    self.zoom(state, renderer, bounds, cursor_position, scale);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Swipe {
+                phase,
+                delta,
+                ..
+            })) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                if phase == mouse::gesture::Phase::Moved {
+                    let state = tree.state.downcast_mut::<State>();
+                    let scaled_size = scaled_image_size(
+                        renderer,
+                        &self.handle,
+                        state,
+                        bounds.size(),
+                        self.content_fit,
+                    );
+
+                    state.current_offset =
+                        pan(state.current_offset - delta, bounds, scaled_size);
+                }
+
+                event::Status::Captured
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let Some(cursor_position) = cursor.position_over(bounds) else {
                     return event::Status::Ignored;
@@ -252,32 +318,10 @@ where
                         bounds.size(),
                         self.content_fit,
                     );
-                    let hidden_width = (scaled_size.width - bounds.width / 2.0)
-                        .max(0.0)
-                        .round();
-
-                    let hidden_height = (scaled_size.height
-                        - bounds.height / 2.0)
-                        .max(0.0)
-                        .round();
-
                     let delta = position - origin;
 
-                    let x = if bounds.width < scaled_size.width {
-                        (state.starting_offset.x - delta.x)
-                            .clamp(-hidden_width, hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < scaled_size.height {
-                        (state.starting_offset.y - delta.y)
-                            .clamp(-hidden_height, hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    state.current_offset = Vector::new(x, y);
+                    state.current_offset =
+                        pan(state.starting_offset - delta, bounds, scaled_size);
 
                     event::Status::Captured
                 } else {
@@ -445,3 +489,197 @@ where
         adjusted_fit.height * state.scale,
     )
 }
+
+/// Limits the given offset so the image of the [`Viewer`] cannot be moved
+/// out of sight.
+fn pan(offset: Vector, bounds: Rectangle, scaled_size: Size) -> Vector {
+    let hidden_width =
+        (scaled_size.width - bounds.width / 2.0).max(0.0).round();
+
+    let hidden_height =
+        (scaled_size.height - bounds.height / 2.0).max(0.0).round();
+
+    let x = if bounds.width < scaled_size.width {
+        offset.x.clamp(-hidden_width, hidden_width)
+    } else {
+        0.0
+    };
+
+    let y = if bounds.height < scaled_size.height {
+        offset.y.clamp(-hidden_height, hidden_height)
+    } else {
+        0.0
+    };
+
+    Vector::new(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::mouse::gesture::Phase;
+    use crate::core::{Background, Transformation};
+
+    /// A renderer whose image handles are their own size.
+    struct Measured;
+
+    impl renderer::Renderer for Measured {
+        fn start_layer(&mut self, _bounds: Rectangle) {}
+
+        fn end_layer(&mut self) {}
+
+        fn start_transformation(&mut self, _transformation: Transformation) {}
+
+        fn end_transformation(&mut self) {}
+
+        fn clear(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            _quad: renderer::Quad,
+            _background: impl Into<Background>,
+        ) {
+        }
+    }
+
+    impl image::Renderer for Measured {
+        type Handle = Size<u32>;
+
+        fn measure_image(&self, handle: &Self::Handle) -> Size<u32> {
+            *handle
+        }
+
+        fn draw_image(
+            &mut self,
+            _handle: Self::Handle,
+            _filter_method: FilterMethod,
+            _bounds: Rectangle,
+            _rotation: Radians,
+            _opacity: f32,
+            _border_radius: [f32; 4],
+        ) {
+        }
+    }
+
+    fn pinch(phase: Phase, scale: f32) -> Event {
+        Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Pinch {
+            phase,
+            fingers: 2,
+            delta: Vector::ZERO,
+            scale,
+            rotation: 0.0,
+        }))
+    }
+
+    fn swipe(delta: Vector) -> Event {
+        Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Swipe {
+            phase: Phase::Moved,
+            fingers: 2,
+            delta,
+        }))
+    }
+
+    /// Lays out a 200x200 viewer of a square image and sends it the given
+    /// events, with the cursor at the given positions.
+    fn run(
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> (State, Vec<event::Status>) {
+        let mut viewer: Viewer<Size<u32>> = Viewer::new(Size::new(100, 100))
+            .width(200)
+            .height(200)
+            .min_scale(0.5)
+            .max_scale(4.0);
+
+        let widget: &mut dyn Widget<(), (), Measured> = &mut viewer;
+        let mut tree = Tree::new(&*widget);
+        let node = widget.layout(
+            &mut tree,
+            &Measured,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        let statuses = events
+            .into_iter()
+            .map(|(event, position)| {
+                widget.on_event(
+                    &mut tree,
+                    event,
+                    Layout::new(&node),
+                    mouse::Cursor::Available(position),
+                    &Measured,
+                    &mut clipboard::Null,
+                    &mut Shell::new(&mut messages),
+                    &Rectangle::with_size(Size::INFINITY),
+                )
+            })
+            .collect();
+
+        (*tree.state.downcast_ref::<State>(), statuses)
+    }
+
+    #[test]
+    fn pinching_zooms_around_cursor() {
+        // 50 pixels to the right of the center
+        let cursor = Point::new(150.0, 100.0);
+
+        let (state, statuses) = run([
+            (pinch(Phase::Started, 1.0), cursor),
+            (pinch(Phase::Moved, 2.0), cursor),
+            (pinch(Phase::Ended, 1.0), cursor),
+            (pinch(Phase::Moved, 2.0), Point::new(300.0, 100.0)),
+        ]);
+
+        assert_eq!(state.scale, 2.0);
+        assert_eq!(state.current_offset, Vector::new(50.0, 0.0));
+        assert_eq!(
+            statuses,
+            vec![
+                event::Status::Captured,
+                event::Status::Captured,
+                event::Status::Captured,
+                event::Status::Ignored,
+            ]
+        );
+    }
+
+    #[test]
+    fn pinching_is_clamped_to_scale_limits() {
+        let center = Point::new(100.0, 100.0);
+
+        let (state, _) = run([(pinch(Phase::Moved, 100.0), center)]);
+
+        assert_eq!(state.scale, 4.0);
+
+        let (state, _) = run([
+            (pinch(Phase::Moved, 2.0), Point::new(150.0, 150.0)),
+            (pinch(Phase::Moved, 0.01), center),
+        ]);
+
+        // The image fits in the viewer again, so it is centered
+        assert_eq!(state.scale, 0.5);
+        assert_eq!(state.current_offset, Vector::ZERO);
+    }
+
+    #[test]
+    fn swiping_pans_zoomed_image_within_bounds() {
+        let center = Point::new(100.0, 100.0);
+
+        let (state, _) = run([
+            (swipe(Vector::new(-30.0, 10.0)), center),
+            (pinch(Phase::Moved, 2.0), center),
+            (swipe(Vector::new(-30.0, 10.0)), center),
+        ]);
+
+        assert_eq!(state.current_offset, Vector::new(30.0, -10.0));
+
+        let (state, _) = run([
+            (pinch(Phase::Moved, 2.0), center),
+            (swipe(Vector::new(-1000.0, 1000.0)), center),
+        ]);
+
+        // The edges of the image cannot go past the center of the viewer
+        assert_eq!(state.current_offset, Vector::new(300.0, -300.0));
+    }
+}
@@ -47,6 +47,7 @@
 //!     }
 //! }
 //! ```
+use crate::core::alignment;
 use crate::core::border;
 use crate::core::font::{self, Font};
 use crate::core::padding;
@@ -54,9 +55,12 @@ use crate::core::theme;
 use crate::core::{
     self, color, Color, Element, Length, Padding, Pixels, Theme,
 };
-use crate::{column, container, rich_text, row, scrollable, span, text};
+use crate::{
+    checkbox, column, container, horizontal_rule, rich_text, row, rule,
+    scrollable, span, text, vertical_rule,
+};
 
-use std::cell::{Cell, RefCell};
+use std::cell::RefCell;
 use std::sync::Arc;
 
 pub use core::text::Highlight;
@@ -81,13 +85,45 @@ pub enum Item {
         /// The items of the list.
         items: Vec<Vec<Item>>,
     },
+    /// A list with some items marked as tasks.
+    TaskList {
+        /// The first number of the list, if it is ordered.
+        start: Option<u64>,
+        /// The items of the list, together with whether they are done, if
+        /// they are tasks.
+        items: Vec<(Option<bool>, Vec<Item>)>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A thematic break.
+    Rule,
+    /// A table.
+    Table {
+        /// The horizontal alignment of each column.
+        alignments: Vec<alignment::Horizontal>,
+        /// The cells of the header.
+        header: Vec<Text>,
+        /// The cells of every row.
+        rows: Vec<Vec<Text>>,
+    },
+    /// An image.
+    ///
+    /// Images are displayed with the resolver given to [`view_with`].
+    Image {
+        /// The destination of the image, as written in the Markdown source.
+        url: String,
+        /// The title of the image.
+        title: String,
+        /// The alternative text of the image.
+        alt: Text,
+    },
 }
 
 /// A bunch of parsed Markdown text.
 #[derive(Debug, Clone)]
 pub struct Text {
     spans: Vec<Span>,
-    last_style: Cell<Option<Style>>,
+    last_style: RefCell<Option<Style>>,
     last_styled_spans: RefCell<Arc<[text::Span<'static, Url>]>>,
 }
 
@@ -95,7 +131,7 @@ impl Text {
     fn new(spans: Vec<Span>) -> Self {
         Self {
             spans,
-            last_style: Cell::default(),
+            last_style: RefCell::default(),
             last_styled_spans: RefCell::default(),
         }
     }
@@ -105,11 +141,11 @@ impl Text {
     /// This method performs caching for you. It will only reallocate if the [`Style`]
     /// provided changes.
     pub fn spans(&self, style: Style) -> Arc<[text::Span<'static, Url>]> {
-        if Some(style) != self.last_style.get() {
+        if self.last_style.borrow().as_ref() != Some(&style) {
             *self.last_styled_spans.borrow_mut() =
                 self.spans.iter().map(|span| span.view(&style)).collect();
 
-            self.last_style.set(Some(style));
+            *self.last_style.borrow_mut() = Some(style);
         }
 
         self.last_styled_spans.borrow().clone()
@@ -150,7 +186,9 @@ impl Span {
                 let span = if *code {
                     span.font(Font::MONOSPACE)
                         .color(style.inline_code_color)
-                        .background(style.inline_code_highlight.background)
+                        .background(
+                            style.inline_code_highlight.background.clone(),
+                        )
                         .border(style.inline_code_highlight.border)
                         .padding(style.inline_code_padding)
                 } else if *strong || *emphasis {
@@ -234,7 +272,19 @@ impl Span {
 pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     struct List {
         start: Option<u64>,
-        items: Vec<Vec<Item>>,
+        items: Vec<(Option<bool>, Vec<Item>)>,
+    }
+
+    struct Table {
+        alignments: Vec<alignment::Horizontal>,
+        header: Vec<Text>,
+        rows: Vec<Vec<Text>>,
+        is_head: bool,
+    }
+
+    enum Scope {
+        List(List),
+        Quote(Vec<Item>),
     }
 
     let mut spans = Vec::new();
@@ -242,9 +292,12 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     let mut emphasis = false;
     let mut strikethrough = false;
     let mut metadata = false;
-    let mut table = false;
+    let mut heading = false;
+    let mut table: Option<Table> = None;
+    let mut image = None;
     let mut link = None;
-    let mut lists = Vec::new();
+    let mut linked = false;
+    let mut scopes = Vec::new();
 
     #[cfg(feature = "highlighter")]
     let mut highlighter = None;
@@ -254,20 +307,19 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
         pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_TABLES
-            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH
+            | pulldown_cmark::Options::ENABLE_TASKLISTS,
     );
 
-    let produce = |lists: &mut Vec<List>, item| {
-        if lists.is_empty() {
-            Some(item)
-        } else {
-            lists
-                .last_mut()
-                .expect("list context")
-                .items
-                .last_mut()
-                .expect("item context")
-                .push(item);
+    let produce = |scopes: &mut Vec<Scope>, item| match scopes.last_mut() {
+        None => Some(item),
+        Some(Scope::List(list)) => {
+            list.items.last_mut().expect("item context").1.push(item);
+
+            None
+        }
+        Some(Scope::Quote(items)) => {
+            items.push(item);
 
             None
         }
@@ -277,21 +329,25 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     #[allow(clippy::drain_collect)]
     parser.filter_map(move |event| match event {
         pulldown_cmark::Event::Start(tag) => match tag {
-            pulldown_cmark::Tag::Strong if !metadata && !table => {
+            pulldown_cmark::Tag::Strong if !metadata => {
                 strong = true;
                 None
             }
-            pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+            pulldown_cmark::Tag::Emphasis if !metadata => {
                 emphasis = true;
                 None
             }
-            pulldown_cmark::Tag::Strikethrough if !metadata && !table => {
+            pulldown_cmark::Tag::Strikethrough if !metadata => {
                 strikethrough = true;
                 None
             }
-            pulldown_cmark::Tag::Link { dest_url, .. }
-                if !metadata && !table =>
-            {
+            pulldown_cmark::Tag::Heading { .. } if !metadata => {
+                heading = true;
+                None
+            }
+            pulldown_cmark::Tag::Link { dest_url, .. } if !metadata => {
+                linked = true;
+
                 match Url::parse(&dest_url) {
                     Ok(url)
                         if url.scheme() == "http"
@@ -304,25 +360,51 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
                 None
             }
-            pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
-                lists.push(List {
+            pulldown_cmark::Tag::List(first_item) if !metadata => {
+                scopes.push(Scope::List(List {
                     start: first_item,
                     items: Vec::new(),
-                });
+                }));
 
                 None
             }
             pulldown_cmark::Tag::Item => {
-                lists
-                    .last_mut()
-                    .expect("list context")
-                    .items
-                    .push(Vec::new());
+                if let Some(Scope::List(list)) = scopes.last_mut() {
+                    list.items.push((None, Vec::new()));
+                }
+
+                None
+            }
+            pulldown_cmark::Tag::BlockQuote(_) if !metadata => {
+                scopes.push(Scope::Quote(Vec::new()));
+
                 None
             }
+            // Images inside links, headings or tables cannot be blocks, so
+            // only their alt text is kept inline
+            pulldown_cmark::Tag::Image { .. }
+                if linked || heading || table.is_some() =>
+            {
+                None
+            }
+            pulldown_cmark::Tag::Image {
+                dest_url, title, ..
+            } if !metadata => {
+                image = Some((dest_url.into_string(), title.into_string()));
+
+                // Images are blocks, so any preceding text becomes a paragraph
+                if spans.is_empty() {
+                    None
+                } else {
+                    produce(
+                        &mut scopes,
+                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                    )
+                }
+            }
             pulldown_cmark::Tag::CodeBlock(
                 pulldown_cmark::CodeBlockKind::Fenced(_language),
-            ) if !metadata && !table => {
+            ) if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     use iced_highlighter::Highlighter;
@@ -341,70 +423,144 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 metadata = true;
                 None
             }
-            pulldown_cmark::Tag::Table(_) => {
-                table = true;
+            pulldown_cmark::Tag::Table(alignments) if !metadata => {
+                table = Some(Table {
+                    alignments: alignments
+                        .into_iter()
+                        .map(|alignment| match alignment {
+                            pulldown_cmark::Alignment::None
+                            | pulldown_cmark::Alignment::Left => {
+                                alignment::Horizontal::Left
+                            }
+                            pulldown_cmark::Alignment::Center => {
+                                alignment::Horizontal::Center
+                            }
+                            pulldown_cmark::Alignment::Right => {
+                                alignment::Horizontal::Right
+                            }
+                        })
+                        .collect(),
+                    header: Vec::new(),
+                    rows: Vec::new(),
+                    is_head: false,
+                });
+
+                None
+            }
+            pulldown_cmark::Tag::TableHead => {
+                if let Some(table) = &mut table {
+                    table.is_head = true;
+                }
+
+                None
+            }
+            pulldown_cmark::Tag::TableRow => {
+                if let Some(table) = &mut table {
+                    table.rows.push(Vec::new());
+                }
+
                 None
             }
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => {
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => {
+                heading = false;
+
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::Heading(level, Text::new(spans.drain(..).collect())),
                 )
             }
-            pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+            pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
             }
-            pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+            pulldown_cmark::TagEnd::Emphasis if !metadata => {
                 emphasis = false;
                 None
             }
-            pulldown_cmark::TagEnd::Strikethrough if !metadata && !table => {
+            pulldown_cmark::TagEnd::Strikethrough if !metadata => {
                 strikethrough = false;
                 None
             }
-            pulldown_cmark::TagEnd::Link if !metadata && !table => {
+            pulldown_cmark::TagEnd::Link if !metadata => {
                 link = None;
+                linked = false;
                 None
             }
-            pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
-                produce(
-                    &mut lists,
-                    Item::Paragraph(Text::new(spans.drain(..).collect())),
-                )
+            pulldown_cmark::TagEnd::Paragraph if !metadata => {
+                // A paragraph may be left empty by a trailing image
+                if spans.is_empty() {
+                    None
+                } else {
+                    produce(
+                        &mut scopes,
+                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                    )
+                }
             }
-            pulldown_cmark::TagEnd::Item if !metadata && !table => {
+            pulldown_cmark::TagEnd::Item if !metadata => {
                 if spans.is_empty() {
                     None
                 } else {
                     produce(
-                        &mut lists,
+                        &mut scopes,
                         Item::Paragraph(Text::new(spans.drain(..).collect())),
                     )
                 }
             }
-            pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-                let list = lists.pop().expect("list context");
+            pulldown_cmark::TagEnd::List(_) if !metadata => {
+                let Some(Scope::List(list)) = scopes.pop() else {
+                    return None;
+                };
 
-                produce(
-                    &mut lists,
-                    Item::List {
+                let item = if list.items.iter().any(|(task, _)| task.is_some())
+                {
+                    Item::TaskList {
                         start: list.start,
                         items: list.items,
+                    }
+                } else {
+                    Item::List {
+                        start: list.start,
+                        items: list
+                            .items
+                            .into_iter()
+                            .map(|(_, items)| items)
+                            .collect(),
+                    }
+                };
+
+                produce(&mut scopes, item)
+            }
+            pulldown_cmark::TagEnd::BlockQuote if !metadata => {
+                let Some(Scope::Quote(items)) = scopes.pop() else {
+                    return None;
+                };
+
+                produce(&mut scopes, Item::Quote(items))
+            }
+            pulldown_cmark::TagEnd::Image if !metadata => {
+                let (url, title) = image.take()?;
+
+                produce(
+                    &mut scopes,
+                    Item::Image {
+                        url,
+                        title,
+                        alt: Text::new(spans.drain(..).collect()),
                     },
                 )
             }
-            pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+            pulldown_cmark::TagEnd::CodeBlock if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     highlighter = None;
                 }
 
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::CodeBlock(Text::new(spans.drain(..).collect())),
                 )
             }
@@ -412,13 +568,41 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 metadata = false;
                 None
             }
-            pulldown_cmark::TagEnd::Table => {
-                table = false;
+            pulldown_cmark::TagEnd::TableCell if !metadata => {
+                let cell = Text::new(spans.drain(..).collect());
+
+                if let Some(table) = &mut table {
+                    if table.is_head {
+                        table.header.push(cell);
+                    } else if let Some(row) = table.rows.last_mut() {
+                        row.push(cell);
+                    }
+                }
+
                 None
             }
+            pulldown_cmark::TagEnd::TableHead => {
+                if let Some(table) = &mut table {
+                    table.is_head = false;
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::Table if !metadata => {
+                let table = table.take()?;
+
+                produce(
+                    &mut scopes,
+                    Item::Table {
+                        alignments: table.alignments,
+                        header: table.header,
+                        rows: table.rows,
+                    },
+                )
+            }
             _ => None,
         },
-        pulldown_cmark::Event::Text(text) if !metadata && !table => {
+        pulldown_cmark::Event::Text(text) if !metadata => {
             #[cfg(feature = "highlighter")]
             if let Some(highlighter) = &mut highlighter {
                 use text::Highlighter as _;
@@ -440,7 +624,8 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
             let span = Span::Standard {
                 text: text.into_string(),
-                strong,
+                strong: strong
+                    || table.as_ref().is_some_and(|table| table.is_head),
                 emphasis,
                 strikethrough,
                 link: link.clone(),
@@ -451,7 +636,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
             None
         }
-        pulldown_cmark::Event::Code(code) if !metadata && !table => {
+        pulldown_cmark::Event::Code(code) if !metadata => {
             let span = Span::Standard {
                 text: code.into_string(),
                 strong,
@@ -464,7 +649,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             spans.push(span);
             None
         }
-        pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+        pulldown_cmark::Event::SoftBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from(" "),
                 strikethrough,
@@ -475,7 +660,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
-        pulldown_cmark::Event::HardBreak if !metadata && !table => {
+        pulldown_cmark::Event::HardBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from("\n"),
                 strikethrough,
@@ -486,6 +671,18 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
+        pulldown_cmark::Event::Rule if !metadata => {
+            produce(&mut scopes, Item::Rule)
+        }
+        pulldown_cmark::Event::TaskListMarker(done) if !metadata => {
+            if let Some(Scope::List(list)) = scopes.last_mut() {
+                if let Some((task, _)) = list.items.last_mut() {
+                    *task = Some(done);
+                }
+            }
+
+            None
+        }
         _ => None,
     })
 }
@@ -540,7 +737,7 @@ impl Default for Settings {
 }
 
 /// The text styling of some Markdown rendering in [`view`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Highlight`] to be applied to the background of inline code.
     pub inline_code_highlight: Highlight,
@@ -618,6 +815,44 @@ pub fn view<'a, 'b, Theme, Renderer>(
     settings: Settings,
     style: Style,
 ) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with(items, settings, style, &|_url| None)
+}
+
+/// Display a bunch of Markdown items, resolving images with the given
+/// function.
+///
+/// The function receives the destination of an [`Item::Image`] and may
+/// return the [`Element`] displaying it. The alternative text of the image
+/// is displayed otherwise.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::markdown;
+/// use iced::Theme;
+///
+/// # fn load_image<'a>(_url: &str) -> Option<Element<'a, markdown::Url>> { None }
+/// fn view(items: &[markdown::Item]) -> Element<'_, markdown::Url> {
+///     markdown::view_with(
+///         items,
+///         markdown::Settings::default(),
+///         markdown::Style::from_palette(Theme::TokyoNightStorm.palette()),
+///         &|url| load_image(url),
+///     )
+/// }
+/// ```
+pub fn view_with<'a, 'b, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'b Item>,
+    settings: Settings,
+    style: Style,
+    image: &dyn Fn(&str) -> Option<Element<'a, Url, Theme, Renderer>>,
+) -> Element<'a, Url, Theme, Renderer>
 where
     Theme: Catalog + 'a,
     Renderer: core::text::Renderer<Font = Font> + 'a,
@@ -636,30 +871,33 @@ where
     let spacing = text_size * 0.625;
 
     let blocks = items.into_iter().enumerate().map(|(i, item)| match item {
-        Item::Heading(level, heading) => {
-            container(rich_text(heading.spans(style)).size(match level {
+        Item::Heading(level, heading) => container(
+            rich_text(heading.spans(style.clone())).size(match level {
                 pulldown_cmark::HeadingLevel::H1 => h1_size,
                 pulldown_cmark::HeadingLevel::H2 => h2_size,
                 pulldown_cmark::HeadingLevel::H3 => h3_size,
                 pulldown_cmark::HeadingLevel::H4 => h4_size,
                 pulldown_cmark::HeadingLevel::H5 => h5_size,
                 pulldown_cmark::HeadingLevel::H6 => h6_size,
-            }))
-            .padding(padding::top(if i > 0 {
-                text_size / 2.0
-            } else {
-                Pixels::ZERO
-            }))
-            .into()
-        }
-        Item::Paragraph(paragraph) => {
-            rich_text(paragraph.spans(style)).size(text_size).into()
-        }
+            }),
+        )
+        .padding(padding::top(if i > 0 {
+            text_size / 2.0
+        } else {
+            Pixels::ZERO
+        }))
+        .into(),
+        Item::Paragraph(paragraph) => rich_text(paragraph.spans(style.clone()))
+            .size(text_size)
+            .into(),
         Item::List { start: None, items } => {
             column(items.iter().map(|items| {
-                row![text("•").size(text_size), view(items, settings, style)]
-                    .spacing(spacing)
-                    .into()
+                row![
+                    text("•").size(text_size),
+                    view_with(items, settings, style.clone(), image)
+                ]
+                .spacing(spacing)
+                .into()
             }))
             .spacing(spacing)
             .into()
@@ -670,17 +908,80 @@ where
         } => column(items.iter().enumerate().map(|(i, items)| {
             row![
                 text!("{}.", i as u64 + *start).size(text_size),
-                view(items, settings, style)
+                view_with(items, settings, style.clone(), image)
             ]
             .spacing(spacing)
             .into()
         }))
         .spacing(spacing)
         .into(),
+        Item::TaskList { start, items } => {
+            column(items.iter().enumerate().map(|(i, (task, items))| {
+                let marker: Element<'a, Url, Theme, Renderer> = match task {
+                    Some(done) => {
+                        checkbox("", *done).size(text_size).spacing(0).into()
+                    }
+                    None => match start {
+                        Some(start) => text!("{}.", i as u64 + *start),
+                        None => text("•"),
+                    }
+                    .size(text_size)
+                    .into(),
+                };
+
+                row![marker, view_with(items, settings, style.clone(), image)]
+                    .spacing(spacing)
+                    .into()
+            }))
+            .spacing(spacing)
+            .into()
+        }
+        Item::Quote(items) => row![
+            vertical_rule(spacing.0 / 2.0),
+            view_with(items, settings, style.clone(), image)
+        ]
+        .spacing(spacing)
+        .height(Length::Shrink)
+        .into(),
+        Item::Rule => horizontal_rule(1).into(),
+        Item::Table {
+            alignments,
+            header,
+            rows,
+        } => {
+            let row_of = |cells: &[Text]| -> Element<'a, Url, Theme, Renderer> {
+                row(cells.iter().enumerate().map(|(i, cell)| {
+                    container(
+                        rich_text(cell.spans(style.clone())).size(text_size),
+                    )
+                    .width(Length::Fill)
+                    .padding(spacing.0 / 2.0)
+                    .align_x(
+                        alignments
+                            .get(i)
+                            .copied()
+                            .unwrap_or(alignment::Horizontal::Left),
+                    )
+                    .into()
+                }))
+                .into()
+            };
+
+            column(std::iter::once(row_of(header)).chain(
+                rows.iter().flat_map(|cells| {
+                    [horizontal_rule(1).into(), row_of(cells)]
+                }),
+            ))
+            .width(Length::Fill)
+            .into()
+        }
+        Item::Image { url, alt, .. } => image(url).unwrap_or_else(|| {
+            rich_text(alt.spans(style.clone())).size(text_size).into()
+        }),
         Item::CodeBlock(code) => container(
             scrollable(
                 container(
-                    rich_text(code.spans(style))
+                    rich_text(code.spans(style.clone()))
                         .font(Font::MONOSPACE)
                         .size(code_size),
                 )
@@ -703,7 +1004,11 @@ where
 
 /// The theme catalog of Markdown items.
 pub trait Catalog:
-    container::Catalog + scrollable::Catalog + text::Catalog
+    container::Catalog
+    + scrollable::Catalog
+    + text::Catalog
+    + checkbox::Catalog
+    + rule::Catalog
 {
     /// The styling class of a Markdown code block.
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a>;
@@ -714,3 +1019,75 @@ impl Catalog for Theme {
         Box::new(container::dark)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extended_blocks() {
+        let items: Vec<_> = parse(
+            "> quoted\n\n---\n\n- [x] done\n- [ ] todo\n\n\
+             | a | b |\n|:--|--:|\n| 1 | 2 |\n\n![alt](image.png \"title\")",
+        )
+        .collect();
+
+        assert!(matches!(
+            items.as_slice(),
+            [
+                Item::Quote(quote),
+                Item::Rule,
+                Item::TaskList { start: None, items: tasks },
+                Item::Table { alignments, header, rows },
+                Item::Image { url, title, .. },
+            ] if matches!(quote.as_slice(), [Item::Paragraph(_)])
+                && tasks[0].0 == Some(true)
+                && tasks[1].0 == Some(false)
+                && alignments == &[
+                    alignment::Horizontal::Left,
+                    alignment::Horizontal::Right,
+                ]
+                && header.len() == 2
+                && rows.len() == 1
+                && rows[0].len() == 2
+                && url == "image.png"
+                && title == "title"
+        ));
+    }
+
+    fn is_alt_text(text: &Text, alt: &str, is_link: bool) -> bool {
+        matches!(
+            text.spans.as_slice(),
+            [Span::Standard { text, link, .. }]
+                if text == alt && link.is_some() == is_link
+        )
+    }
+
+    #[test]
+    fn images_in_links_stay_inline() {
+        let items: Vec<_> =
+            parse("[![alt](image.png)](https://iced.rs)").collect();
+
+        assert!(matches!(
+            items.as_slice(),
+            [Item::Paragraph(text)] if is_alt_text(text, "alt", true)
+        ));
+    }
+
+    #[test]
+    fn images_in_table_cells_and_headings_stay_inline() {
+        let items: Vec<_> = parse(
+            "# ![logo](logo.png)\n\n\
+             | a | b |\n|---|---|\n| ![cell](cell.png) | 2 |",
+        )
+        .collect();
+
+        assert!(matches!(
+            items.as_slice(),
+            [Item::Heading(_, heading), Item::Table { rows, .. }]
+                if is_alt_text(heading, "logo", false)
+                    && rows.len() == 1
+                    && is_alt_text(&rows[0][0], "cell", false)
+        ));
+    }
+}
@@ -786,7 +786,7 @@ where
                                     border: style.hovered_region.border,
                                     ..renderer::Quad::default()
                                 },
-                                style.hovered_region.background,
+                                style.hovered_region.background.clone(),
                             );
                         }
                     }
@@ -899,6 +899,26 @@ where
 
         (!children.is_empty()).then(|| Group::with_children(children).overlay())
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.contents
+                .iter()
+                .zip(&state.children)
+                .zip(layout.children())
+                .map(|(((_pane, content), state), layout)| {
+                    content.a11y_nodes(layout, state, cursor)
+                }),
+        )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<PaneGrid<'a, Message, Theme, Renderer>>
@@ -1188,7 +1208,7 @@ impl<'a, T> Contents<'a, T> {
 }
 
 /// The appearance of a [`PaneGrid`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The appearance of a hovered region highlight.
     pub hovered_region: Highlight,
@@ -1199,7 +1219,7 @@ pub struct Style {
 }
 
 /// The appearance of a highlight of the [`PaneGrid`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Highlight {
     /// The [`Background`] of the pane region.
     pub background: Background,
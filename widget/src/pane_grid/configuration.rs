@@ -4,6 +4,7 @@ use crate::pane_grid::Axis;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -239,6 +239,42 @@ where
         );
     }
 
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        if let Some(title_bar) = &self.title_bar {
+            let mut children = layout.children();
+            let title_bar_layout = children.next().unwrap();
+            let body_layout = children.next().unwrap();
+
+            A11yTree::join(
+                [
+                    title_bar.a11y_nodes(
+                        title_bar_layout,
+                        &tree.children[1],
+                        cursor,
+                    ),
+                    self.body.as_widget().a11y_nodes(
+                        body_layout,
+                        &tree.children[0],
+                        cursor,
+                    ),
+                ]
+                .into_iter(),
+            )
+        } else {
+            self.body
+                .as_widget()
+                .a11y_nodes(layout, &tree.children[0], cursor)
+        }
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
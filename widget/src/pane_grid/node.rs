@@ -7,6 +7,7 @@ use std::collections::BTreeMap;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Node {
     /// The region of this [`Node`] is split into two.
     Split {
@@ -21,6 +21,7 @@ use rustc_hash::FxHashMap;
 /// [`PaneGrid`]: super::PaneGrid
 /// [`PaneGrid::new`]: super::PaneGrid::new
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct State<T> {
     /// The panes of the [`PaneGrid`].
     ///
@@ -342,6 +343,7 @@ impl<T> State<T> {
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Internal {
     layout: Node,
     last_id: usize,
@@ -430,6 +430,68 @@ where
         }
     }
 
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+        let mut show_title = true;
+        let mut controls_tree = None;
+
+        if let Some(controls) = &self.controls {
+            let controls_layout = children.next().unwrap();
+
+            controls_tree = Some(
+                if title_layout.bounds().width + controls_layout.bounds().width
+                    > padded.bounds().width
+                {
+                    if let Some(compact) = controls.compact.as_ref() {
+                        let compact_layout = children.next().unwrap();
+
+                        compact.as_widget().a11y_nodes(
+                            compact_layout,
+                            &tree.children[2],
+                            cursor,
+                        )
+                    } else {
+                        show_title = false;
+
+                        controls.full.as_widget().a11y_nodes(
+                            controls_layout,
+                            &tree.children[1],
+                            cursor,
+                        )
+                    }
+                } else {
+                    controls.full.as_widget().a11y_nodes(
+                        controls_layout,
+                        &tree.children[1],
+                        cursor,
+                    )
+                },
+            );
+        }
+
+        let title_tree = show_title.then(|| {
+            self.content.as_widget().a11y_nodes(
+                title_layout,
+                &tree.children[0],
+                cursor,
+            )
+        });
+
+        A11yTree::join(title_tree.into_iter().chain(controls_tree))
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
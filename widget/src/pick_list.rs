@@ -72,16 +72,22 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::focus_ring;
 use crate::overlay::menu::{self, Menu};
 
 use std::borrow::Borrow;
 use std::f32;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A widget for selecting a single value from a list of options.
 ///
 /// # Example
@@ -160,6 +166,11 @@ pub struct PickList<
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
@@ -196,6 +207,11 @@ where
         on_select: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
             on_select: Box::new(on_select),
             on_open: None,
             on_close: None,
@@ -328,6 +344,39 @@ where
         self.menu_class = class.into();
         self
     }
+
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`PickList`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description_widget<D: iced_accessibility::Describes>(
+        mut self,
+        description: &D,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -435,6 +484,18 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(state, Some(&self.id));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -452,6 +513,10 @@ where
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+                if !cursor.is_over(layout.bounds()) {
+                    state.is_focused = false;
+                }
+
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
@@ -491,15 +556,6 @@ where
                     && cursor.is_over(layout.bounds())
                     && !state.is_open
                 {
-                    fn find_next<'a, T: PartialEq>(
-                        selected: &'a T,
-                        mut options: impl Iterator<Item = &'a T>,
-                    ) -> Option<&'a T> {
-                        let _ = options.find(|&option| option == selected);
-
-                        options.next()
-                    }
-
                     let options = self.options.borrow();
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
@@ -536,6 +592,149 @@ where
 
                 event::Status::Ignored
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let options = self.options.borrow();
+                let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                if state.is_open {
+                    let last = options.len().checked_sub(1);
+
+                    match key {
+                        keyboard::key::Named::ArrowDown => {
+                            state.hovered_option = match state.hovered_option {
+                                Some(index) => {
+                                    last.map(|last| (index + 1).min(last))
+                                }
+                                None => last.map(|_| 0),
+                            };
+                        }
+                        keyboard::key::Named::ArrowUp => {
+                            state.hovered_option = match state.hovered_option {
+                                Some(index) => Some(index.saturating_sub(1)),
+                                None => last,
+                            };
+                        }
+                        keyboard::key::Named::Enter
+                        | keyboard::key::Named::Space => {
+                            if let Some(option) = state
+                                .hovered_option
+                                .and_then(|index| options.get(index))
+                            {
+                                shell.publish((self.on_select)(option.clone()));
+                            }
+
+                            state.is_open = false;
+
+                            if let Some(on_close) = &self.on_close {
+                                shell.publish(on_close.clone());
+                            }
+                        }
+                        keyboard::key::Named::Escape => {
+                            state.is_open = false;
+
+                            if let Some(on_close) = &self.on_close {
+                                shell.publish(on_close.clone());
+                            }
+                        }
+                        _ => return event::Status::Ignored,
+                    }
+                } else {
+                    match key {
+                        keyboard::key::Named::Enter
+                        | keyboard::key::Named::Space => {
+                            state.is_open = true;
+                            state.hovered_option = options
+                                .iter()
+                                .position(|option| Some(option) == selected);
+
+                            if let Some(on_open) = &self.on_open {
+                                shell.publish(on_open.clone());
+                            }
+                        }
+                        keyboard::key::Named::ArrowDown => {
+                            let next_option = if let Some(selected) = selected {
+                                find_next(selected, options.iter())
+                            } else {
+                                options.first()
+                            };
+
+                            if let Some(next_option) = next_option {
+                                shell.publish((self.on_select)(
+                                    next_option.clone(),
+                                ));
+                            }
+                        }
+                        keyboard::key::Named::ArrowUp => {
+                            let next_option = if let Some(selected) = selected {
+                                find_next(selected, options.iter().rev())
+                            } else {
+                                options.last()
+                            };
+
+                            if let Some(next_option) = next_option {
+                                shell.publish((self.on_select)(
+                                    next_option.clone(),
+                                ));
+                            }
+                        }
+                        _ => return event::Status::Ignored,
+                    }
+                }
+
+                event::Status::Captured
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest { action, .. },
+            ) if event_id == self.id => {
+                use iced_accessibility::accesskit::Action;
+
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                match action {
+                    Action::Focus => {
+                        state.is_focused = true;
+                    }
+                    Action::Default | Action::Expand if !state.is_open => {
+                        let selected =
+                            self.selected.as_ref().map(Borrow::borrow);
+
+                        state.is_open = true;
+                        state.is_focused = true;
+                        state.hovered_option = self
+                            .options
+                            .borrow()
+                            .iter()
+                            .position(|option| Some(option) == selected);
+
+                        if let Some(on_open) = &self.on_open {
+                            shell.publish(on_open.clone());
+                        }
+                    }
+                    Action::Default | Action::Collapse if state.is_open => {
+                        state.is_open = false;
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -697,6 +896,19 @@ where
                 *viewport,
             );
         }
+
+        if state.is_focused {
+            if let Some(focus_ring) =
+                <Theme as Catalog>::focus_ring(theme, &self.class)
+            {
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    style.border.radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -740,6 +952,84 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let mut node = NodeBuilder::new(Role::ComboBox);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.add_action(if state.is_open {
+            Action::Collapse
+        } else {
+            Action::Expand
+        });
+        node.set_bounds(bounds);
+        node.set_expanded(state.is_open);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter()
+                        .cloned()
+                        .map(|id| NodeId::from(id))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(selected) = self.selected.as_ref() {
+            node.set_value(selected.borrow().to_string());
+        } else if let Some(placeholder) = self.placeholder.as_ref() {
+            node.set_placeholder(placeholder.clone());
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer>
@@ -765,6 +1055,7 @@ struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
@@ -777,6 +1068,7 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
@@ -790,6 +1082,29 @@ impl<P: text::Paragraph> Default for State<P> {
     }
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+fn find_next<'a, T: PartialEq>(
+    selected: &'a T,
+    mut options: impl Iterator<Item = &'a T>,
+) -> Option<&'a T> {
+    let _ = options.find(|&option| option == selected);
+
+    options.next()
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -848,7 +1163,7 @@ pub enum Status {
 }
 
 /// The appearance of a pick list.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The text [`Color`] of the pick list.
     pub text_color: Color,
@@ -881,6 +1196,15 @@ pub trait Catalog: menu::Catalog {
         class: &<Self as Catalog>::Class<'_>,
         status: Status,
     ) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`PickList`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &<Self as Catalog>::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`PickList`].
@@ -898,6 +1222,13 @@ impl Catalog for Theme {
     fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &StyleFn<'_, Self>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// The default style of the field of a [`PickList`].
@@ -0,0 +1,113 @@
+//! Draw the text being composed by an input method.
+use crate::core::alignment;
+use crate::core::input_method::Preedit;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
+use crate::core::{Background, Color, Pixels, Point, Rectangle, Size};
+
+/// The appearance of a [`Preedit`].
+#[derive(Debug, Clone)]
+pub(crate) struct Style {
+    pub background: Background,
+    pub value: Color,
+    pub selection: Color,
+}
+
+/// Draws the given [`Preedit`] in place, with its top-left corner at the given
+/// `position`.
+///
+/// The [`Preedit`] is drawn on top of a background, so it hides any text
+/// behind it, and underlined to set it apart from committed text.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    preedit: &Preedit,
+    font: Renderer::Font,
+    size: Pixels,
+    line_height: text::LineHeight,
+    position: Point,
+    style: Style,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    if preedit.is_empty() {
+        return;
+    }
+
+    let paragraph = Renderer::Paragraph::with_text(Text {
+        content: &preedit.content,
+        bounds: Size::INFINITY,
+        size: preedit.text_size.unwrap_or(size),
+        line_height,
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::None,
+    });
+
+    let bounds = Rectangle::new(position, paragraph.min_bounds());
+
+    renderer.with_layer(clip_bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        if let Some(selection) = preedit
+            .selection
+            .as_ref()
+            .filter(|selection| !selection.is_empty())
+        {
+            let x = |index: usize| {
+                let graphemes = preedit
+                    .content
+                    .get(..index)
+                    .map(|prefix| {
+                        unicode_segmentation::UnicodeSegmentation::graphemes(
+                            prefix, true,
+                        )
+                        .count()
+                    })
+                    .unwrap_or_default();
+
+                paragraph
+                    .grapheme_position(0, graphemes)
+                    .map(|position| position.x)
+                    .unwrap_or_default()
+            };
+
+            let start = x(selection.start);
+            let end = x(selection.end);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + start,
+                        width: end - start,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.selection,
+            );
+        }
+
+        renderer.fill_paragraph(&paragraph, position, style.value, bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + bounds.height - 1.0,
+                    height: 1.0,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            style.value,
+        );
+    });
+}
@@ -1,5 +1,9 @@
 //! Radio buttons let users choose a single option from a bunch of options.
 //!
+//! A focused radio button is selected with Space or Enter. Radio buttons
+//! created together with [`Radio::group`] also select the previous or next
+//! option of their group with the arrow keys.
+//!
 //! # Example
 //! ```no_run
 //! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
@@ -59,17 +63,24 @@
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, focusable, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    Background, Clipboard, Color, Element, Layout, Length, Pixels, Rectangle,
-    Shell, Size, Theme, Widget,
+    id::Internal, Background, Clipboard, Color, Element, Layout, Length,
+    Pixels, Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::focus_ring;
+
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
 
 /// A circular button representing a choice.
 ///
@@ -135,8 +146,16 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: widget::Id,
+    label_id: widget::Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     is_selected: bool,
     on_click: Message,
+    on_previous: Option<Message>,
+    on_next: Option<Message>,
     label: String,
     width: Length,
     size: f32,
@@ -180,8 +199,16 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: widget::Id::unique(),
+            label_id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
             is_selected: Some(value) == selected,
             on_click: f(value),
+            on_previous: None,
+            on_next: None,
             label: label.into(),
             width: Length::Shrink,
             size: Self::DEFAULT_SIZE,
@@ -195,6 +222,41 @@ where
         }
     }
 
+    /// Creates a group of [`Radio`] buttons, one for each of the given
+    /// labeled options.
+    ///
+    /// While one of the buttons is focused, the arrow keys select the
+    /// previous or next option of the group and move the focus along with
+    /// the selection. The buttons are meant to be laid out next to each
+    /// other, in order.
+    pub fn group<L, V, F>(
+        options: impl IntoIterator<Item = (L, V)>,
+        selected: Option<V>,
+        f: F,
+    ) -> Vec<Self>
+    where
+        L: Into<String>,
+        V: Eq + Copy,
+        F: Fn(V) -> Message,
+    {
+        let options: Vec<_> = options.into_iter().collect();
+        let values: Vec<V> = options.iter().map(|(_, value)| *value).collect();
+
+        options
+            .into_iter()
+            .enumerate()
+            .map(|(i, (label, value))| {
+                let mut radio = Self::new(label, value, selected, &f);
+
+                radio.on_previous =
+                    i.checked_sub(1).map(|previous| f(values[previous]));
+                radio.on_next = values.get(i + 1).map(|next| f(*next));
+
+                radio
+            })
+            .collect()
+    }
+
     /// Sets the size of the [`Radio`] button.
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = size.into().0;
@@ -263,6 +325,33 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`Radio`] button.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description_widget<T: iced_accessibility::Describes>(
+        mut self,
+        description: &T,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -273,11 +362,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -298,12 +387,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -321,9 +409,21 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(state, Some(&self.id));
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -332,6 +432,8 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -340,6 +442,63 @@ where
 
                     return event::Status::Captured;
                 }
+
+                state.is_focused = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        keyboard::key::Named::Space
+                        | keyboard::key::Named::Enter,
+                    ),
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                let (direction, message) = match key {
+                    keyboard::key::Named::ArrowDown
+                    | keyboard::key::Named::ArrowRight => {
+                        (focusable::Direction::Next, &self.on_next)
+                    }
+                    keyboard::key::Named::ArrowUp
+                    | keyboard::key::Named::ArrowLeft => {
+                        (focusable::Direction::Previous, &self.on_previous)
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                let Some(message) = message else {
+                    return event::Status::Ignored;
+                };
+
+                shell.publish(message.clone());
+                shell.request_focus(direction);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest { action, .. },
+            ) if event_id == self.id => {
+                match action {
+                    iced_accessibility::accesskit::Action::Default => {
+                        shell.publish(self.on_click.clone());
+                    }
+                    iced_accessibility::accesskit::Action::Focus => {
+                        state.is_focused = true;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
             }
             _ => {}
         }
@@ -374,6 +533,7 @@ where
     ) {
         let is_mouse_over = cursor.is_over(layout.bounds());
         let is_selected = self.is_selected;
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         let mut children = layout.children();
 
@@ -420,18 +580,27 @@ where
                     style.dot_color,
                 );
             }
+
+            if state.is_focused {
+                if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                    focus_ring::draw(
+                        renderer,
+                        bounds,
+                        (size / 2.0).into(),
+                        focus_ring,
+                    );
+                }
+            }
         }
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -439,6 +608,86 @@ where
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, NodeId, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let into_rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let bounds = layout.bounds();
+        let label_bounds = layout
+            .children()
+            .nth(1)
+            .map_or(bounds, |layout| layout.bounds());
+
+        let mut node = NodeBuilder::new(Role::RadioButton);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.set_bounds(into_rect(bounds));
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter()
+                        .cloned()
+                        .map(|id| NodeId::from(id))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+        node.set_selected(self.is_selected);
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        let mut label_node = NodeBuilder::new(Role::Label);
+        label_node.set_name(self.label.clone());
+        label_node.set_bounds(into_rect(label_bounds));
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::leaf(label_node, self.label_id.clone()),
+        )
+    }
+
+    fn id(&self) -> Option<widget::Id> {
+        Some(widget::Id(Internal::Set(vec![
+            self.id.clone(),
+            self.label_id.clone(),
+        ])))
+    }
+
+    fn set_id(&mut self, id: widget::Id) {
+        if let widget::Id(Internal::Set(list)) = id {
+            if list.len() == 2 {
+                self.id = list[0].clone();
+                self.label_id = list[1].clone();
+            }
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Radio<'a, Message, Theme, Renderer>>
@@ -455,6 +704,27 @@ where
     }
 }
 
+/// The local state of a [`Radio`].
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    label: widget::text::State<P>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Radio`] button.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -471,7 +741,7 @@ pub enum Status {
 }
 
 /// The appearance of a radio button.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the radio button.
     pub background: Background,
@@ -495,6 +765,15 @@ pub trait Catalog {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Radio`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Radio`].
@@ -510,6 +789,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// The default style of a [`Radio`] button.
@@ -20,6 +20,7 @@
 //! }
 //! ```
 use crate::container;
+use crate::core::animation::{Animation, Easing};
 use crate::core::border::{self, Border};
 use crate::core::clipboard::DndDestinationRectangles;
 use iced_runtime::core::widget::Id;
@@ -41,6 +42,7 @@ use crate::core::{
     self, id::Internal, Background, Clipboard, Color, Element, Layout, Length,
     Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::focus_ring;
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
@@ -91,6 +93,7 @@ pub struct Scrollable<
     direction: Direction,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    smooth_scrolling: bool,
     class: Theme::Class<'a>,
 }
 
@@ -125,6 +128,7 @@ where
             direction: direction.into(),
             content: content.into(),
             on_scroll: None,
+            smooth_scrolling: false,
             class: Theme::default(),
         }
         .validate()
@@ -186,6 +190,16 @@ where
         self
     }
 
+    /// Sets whether line-based mouse wheel scrolling of the [`Scrollable`]
+    /// should be animated.
+    ///
+    /// Pixel-based deltas, like the ones produced by touchpads, are always
+    /// applied immediately.
+    pub fn smooth_scrolling(mut self, smooth_scrolling: bool) -> Self {
+        self.smooth_scrolling = smooth_scrolling;
+        self
+    }
+
     /// Anchors the vertical [`Scrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -538,6 +552,12 @@ where
         let translation =
             state.translation(self.direction, bounds, content_bounds);
 
+        if Scrollbars::new(state, self.direction, bounds, content_bounds)
+            .active()
+        {
+            operation.focusable(state, Some(&self.id));
+        }
+
         operation.scrollable(
             state,
             Some(&self.id),
@@ -598,6 +618,12 @@ where
             }
         }
 
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = false;
+        }
+
         if let Some(scroller_grabbed_at) = state.y_scroller_grabbed_at {
             match event {
                 Event::Mouse(mouse::Event::CursorMoved { .. })
@@ -843,6 +869,9 @@ where
                     return event::Status::Ignored;
                 }
 
+                let is_smooth = self.smooth_scrolling
+                    && matches!(delta, mouse::ScrollDelta::Lines { .. });
+
                 let Vector { x, y } = match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
                         // TODO: Configurable speed/friction (?)
@@ -871,10 +900,73 @@ where
                 } else {
                     Vector::new(y, x)
                 };
-                let delta = movement * -1.;
+                let delta = self.direction.align(movement * -1.);
+
+                if is_smooth {
+                    let remaining = state
+                        .smooth_scroll
+                        .map(|smooth_scroll| smooth_scroll.remaining())
+                        .unwrap_or(Vector::ZERO);
+
+                    let mut target = *state;
+                    target.scroll(remaining + delta, bounds, content_bounds);
+
+                    let distance = target
+                        .absolute_offset(bounds, content_bounds)
+                        - state.absolute_offset(bounds, content_bounds);
+
+                    let in_transaction = state.last_scrolled.is_some();
+
+                    if distance == Vector::ZERO {
+                        state.smooth_scroll = None;
+
+                        return if in_transaction {
+                            event::Status::Captured
+                        } else {
+                            event::Status::Ignored
+                        };
+                    }
+
+                    state.smooth_scroll = Some(SmoothScroll::new(distance));
+                    state.last_scrolled = Some(Instant::now());
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+
+                state.smooth_scroll = None;
+                state.scroll(delta, bounds, content_bounds);
+
+                let has_scrolled = notify_scroll(
+                    state,
+                    &self.on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                let in_transaction = state.last_scrolled.is_some();
 
+                if has_scrolled || in_transaction {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Swipe {
+                phase: mouse::gesture::Phase::Moved,
+                delta,
+                ..
+            })) => {
+                if cursor_over_scrollable.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                // The content follows the fingers, like with touch
+                state.smooth_scroll = None;
                 state.scroll(
-                    self.direction.align(delta),
+                    self.direction.align(-delta),
                     bounds,
                     content_bounds,
                 );
@@ -887,9 +979,7 @@ where
                     shell,
                 );
 
-                let in_transaction = state.last_scrolled.is_some();
-
-                if has_scrolled || in_transaction {
+                if has_scrolled {
                     event::Status::Captured
                 } else {
                     event::Status::Ignored
@@ -945,7 +1035,70 @@ where
 
                 event::Status::Captured
             }
-            Event::Window(window::Event::RedrawRequested(_)) => {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                let delta = match key {
+                    keyboard::key::Named::ArrowUp => Vector::new(0.0, -60.0),
+                    keyboard::key::Named::ArrowDown => Vector::new(0.0, 60.0),
+                    keyboard::key::Named::ArrowLeft => Vector::new(-60.0, 0.0),
+                    keyboard::key::Named::ArrowRight => Vector::new(60.0, 0.0),
+                    keyboard::key::Named::PageUp => {
+                        Vector::new(0.0, -bounds.height)
+                    }
+                    keyboard::key::Named::PageDown => {
+                        Vector::new(0.0, bounds.height)
+                    }
+                    keyboard::key::Named::Home => {
+                        Vector::new(0.0, -content_bounds.height)
+                    }
+                    keyboard::key::Named::End => {
+                        Vector::new(0.0, content_bounds.height)
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                state.scroll(
+                    self.direction.align(delta),
+                    bounds,
+                    content_bounds,
+                );
+
+                let _ = notify_scroll(
+                    state,
+                    &self.on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                event::Status::Captured
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(mut smooth_scroll) = state.smooth_scroll.take() {
+                    state.scroll(
+                        smooth_scroll.step(now),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    let _ = notify_scroll(
+                        state,
+                        &self.on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+
+                    if let Some(redraw_request) =
+                        smooth_scroll.animation.redraw_request(now)
+                    {
+                        state.smooth_scroll = Some(smooth_scroll);
+                        shell.request_redraw(redraw_request);
+                    }
+                }
+
                 let _ = notify_viewport(
                     state,
                     &self.on_scroll,
@@ -1147,6 +1300,17 @@ where
                 },
             );
         }
+
+        if state.is_focused {
+            if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    style.container.border.radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -1435,6 +1599,19 @@ pub fn scroll_by<T>(id: Id, offset: AbsoluteOffset) -> Task<T> {
     task::effect(Action::widget(operation::scrollable::scroll_by(id, offset)))
 }
 
+/// Produces a [`Task`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// to the item at the given `index`.
+///
+/// Only a [`VirtualList`] knows where its items are; other contents are not
+/// scrolled.
+///
+/// [`VirtualList`]: crate::VirtualList
+pub fn scroll_to_index<T>(id: Id, index: usize) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_to_index(
+        id, index,
+    )))
+}
+
 fn notify_scroll<Message>(
     state: &mut State,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
@@ -1513,6 +1690,45 @@ struct State {
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
+    smooth_scroll: Option<SmoothScroll>,
+    is_focused: bool,
+}
+
+/// The duration of an animated scroll of a [`Scrollable`].
+const SMOOTH_SCROLL_DURATION: Duration = Duration::from_millis(150);
+
+/// An animated scroll of a [`Scrollable`] that is still in progress.
+#[derive(Debug, Clone, Copy)]
+struct SmoothScroll {
+    animation: Animation<Vector>,
+    applied: Vector,
+}
+
+impl SmoothScroll {
+    fn new(distance: Vector) -> Self {
+        Self {
+            animation: Animation::new(Vector::ZERO)
+                .duration(SMOOTH_SCROLL_DURATION)
+                .easing(Easing::EaseOut)
+                .go(distance, Instant::now()),
+            applied: Vector::ZERO,
+        }
+    }
+
+    /// Returns the distance that still has to be scrolled.
+    fn remaining(&self) -> Vector {
+        *self.animation.target() - self.applied
+    }
+
+    /// Returns the delta to scroll at the given time and marks it as applied.
+    fn step(&mut self, now: Instant) -> Vector {
+        let value = self.animation.value(now);
+        let delta = value - self.applied;
+
+        self.applied = value;
+
+        delta
+    }
 }
 
 impl Default for State {
@@ -1526,10 +1742,26 @@ impl Default for State {
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
             last_scrolled: None,
+            smooth_scroll: None,
+            is_focused: false,
         }
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl operation::Scrollable for State {
     fn snap_to(&mut self, offset: RelativeOffset) {
         State::snap_to(self, offset);
@@ -1681,6 +1913,7 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.smooth_scroll = None;
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1695,6 +1928,7 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.smooth_scroll = None;
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1702,12 +1936,14 @@ impl State {
     pub fn snap_to(&mut self, offset: RelativeOffset) {
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
+        self.smooth_scroll = None;
     }
 
     /// Scroll to the provided [`AbsoluteOffset`].
     pub fn scroll_to(&mut self, offset: AbsoluteOffset) {
         self.offset_x = Offset::Absolute(offset.x.max(0.0));
         self.offset_y = Offset::Absolute(offset.y.max(0.0));
+        self.smooth_scroll = None;
     }
 
     /// Scroll by the provided [`AbsoluteOffset`].
@@ -1731,6 +1967,19 @@ impl State {
         );
     }
 
+    /// Returns the absolute scrolling offset of the [`State`], given the bounds
+    /// of the [`Scrollable`] and its contents.
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector {
+        Vector::new(
+            self.offset_x.absolute(bounds.width, content_bounds.width),
+            self.offset_y.absolute(bounds.height, content_bounds.height),
+        )
+    }
+
     /// Returns the scrolling translation of the [`State`], given a [`Direction`],
     /// the bounds of the [`Scrollable`] and its contents.
     fn translation(
@@ -2082,7 +2331,7 @@ pub enum Status {
 }
 
 /// The appearance of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`container::Style`] of a scrollable.
     pub container: container::Style,
@@ -2095,7 +2344,7 @@ pub struct Style {
 }
 
 /// The appearance of the scrollbar of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rail {
     /// The [`Background`] of a scrollbar.
     pub background: Option<Background>,
@@ -2124,6 +2373,15 @@ pub trait Catalog {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Scrollable`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Scrollable`].
@@ -2139,6 +2397,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// The default style of a [`Scrollable`].
@@ -2157,7 +2422,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     match status {
         Status::Active => Style {
             container: container::Style::default(),
-            vertical_rail: scrollbar,
+            vertical_rail: scrollbar.clone(),
             horizontal_rail: scrollbar,
             gap: None,
         },
@@ -2170,15 +2435,15 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                     color: palette.primary.strong.color,
                     ..scrollbar.scroller
                 },
-                ..scrollbar
+                ..scrollbar.clone()
             };
 
             Style {
                 container: container::Style::default(),
                 vertical_rail: if is_vertical_scrollbar_hovered {
-                    hovered_scrollbar
+                    hovered_scrollbar.clone()
                 } else {
-                    scrollbar
+                    scrollbar.clone()
                 },
                 horizontal_rail: if is_horizontal_scrollbar_hovered {
                     hovered_scrollbar
@@ -2197,15 +2462,15 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                     color: palette.primary.base.color,
                     ..scrollbar.scroller
                 },
-                ..scrollbar
+                ..scrollbar.clone()
             };
 
             Style {
                 container: container::Style::default(),
                 vertical_rail: if is_vertical_scrollbar_dragged {
-                    dragged_scrollbar
+                    dragged_scrollbar.clone()
                 } else {
-                    scrollbar
+                    scrollbar.clone()
                 },
                 horizontal_rail: if is_horizontal_scrollbar_dragged {
                     dragged_scrollbar
@@ -2217,3 +2482,68 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    fn swipe(delta: Vector) -> Event {
+        Event::Mouse(mouse::Event::Gesture(mouse::Gesture::Swipe {
+            phase: mouse::gesture::Phase::Moved,
+            fingers: 2,
+            delta,
+        }))
+    }
+
+    /// Lays out a 200x200 scrollable with 1000 pixels of content and sends
+    /// it the given events, with the cursor at the given positions.
+    fn run(events: impl IntoIterator<Item = (Event, Point)>) -> Vec<f32> {
+        let mut scrollable: Element<'_, f32, Theme, ()> =
+            Scrollable::new(crate::Space::new(Length::Fill, 1000.0))
+                .width(200)
+                .height(200)
+                .on_scroll(|viewport| viewport.absolute_offset().y)
+                .into();
+
+        let mut tree = Tree::new(&scrollable);
+        let node = scrollable.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+        let mut offsets = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut offsets);
+
+            let _ = scrollable.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        offsets
+    }
+
+    #[test]
+    fn swiping_scrolls_content_along_with_fingers() {
+        let inside = Point::new(100.0, 100.0);
+        let outside = Point::new(300.0, 100.0);
+
+        let offsets = run([
+            (swipe(Vector::new(0.0, -40.0)), inside),
+            (swipe(Vector::new(0.0, -40.0)), outside),
+            (swipe(Vector::new(0.0, 100.0)), inside),
+            (swipe(Vector::new(0.0, 10.0)), inside),
+        ]);
+
+        assert_eq!(offsets, vec![40.0, 0.0]);
+    }
+}
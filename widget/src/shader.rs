@@ -106,10 +106,12 @@ where
                 Some(Event::Keyboard(keyboard_event))
             }
             core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Pen(pen_event) => Some(Event::Pen(pen_event)),
             core::Event::Window(window::Event::RedrawRequested(instant)) => {
                 Some(Event::RedrawRequested(instant))
             }
             core::Event::Window(_) => None,
+            core::Event::InputMethod(_) => None,
             #[cfg(feature = "a11y")]
             core::Event::A11y(_, _) => None,
             core::Event::Dnd(_) => None,
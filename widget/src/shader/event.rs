@@ -1,6 +1,7 @@
 //! Handle events of a custom shader widget.
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::pen;
 use crate::core::time::Instant;
 use crate::core::touch;
 
@@ -17,6 +18,9 @@ pub enum Event {
     /// A touch event.
     Touch(touch::Event),
 
+    /// A pen event.
+    Pen(pen::Event),
+
     /// A keyboard event.
     Keyboard(keyboard::Event),
 
@@ -36,12 +36,14 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Id;
 use crate::core::{
     self, Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::focus_ring;
 
 use std::ops::RangeInclusive;
 
@@ -306,6 +308,18 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, Some(&self.id));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -411,6 +425,8 @@ where
 
                     return event::Status::Captured;
                 }
+
+                state.is_focused = false;
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
@@ -451,6 +467,26 @@ where
                 }
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if state.is_focused {
+                    let new_value = match key {
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => increment(current_value),
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => decrement(current_value),
+                        Key::Named(key::Named::Home) => {
+                            Some(*self.range.start())
+                        }
+                        Key::Named(key::Named::End) => Some(*self.range.end()),
+                        _ => return event::Status::Ignored,
+                    };
+
+                    let _ = new_value.map(change);
+
+                    return event::Status::Captured;
+                }
+
                 if cursor.is_over(layout.bounds()) {
                     match key {
                         Key::Named(key::Named::ArrowUp) => {
@@ -619,14 +655,16 @@ where
         );
 
         // handle
+        let handle_bounds = Rectangle {
+            x: bounds.x + offset,
+            y: rail_y - (handle_height / 2.0),
+            width: handle_width,
+            height: handle_height,
+        };
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + offset,
-                    y: rail_y - (handle_height / 2.0),
-                    width: handle_width,
-                    height: handle_height,
-                },
+                bounds: handle_bounds,
                 border: Border {
                     radius: handle_border_radius,
                     width: style.handle.border_width,
@@ -636,6 +674,17 @@ where
             },
             style.handle.background,
         );
+
+        if state.is_focused {
+            if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    handle_bounds,
+                    handle_border_radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -759,9 +808,24 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Slider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -774,7 +838,7 @@ pub enum Status {
 }
 
 /// The appearance of a slider.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The colors of the rail of the slider.
     pub rail: Rail,
@@ -803,7 +867,7 @@ impl Style {
 }
 
 /// The appearance of a slider rail
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rail {
     /// The backgrounds of the rail of the slider.
     pub backgrounds: (Background, Background),
@@ -829,7 +893,7 @@ pub enum RailBackground {
 }
 
 /// The appearance of the handle of a slider.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Handle {
     /// The shape of the handle.
     pub shape: HandleShape,
@@ -870,6 +934,15 @@ pub trait Catalog: Sized {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Slider`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Slider`].
@@ -885,6 +958,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// The default style of a [`Slider`].
@@ -0,0 +1,1615 @@
+//! Display large collections of data in sortable, resizable columns.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::table::Order;
+//! use iced::widget::{table, text};
+//!
+//! struct User {
+//!     name: String,
+//!     email: String,
+//! }
+//!
+//! struct State {
+//!     users: Vec<User>,
+//!     widths: [f32; 2],
+//!     sort: Option<(usize, Order)>,
+//!     selected: Option<usize>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Sort(usize, Order),
+//!     Resize(usize, f32),
+//!     Select(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     table(&state.users)
+//!         .push(
+//!             table::column("Name", |user| text(&user.name))
+//!                 .width(state.widths[0]),
+//!         )
+//!         .push(
+//!             table::column("Email", |user| text(&user.email))
+//!                 .width(state.widths[1]),
+//!         )
+//!         .sort(state.sort)
+//!         .on_sort(Message::Sort)
+//!         .on_resize(Message::Resize)
+//!         .selected(|index| state.selected == Some(index))
+//!         .on_select(Message::Select)
+//!         .into()
+//! }
+//! ```
+use crate::container::{self, Container};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, Scrollable};
+use crate::Row;
+
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A widget that displays rows of data in typed columns.
+///
+/// Only the rows visible inside its [`Scrollable`] body are built and laid
+/// out, which keeps a [`Table`] responsive with many thousands of rows.
+///
+/// Sorting, resizing and selection are driven by messages, so the data and
+/// the column widths stay in your application state.
+#[allow(missing_debug_implementations)]
+pub struct Table<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    rows: &'a [T],
+    columns: Vec<Column<'a, T, Message, Theme, Renderer>>,
+    id: Option<Id>,
+    height: Length,
+    row_height: f32,
+    header_height: f32,
+    padding: Padding,
+    sort: Option<(usize, Order)>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    is_selected: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 32.0;
+
+    /// The default height of the header of a [`Table`].
+    pub const DEFAULT_HEADER_HEIGHT: f32 = 36.0;
+
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 0.0,
+        bottom: 0.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Table`] without columns for the given rows.
+    pub fn new(rows: &'a [T]) -> Self {
+        Self {
+            rows,
+            columns: Vec::new(),
+            id: None,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            header_height: Self::DEFAULT_HEADER_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            sort: None,
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            is_selected: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Column`] to the [`Table`].
+    pub fn push(
+        mut self,
+        column: Column<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Extends the [`Table`] with the given columns.
+    pub fn extend(
+        self,
+        columns: impl IntoIterator<Item = Column<'a, T, Message, Theme, Renderer>>,
+    ) -> Self {
+        columns.into_iter().fold(self, Self::push)
+    }
+
+    /// Sets the [`Id`] of the [`Scrollable`] containing the rows of the
+    /// [`Table`].
+    ///
+    /// This lets you scroll the [`Table`] with the [`Task`] functions in the
+    /// [`scrollable`] module.
+    ///
+    /// [`Task`]: crate::runtime::Task
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of every row of the [`Table`].
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the height of the header of the [`Table`].
+    pub fn header_height(mut self, header_height: impl Into<Pixels>) -> Self {
+        self.header_height = header_height.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the column the rows of the [`Table`] are currently sorted by,
+    /// together with its [`Order`].
+    ///
+    /// The header of the column will display an indicator accordingly.
+    pub fn sort(mut self, sort: Option<(usize, Order)>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable column is clicked.
+    ///
+    /// The closure receives the index of the column and the [`Order`] the
+    /// rows should be sorted in next.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(usize, Order) -> Message + 'a,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that should be produced when a column of the
+    /// [`Table`] is resized by dragging the edge of its header.
+    ///
+    /// The closure receives the index of the column and its new width.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(usize, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that should be produced when a row of the [`Table`]
+    /// is clicked.
+    ///
+    /// The closure receives the index of the row.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function used to know whether the row with the given index is
+    /// selected.
+    pub fn selected(
+        mut self,
+        is_selected: impl Fn(usize) -> bool + 'a,
+    ) -> Self {
+        self.is_selected = Some(Box::new(is_selected));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    header: Element<'a, Message, Theme, Renderer>,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: f32,
+    min_width: f32,
+    align_x: alignment::Horizontal,
+    is_sortable: bool,
+}
+
+impl<'a, T, Message, Theme, Renderer> Column<'a, T, Message, Theme, Renderer> {
+    /// The default width of a [`Column`].
+    pub const DEFAULT_WIDTH: f32 = 150.0;
+
+    /// The default minimum width of a [`Column`].
+    pub const DEFAULT_MIN_WIDTH: f32 = 40.0;
+
+    /// Creates a new [`Column`] with the given header and a closure that
+    /// produces the cell of a row.
+    pub fn new<E>(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        view: impl Fn(&'a T) -> E + 'a,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            header: header.into(),
+            view: Box::new(move |row| view(row).into()),
+            width: Self::DEFAULT_WIDTH,
+            min_width: Self::DEFAULT_MIN_WIDTH,
+            align_x: alignment::Horizontal::Left,
+            is_sortable: true,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0.max(self.min_width);
+        self
+    }
+
+    /// Sets the minimum width the [`Column`] can be resized to.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self.width = self.width.max(self.min_width);
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells of the [`Column`].
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = align.into();
+        self
+    }
+
+    /// Sets whether clicking the header of the [`Column`] sorts the
+    /// [`Table`].
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// Creates a new [`Column`] with the given header and a closure that
+/// produces the cell of a row.
+pub fn column<'a, T, E, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    view: impl Fn(&'a T) -> E + 'a,
+) -> Column<'a, T, Message, Theme, Renderer>
+where
+    E: Into<Element<'a, Message, Theme, Renderer>>,
+{
+    Column::new(header, view)
+}
+
+/// The order the rows of a [`Table`] are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Table<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: Catalog + container::Catalog + scrollable::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, T, Message, Theme, Renderer>) -> Self {
+        let class = Rc::new(table.class);
+        let mut metrics = Vec::with_capacity(table.columns.len());
+        let mut headers = Vec::with_capacity(table.columns.len());
+        let mut cells = Vec::with_capacity(table.columns.len());
+
+        for column in table.columns {
+            metrics.push(Metrics {
+                width: column.width,
+                min_width: column.min_width,
+                is_sortable: column.is_sortable,
+            });
+
+            headers.push(
+                Container::new(column.header)
+                    .width(column.width)
+                    .height(table.header_height)
+                    .padding(table.padding)
+                    .align_x(column.align_x)
+                    .align_y(alignment::Vertical::Center)
+                    .clip(true)
+                    .into(),
+            );
+
+            cells.push(Cell {
+                view: column.view,
+                width: column.width,
+                align_x: column.align_x,
+            });
+        }
+
+        let header = Header {
+            cells: headers,
+            columns: metrics,
+            height: table.header_height,
+            padding: table.padding,
+            sort: table.sort,
+            on_sort: table.on_sort,
+            on_resize: table.on_resize,
+            class: Rc::clone(&class),
+        };
+
+        let body = Body {
+            items: table.rows,
+            cells,
+            row_height: table.row_height,
+            padding: table.padding,
+            on_select: table.on_select,
+            is_selected: table.is_selected,
+            class,
+            range: 0..0,
+            rows: Vec::new(),
+        };
+
+        let mut scrollable =
+            Scrollable::new(Element::new(body)).height(Length::Fill);
+
+        if let Some(id) = table.id {
+            scrollable = scrollable.id(id);
+        }
+
+        crate::Column::new()
+            .push(Element::new(header))
+            .push(scrollable)
+            .height(table.height)
+            .into()
+    }
+}
+
+/// The number of rows built when the visible rows of a [`Table`] are not
+/// known yet.
+const INITIAL_ROWS: usize = 50;
+
+/// The number of rows built above and below the visible ones, so fast
+/// scrolling does not reveal empty space.
+const OVERSCAN: usize = 5;
+
+/// The width of the area around the edge of a header that resizes a
+/// column.
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    width: f32,
+    min_width: f32,
+    is_sortable: bool,
+}
+
+struct Header<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    columns: Vec<Metrics>,
+    height: f32,
+    padding: Padding,
+    sort: Option<(usize, Order)>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    class: Rc<Theme::Class<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct HeaderState {
+    resizing: Option<Resize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Header<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    fn width(&self) -> f32 {
+        self.columns.iter().map(|column| column.width).sum()
+    }
+
+    /// Returns the index of the column under the given position.
+    fn column_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let mut x = bounds.x;
+
+        self.columns.iter().position(|column| {
+            let start = x;
+            x += column.width;
+
+            (start..x).contains(&position.x)
+        })
+    }
+
+    /// Returns the index of the column whose right edge is under the given
+    /// position.
+    fn resize_handle_at(
+        &self,
+        bounds: Rectangle,
+        position: Point,
+    ) -> Option<usize> {
+        let mut x = bounds.x;
+
+        self.columns.iter().position(|column| {
+            x += column.width;
+
+            (position.x - x).abs() <= RESIZE_HANDLE_WIDTH / 2.0
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Header<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<HeaderState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(HeaderState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.cells);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.width()),
+            height: Length::Fixed(self.height),
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut x = 0.0;
+
+        let children = self
+            .cells
+            .iter()
+            .zip(&self.columns)
+            .zip(&mut tree.children)
+            .map(|((cell, column), tree)| {
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(column.width, self.height),
+                );
+
+                let node = cell
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(x, 0.0));
+
+                x += column.width;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(self.width(), self.height),
+            ),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((cell, state), layout)| {
+                    cell.as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<HeaderState>();
+
+        if let Some(resize) = state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    if let Some(on_resize) = &self.on_resize {
+                        let min_width = self.columns[resize.column].min_width;
+                        let width = (resize.width + position.x - resize.origin)
+                            .max(min_width);
+
+                        shell.publish(on_resize(resize.column, width));
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. }
+                    | touch::Event::FingerLost { .. },
+                ) => {
+                    state.resizing = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if self.on_resize.is_some() {
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            ))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                if let Some((column, position)) =
+                    cursor.position_over(bounds).and_then(|position| {
+                        Some((
+                            self.resize_handle_at(bounds, position)?,
+                            position,
+                        ))
+                    })
+                {
+                    state.resizing = Some(Resize {
+                        column,
+                        origin: position.x,
+                        width: self.columns[column].width,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let status = self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_sort) = &self.on_sort else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(index) = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.column_at(bounds, position))
+                    .filter(|index| self.columns[*index].is_sortable)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let order = match self.sort {
+                    Some((column, order)) if column == index => order.reverse(),
+                    _ => Order::Ascending,
+                };
+
+                shell.publish(on_sort(index, order));
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<HeaderState>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if self.on_resize.is_some()
+            && self.resize_handle_at(bounds, position).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_sort.is_some()
+            && self
+                .column_at(bounds, position)
+                .is_some_and(|index| self.columns[index].is_sortable)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.header_background,
+        );
+
+        let cell_style = renderer::Style {
+            text_color: style.header_text_color,
+            ..*renderer_style
+        };
+
+        for ((cell, state), layout) in
+            self.cells.iter().zip(&tree.children).zip(layout.children())
+        {
+            cell.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &cell_style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if let Some((cell, order)) = self.sort.and_then(|(index, order)| {
+            Some((layout.children().nth(index)?, order))
+        }) {
+            let bounds = cell.bounds();
+            let size = renderer.default_size() * 0.7;
+            let line_height = text::LineHeight::default();
+
+            renderer.fill_text(
+                Text {
+                    content: match order {
+                        Order::Ascending => String::from("▲"),
+                        Order::Descending => String::from("▼"),
+                    },
+                    size,
+                    line_height,
+                    font: renderer.default_font(),
+                    bounds: Size::new(
+                        size.0,
+                        f32::from(line_height.to_absolute(size)),
+                    ),
+                    horizontal_alignment: alignment::Horizontal::Right,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                Point::new(bounds.x + bounds.width - 2.0, bounds.center_y()),
+                style.header_text_color,
+                bounds,
+            );
+        }
+
+        draw_separators(
+            renderer,
+            self.columns.iter().map(|column| column.width),
+            bounds,
+            bounds,
+            style.separator_color,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + bounds.height - 1.0,
+                    height: 1.0,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            style.separator_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.cells,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.cells
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((cell, layout), state)| {
+                    cell.as_widget().a11y_nodes(layout, state, cursor)
+                }),
+        )
+    }
+}
+
+struct Cell<'a, T, Message, Theme, Renderer> {
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: f32,
+    align_x: alignment::Horizontal,
+}
+
+struct Body<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    items: &'a [T],
+    cells: Vec<Cell<'a, T, Message, Theme, Renderer>>,
+    row_height: f32,
+    padding: Padding,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    is_selected: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    class: Rc<Theme::Class<'a>>,
+    range: Range<usize>,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The local state of the rows of a [`Table`].
+///
+/// The children of its [`Tree`] hold a single [`Tree`] whose children are
+/// the states of the rows currently built, in the stored range.
+#[derive(Debug, Clone, Default)]
+struct BodyState {
+    range: Option<Range<usize>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Body<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + container::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn widths(&self) -> impl ExactSizeIterator<Item = f32> + '_ {
+        self.cells.iter().map(|cell| cell.width)
+    }
+
+    fn row(&self, index: usize) -> Element<'a, Message, Theme, Renderer> {
+        let item = &self.items[index];
+
+        Row::with_children(self.cells.iter().map(|cell| {
+            Container::new((cell.view)(item))
+                .width(cell.width)
+                .height(self.row_height)
+                .padding(self.padding)
+                .align_x(cell.align_x)
+                .align_y(alignment::Vertical::Center)
+                .clip(true)
+                .into()
+        }))
+        .into()
+    }
+
+    /// Builds the rows in the given range and reconciles their state with
+    /// the rows built previously, matching them by index.
+    fn build(&mut self, tree: &mut Tree, range: Range<usize>) {
+        let range =
+            range.start.min(self.items.len())..range.end.min(self.items.len());
+
+        self.rows = range.clone().map(|index| self.row(index)).collect();
+        self.range = range.clone();
+
+        let state = tree.state.downcast_mut::<BodyState>();
+        let previous_range = state.range.replace(range.clone()).unwrap_or(0..0);
+
+        let rows = &mut tree.children[0];
+        let mut previous: Vec<Option<Tree>> =
+            std::mem::take(&mut rows.children)
+                .into_iter()
+                .map(Some)
+                .collect();
+
+        rows.children = range
+            .zip(&mut self.rows)
+            .map(|(index, row)| {
+                let tree = if previous_range.contains(&index) {
+                    previous
+                        .get_mut(index - previous_range.start)
+                        .and_then(Option::take)
+                } else {
+                    None
+                };
+
+                match tree {
+                    Some(mut tree) => {
+                        tree.diff(row.as_widget_mut());
+                        tree
+                    }
+                    None => Tree::new(row.as_widget()),
+                }
+            })
+            .collect();
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Body<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + container::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<BodyState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(BodyState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::empty()]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let range = tree
+            .state
+            .downcast_ref::<BodyState>()
+            .range
+            .clone()
+            .unwrap_or(0..INITIAL_ROWS);
+
+        self.build(tree, range);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = self.widths().sum();
+        let height = self.items.len() as f32 * self.row_height;
+
+        let row_limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, self.row_height));
+
+        let children = self
+            .range
+            .clone()
+            .zip(&self.rows)
+            .zip(&mut tree.children[0].children)
+            .map(|((index, row), tree)| {
+                row.as_widget()
+                    .layout(tree, renderer, &row_limits)
+                    .move_to(Point::new(0.0, index as f32 * self.row_height))
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(width, height),
+            ),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.rows
+                .iter()
+                .zip(&mut tree.children[0].children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let range = visible_rows(
+                bounds,
+                *viewport,
+                self.row_height,
+                self.items.len(),
+            );
+
+            if range != self.range {
+                self.build(tree, range);
+                shell.invalidate_layout();
+
+                return event::Status::Ignored;
+            }
+        }
+
+        let status = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children[0].children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_select) = &self.on_select else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(index) = cursor
+                    .position_in(bounds)
+                    .map(|position| (position.y / self.row_height) as usize)
+                    .filter(|index| *index < self.items.len())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                shell.publish(on_select(index));
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .rows
+            .iter()
+            .zip(&tree.children[0].children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_select.is_some()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let Some(visible_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let style = <Theme as Catalog>::style(theme, &self.class);
+
+        let hovered_row = cursor
+            .position_in(bounds)
+            .filter(|_| self.on_select.is_some())
+            .map(|position| (position.y / self.row_height) as usize);
+
+        for ((index, (row, state)), layout) in self
+            .range
+            .clone()
+            .zip(self.rows.iter().zip(&tree.children[0].children))
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            let is_selected = self
+                .is_selected
+                .as_ref()
+                .is_some_and(|is_selected| is_selected(index));
+
+            let background = if is_selected {
+                Some(style.selected_row_background.clone())
+            } else if hovered_row == Some(index) {
+                style.hovered_row_background.clone()
+            } else if index % 2 == 1 {
+                style.alternate_row_background.clone()
+            } else {
+                style.row_background.clone()
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: layout.bounds(),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            row.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: if is_selected {
+                        style.selected_text_color
+                    } else {
+                        renderer_style.text_color
+                    },
+                    ..*renderer_style
+                },
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        draw_separators(
+            renderer,
+            self.widths(),
+            bounds,
+            visible_bounds,
+            style.separator_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.rows,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.rows
+                .iter()
+                .zip(layout.children())
+                .zip(state.children[0].children.iter())
+                .map(|((row, layout), state)| {
+                    row.as_widget().a11y_nodes(layout, state, cursor)
+                }),
+        )
+    }
+}
+
+/// Returns the range of rows of the given height that are visible in the
+/// viewport, including some overscan.
+fn visible_rows(
+    bounds: Rectangle,
+    viewport: Rectangle,
+    row_height: f32,
+    total: usize,
+) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let top = ((viewport.y - bounds.y) / row_height).floor().max(0.0) as usize;
+    let bottom = ((viewport.y + viewport.height - bounds.y) / row_height)
+        .ceil()
+        .max(0.0) as usize;
+
+    top.saturating_sub(OVERSCAN).min(total)..(bottom + OVERSCAN).min(total)
+}
+
+/// Draws the vertical lines between the columns of a [`Table`].
+fn draw_separators<Renderer>(
+    renderer: &mut Renderer,
+    widths: impl ExactSizeIterator<Item = f32>,
+    bounds: Rectangle,
+    visible_bounds: Rectangle,
+    color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    let mut x = bounds.x;
+    let separators = widths.len().saturating_sub(1);
+
+    for width in widths.take(separators) {
+        x += width;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: x - 0.5,
+                    y: visible_bounds.y,
+                    width: 1.0,
+                    height: visible_bounds.height,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the header.
+    pub header_background: Background,
+    /// The text [`Color`] of the header.
+    pub header_text_color: Color,
+    /// The [`Background`] of the rows, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row, if any.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of a selected row.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the lines between columns.
+    pub separator_color: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        header_background: palette.background.weak.color.into(),
+        header_text_color: palette.background.weak.text,
+        row_background: None,
+        alternate_row_background: Some(
+            palette.background.weak.color.scale_alpha(0.4).into(),
+        ),
+        hovered_row_background: Some(
+            palette.primary.weak.color.scale_alpha(0.3).into(),
+        ),
+        selected_row_background: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+        separator_color: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    const ROWS: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Sort(usize, Order),
+        Resize(usize, f32),
+        Select(usize),
+    }
+
+    fn table<'a>(
+        sort: Option<(usize, Order)>,
+    ) -> Element<'a, Message, crate::Theme, ()> {
+        Table::new(&ROWS)
+            .push(column("Name", |row: &&str| crate::text(*row)))
+            .push(
+                column("Kind", |_row: &&str| crate::text("letter"))
+                    .min_width(60)
+                    .sortable(false),
+            )
+            .sort(sort)
+            .on_sort(Message::Sort)
+            .on_resize(Message::Resize)
+            .on_select(Message::Select)
+            .into()
+    }
+
+    /// Lays out the given table and sends it the given events, with the
+    /// cursor at the given positions.
+    fn run(
+        mut element: Element<'_, Message, crate::Theme, ()>,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> Vec<Message> {
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 300.0)),
+        );
+        let mut messages = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    fn press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn move_to(position: Point) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        )
+    }
+
+    #[test]
+    fn clicking_headers_cycles_sort_order() {
+        let name = Point::new(75.0, 18.0);
+        let kind = Point::new(225.0, 18.0);
+
+        let clicks =
+            |sort| run(table(sort), [(press(), name), (release(), name)]);
+
+        assert_eq!(clicks(None), vec![Message::Sort(0, Order::Ascending)]);
+        assert_eq!(
+            clicks(Some((0, Order::Ascending))),
+            vec![Message::Sort(0, Order::Descending)]
+        );
+        assert_eq!(
+            clicks(Some((0, Order::Descending))),
+            vec![Message::Sort(0, Order::Ascending)]
+        );
+        assert!(run(table(None), [(press(), kind)]).is_empty());
+    }
+
+    #[test]
+    fn dragging_header_edges_resizes_columns() {
+        let edge = Point::new(150.0, 18.0);
+        let kind_edge = Point::new(298.0, 18.0);
+
+        assert_eq!(
+            run(
+                table(None),
+                [
+                    (press(), edge),
+                    move_to(Point::new(200.0, 18.0)),
+                    move_to(Point::new(0.0, 18.0)),
+                    (release(), Point::new(0.0, 18.0)),
+                    move_to(Point::new(100.0, 18.0)),
+                ]
+            ),
+            vec![
+                Message::Resize(0, 200.0),
+                Message::Resize(0, Column::<&str, Message>::DEFAULT_MIN_WIDTH),
+            ]
+        );
+
+        assert_eq!(
+            run(
+                table(None),
+                [(press(), kind_edge), move_to(Point::new(0.0, 18.0))]
+            ),
+            vec![Message::Resize(1, 60.0)]
+        );
+    }
+
+    #[test]
+    fn clicking_rows_selects_them() {
+        let row = |index: f32| Point::new(50.0, 36.0 + (index + 0.5) * 32.0);
+
+        assert_eq!(
+            run(
+                table(None),
+                [
+                    (press(), row(2.0)),
+                    (press(), row(0.0)),
+                    (press(), row(7.0))
+                ]
+            ),
+            vec![Message::Select(2), Message::Select(0)]
+        );
+    }
+
+    #[test]
+    fn visible_rows_follow_viewport() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(300.0, 32_000.0));
+        let viewport =
+            Rectangle::new(Point::new(0.0, 3200.0), Size::new(300.0, 320.0));
+
+        assert_eq!(
+            visible_rows(bounds, viewport, 32.0, 1000),
+            100 - OVERSCAN..110 + OVERSCAN
+        );
+    }
+
+    #[test]
+    fn visible_rows_are_clamped() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(300.0, 320.0));
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(300.0, 640.0));
+
+        assert_eq!(visible_rows(bounds, viewport, 32.0, 10), 0..10);
+        assert_eq!(visible_rows(bounds, viewport, 0.0, 10), 0..0);
+    }
+}
@@ -8,6 +8,7 @@ use crate::core::widget::text::{
     self, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Vector, Widget,
@@ -21,6 +22,7 @@ where
     Theme: Catalog,
     Renderer: core::text::Renderer,
 {
+    id: Id,
     spans: Box<dyn AsRef<[Span<'a, Link, Renderer::Font>]> + 'a>,
     size: Option<Pixels>,
     line_height: LineHeight,
@@ -43,6 +45,7 @@ where
     /// Creates a new empty [`Rich`] text.
     pub fn new() -> Self {
         Self {
+            id: Id::unique(),
             spans: Box::new([]),
             size: None,
             line_height: LineHeight::default(),
@@ -262,7 +265,7 @@ where
                 let translation = layout.position() - Point::ORIGIN;
                 let regions = state.paragraph.span_bounds(index);
 
-                if let Some(highlight) = span.highlight {
+                if let Some(highlight) = &span.highlight {
                     for bounds in &regions {
                         let bounds = Rectangle::new(
                             bounds.position()
@@ -283,7 +286,7 @@ where
                                 border: highlight.border,
                                 ..Default::default()
                             },
-                            highlight.background,
+                            highlight.background.clone(),
                         );
                     }
                 }
@@ -439,6 +442,55 @@ where
 
         mouse::Interaction::None
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        _cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Live, NodeBuilder, Rect, Role},
+            A11yTree,
+        };
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let text: String = self
+            .spans
+            .as_ref()
+            .as_ref()
+            .iter()
+            .map(|span| span.text.as_ref())
+            .collect();
+
+        let mut node = NodeBuilder::new(Role::Paragraph);
+        node.set_name(text.into_boxed_str());
+        node.set_bounds(bounds);
+        node.set_live(Live::Polite);
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 fn layout<Link, Renderer>(
@@ -31,9 +31,15 @@
 //!     }
 //! }
 //! ```
+pub mod search;
+
+pub use search::{Match, Search};
+
 use crate::core::alignment;
+use crate::core::border;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -41,6 +47,7 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
+use crate::core::text::paragraph::Paragraph as _;
 use crate::core::text::{self, LineHeight, Text, Wrapping};
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
@@ -50,12 +57,18 @@ use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::preedit;
+
+use search::Matcher;
 
 use std::cell::RefCell;
 use std::fmt;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 pub use text::editor::{Action, Edit, Motion};
 
 /// A multi-line text input.
@@ -104,6 +117,14 @@ pub struct TextEditor<
     Renderer: text::Renderer,
 {
     content: &'a Content<Renderer>,
+    #[cfg(feature = "a11y")]
+    id: widget::Id,
+    #[cfg(feature = "a11y")]
+    value_id: widget::Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     placeholder: Option<text::Fragment<'a>>,
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
@@ -115,6 +136,8 @@ pub struct TextEditor<
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    line_numbers: bool,
+    marker: Option<Box<dyn Fn(usize) -> Option<Marker> + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -132,6 +155,14 @@ where
     pub fn new(content: &'a Content<Renderer>) -> Self {
         Self {
             content,
+            #[cfg(feature = "a11y")]
+            id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            value_id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
             placeholder: None,
             font: None,
             text_size: None,
@@ -143,6 +174,8 @@ where
             class: Theme::default(),
             key_binding: None,
             on_edit: None,
+            line_numbers: false,
+            marker: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -226,6 +259,25 @@ where
         self
     }
 
+    /// Sets whether the [`TextEditor`] shows a gutter with line numbers.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the closure that produces the [`Marker`] of a line, given its
+    /// index.
+    ///
+    /// Markers are drawn in the gutter of the [`TextEditor`] and can be used
+    /// to flag breakpoints or diagnostics.
+    pub fn markers(
+        mut self,
+        marker: impl Fn(usize) -> Option<Marker> + 'a,
+    ) -> Self {
+        self.marker = Some(Box::new(marker));
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
     #[cfg(feature = "highlighter")]
     pub fn highlight(
@@ -257,6 +309,14 @@ where
     ) -> TextEditor<'a, H, Message, Theme, Renderer> {
         TextEditor {
             content: self.content,
+            #[cfg(feature = "a11y")]
+            id: self.id,
+            #[cfg(feature = "a11y")]
+            value_id: self.value_id,
+            #[cfg(feature = "a11y")]
+            name: self.name,
+            #[cfg(feature = "a11y")]
+            description: self.description,
             placeholder: self.placeholder,
             font: self.font,
             text_size: self.text_size,
@@ -268,6 +328,8 @@ where
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
+            line_numbers: self.line_numbers,
+            marker: self.marker,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -301,9 +363,155 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`TextEditor`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextEditor`].
+    pub fn description_widget<T: iced_accessibility::Describes>(
+        mut self,
+        description: &T,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextEditor`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    /// Returns the [`InputMethod`] requested by the [`TextEditor`].
+    fn input_method(
+        &self,
+        state: &State<Highlighter>,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> InputMethod {
+        if !state
+            .focus
+            .as_ref()
+            .is_some_and(|focus| focus.is_window_focused)
+        {
+            return InputMethod::Disabled;
+        }
+
+        let internal = self.content.0.borrow();
+
+        InputMethod::Enabled {
+            cursor: caret_bounds(
+                &internal.editor,
+                layout.bounds().shrink(self.text_padding(state)),
+                self.line_height,
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+            ),
+            purpose: input_method::Purpose::Normal,
+            preedit: state.preedit.clone(),
+        }
+    }
+
+    /// Returns the [`Padding`] around the text of the [`TextEditor`],
+    /// including its gutter.
+    fn text_padding(&self, state: &State<Highlighter>) -> Padding {
+        if state.gutter_width > 0.0 {
+            Padding {
+                left: state.gutter_width + self.padding.left,
+                ..self.padding
+            }
+        } else {
+            self.padding
+        }
+    }
+
+    /// Computes the width of the gutter of the [`TextEditor`] for the given
+    /// amount of lines.
+    fn gutter_width(&self, renderer: &Renderer, line_count: usize) -> f32 {
+        if !self.line_numbers && self.marker.is_none() {
+            return 0.0;
+        }
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let markers = if self.marker.is_some() {
+            self.line_height.to_absolute(text_size).into()
+        } else {
+            0.0
+        };
+
+        let numbers = if self.line_numbers {
+            let digits = line_count.max(1).to_string().len().max(2);
+
+            Renderer::Paragraph::with_text(Text {
+                content: &"0".repeat(digits),
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: Wrapping::None,
+            })
+            .min_width()
+        } else {
+            0.0
+        };
+
+        let spacing = if markers > 0.0 && numbers > 0.0 {
+            self.padding.left
+        } else {
+            0.0
+        };
+
+        self.padding.left + markers + spacing + numbers + self.padding.left
+    }
+}
+
+/// A marker drawn next to a line in the gutter of a [`TextEditor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    /// The [`Background`] of the [`Marker`].
+    pub background: Background,
+    /// The [`Border`] of the [`Marker`].
+    pub border: Border,
+}
+
+impl Marker {
+    /// Creates a round [`Marker`] of the given [`Color`]; like a breakpoint.
+    pub fn dot(color: impl Into<Color>) -> Self {
+        Self {
+            background: Background::Color(color.into()),
+            border: Border {
+                radius: f32::MAX.into(),
+                ..Border::default()
+            },
+        }
+    }
+
+    /// Creates a square [`Marker`] of the given [`Color`].
+    pub fn square(color: impl Into<Color>) -> Self {
+        Self {
+            background: Background::Color(color.into()),
+            border: Border::default(),
+        }
+    }
 }
 
 /// The content of a [`TextEditor`].
+///
+/// A [`Content`] keeps a history of the edits performed on it, which can be
+/// reverted with [`Content::undo`] and reapplied with [`Content::redo`].
 pub struct Content<R = crate::Renderer>(RefCell<Internal<R>>)
 where
     R: text::Renderer;
@@ -313,6 +521,9 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
+    carets: Vec<Selection>,
+    block: Option<Block>,
     is_dirty: bool,
 }
 
@@ -329,18 +540,80 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
+            carets: Vec::new(),
+            block: None,
             is_dirty: true,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`]. Consecutive
+    /// insertions are grouped together, so they are undone at once.
+    ///
+    /// Edits and motions apply at every cursor of the [`Content`]. Any
+    /// other action drops the additional cursors.
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        if !matches!(action, Action::SelectBlock(_)) {
+            internal.block = None;
+        }
+
+        match action {
+            Action::Edit(edit) if internal.carets.is_empty() => {
+                internal.edit(edit);
+            }
+            Action::Edit(edit) => internal.edit_all(edit),
+            Action::Move(_) | Action::Select(_)
+                if !internal.carets.is_empty() =>
+            {
+                internal.move_all(action);
+            }
+            Action::Undo => {
+                internal.carets.clear();
+                internal.undo();
+            }
+            Action::Redo => {
+                internal.carets.clear();
+                internal.redo();
+            }
+            Action::Scroll { .. } => internal.editor.perform(action),
+            Action::AddCursor(point) => internal.add_cursor(point),
+            Action::SelectBlock(point) => internal.select_block(point),
+            Action::SelectNextOccurrence => internal.select_next_occurrence(),
+            _ => {
+                internal.history.is_grouping = false;
+                internal.carets.clear();
+                internal.editor.perform(action);
+            }
+        }
+
         internal.is_dirty = true;
     }
 
+    /// Reverts the last group of edits of the [`Content`], restoring the
+    /// selection it had before them.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Reapplies the last group of edits reverted with [`Content::undo`].
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns whether the [`Content`] has any edits to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.0.borrow().history.undo.is_empty()
+    }
+
+    /// Returns whether the [`Content`] has any undone edits to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.0.borrow().history.redo.is_empty()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -401,25 +674,792 @@ where
 
                 contents.push_str(&line);
 
-                contents
-            },
-        );
+                contents
+            },
+        );
+
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Returns the selected text of the [`Content`].
+    ///
+    /// The selections of multiple cursors are joined with `'\n'`.
+    pub fn selection(&self) -> Option<String> {
+        let internal = self.0.borrow();
+
+        if internal.carets.is_empty() {
+            return internal.editor.selection();
+        }
+
+        let selections: Vec<_> = internal
+            .cursors()
+            .into_iter()
+            .map(|cursor| {
+                let (start, end) = cursor.bounds();
+
+                internal.text_range(start, end)
+            })
+            .filter(|selection| !selection.is_empty())
+            .collect();
+
+        (!selections.is_empty()).then(|| selections.join("\n"))
+    }
+
+    /// Returns the current cursor position of the [`Content`].
+    ///
+    /// If the [`Content`] has multiple cursors, this is the position of the
+    /// main one.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        self.0.borrow().editor.cursor_position()
+    }
+
+    /// Returns whether the [`Content`] has additional cursors.
+    fn has_carets(&self) -> bool {
+        !self.0.borrow().carets.is_empty()
+    }
+
+    /// Returns the position and selection anchor of the main cursor.
+    fn main_cursor(&self) -> ((usize, usize), Option<(usize, usize)>) {
+        let selection = self.0.borrow().selection();
+
+        (selection.cursor, selection.anchor)
+    }
+
+    /// Returns the positions of all the cursors of the [`Content`], in order.
+    pub fn cursor_positions(&self) -> Vec<(usize, usize)> {
+        self.0
+            .borrow()
+            .cursors()
+            .into_iter()
+            .map(|cursor| cursor.cursor)
+            .collect()
+    }
+
+    /// Returns all the matches of the given [`Search`] in the [`Content`],
+    /// in order.
+    pub fn find(&self, search: &Search) -> Vec<Match> {
+        let Some(matcher) = Matcher::new(search) else {
+            return Vec::new();
+        };
+
+        self.0.borrow().find(&matcher)
+    }
+
+    /// Selects the first match of the given [`Search`] after the current
+    /// selection of the [`Content`], wrapping around its end.
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn find_next(&mut self, search: &Search) -> Option<Match> {
+        let matcher = Matcher::new(search)?;
+
+        self.0.get_mut().select_next(&matcher)
+    }
+
+    /// Selects the last match of the given [`Search`] before the current
+    /// selection of the [`Content`], wrapping around its start.
+    ///
+    /// Returns the selected [`Match`], if any.
+    pub fn find_previous(&mut self, search: &Search) -> Option<Match> {
+        let matcher = Matcher::new(search)?;
+
+        self.0.get_mut().select_previous(&matcher)
+    }
+
+    /// Replaces the selected match of the given [`Search`] and selects the
+    /// next one.
+    ///
+    /// If the current selection is not a match, the next match is selected
+    /// without replacing anything; so that it can be reviewed first.
+    ///
+    /// Returns the newly selected [`Match`], if any.
+    pub fn replace(
+        &mut self,
+        search: &Search,
+        replacement: &str,
+    ) -> Option<Match> {
+        let matcher = Matcher::new(search)?;
+        let internal = self.0.get_mut();
+
+        let (start, end) = internal.selection().bounds();
+        let selected = Match { start, end };
+
+        if internal.find(&matcher).contains(&selected) {
+            let text = internal
+                .editor
+                .line(start.0)
+                .map_or_else(String::new, |line| {
+                    matcher.replacement(line, start.1..end.1, replacement)
+                });
+
+            internal.substitute(start, end, &text);
+        }
+
+        internal.select_next(&matcher)
+    }
+
+    /// Replaces all the matches of the given [`Search`] in the [`Content`].
+    ///
+    /// The replacements are recorded as a single edit in the history.
+    ///
+    /// Returns the amount of replaced matches.
+    pub fn replace_all(&mut self, search: &Search, replacement: &str) -> usize {
+        let Some(matcher) = Matcher::new(search) else {
+            return 0;
+        };
+
+        let internal = self.0.get_mut();
+        let matches = internal.find(&matcher);
+
+        let (Some(first), Some(last)) = (matches.first(), matches.last())
+        else {
+            return 0;
+        };
+
+        let mut text = String::new();
+        let mut position = first.start;
+
+        for found in &matches {
+            text.push_str(&internal.text_range(position, found.start));
+
+            if let Some(line) = internal.editor.line(found.start.0) {
+                text.push_str(&matcher.replacement(
+                    line,
+                    found.start.1..found.end.1,
+                    replacement,
+                ));
+            }
+
+            position = found.end;
+        }
+
+        internal.substitute(first.start, last.end, &text);
+
+        matches.len()
+    }
+}
+
+impl<R> Internal<R>
+where
+    R: text::Renderer,
+{
+    fn edit(&mut self, edit: Edit) {
+        let is_insert = matches!(edit, Edit::Insert(_));
+        let before = self.selection();
+
+        // Only the lines around the cursor and the selection can change
+        let first = before
+            .cursor
+            .0
+            .min(before.anchor.map_or(usize::MAX, |(line, _)| line))
+            .saturating_sub(1);
+        let last = before
+            .cursor
+            .0
+            .max(before.anchor.map_or(0, |(line, _)| line))
+            + 1;
+
+        let line_count = self.editor.line_count();
+        let last = last.min(line_count.saturating_sub(1));
+        let old = self.text_between(first, last);
+
+        self.editor.perform(Action::Edit(edit));
+
+        let last = (last + self.editor.line_count()).saturating_sub(line_count);
+        let new = self.text_between(first, last);
+
+        if let Some(change) =
+            Change::diff((first, 0), &old, &new, before, self.selection())
+        {
+            self.history.record(change, is_insert);
+        }
+    }
+
+    fn undo(&mut self) {
+        let Some(change) = self.history.undo.pop() else {
+            return;
+        };
+
+        self.replace(change.start, change.end(), &change.removed);
+        self.restore(change.before);
+
+        self.history.redo.push(change);
+        self.history.is_grouping = false;
+    }
+
+    fn redo(&mut self) {
+        let Some(change) = self.history.redo.pop() else {
+            return;
+        };
+
+        let end = advance(change.start, &change.removed);
+
+        self.replace(change.start, end, &change.inserted);
+        self.restore(change.after);
+
+        self.history.undo.push(change);
+        self.history.is_grouping = false;
+    }
+
+    fn replace(
+        &mut self,
+        start: (usize, usize),
+        end: (usize, usize),
+        text: &str,
+    ) {
+        self.editor
+            .set_cursor(end, Some(start).filter(|start| *start != end));
+
+        self.editor
+            .perform(Action::Edit(Edit::Paste(Arc::new(text.to_owned()))));
+    }
+
+    fn restore(&mut self, selection: Selection) {
+        self.editor.set_cursor(selection.cursor, selection.anchor);
+    }
+
+    fn selection(&self) -> Selection {
+        Selection {
+            cursor: self.editor.cursor_position(),
+            anchor: self.editor.anchor_position(),
+        }
+    }
+
+    /// Returns the selections of all the cursors, sorted by position.
+    fn cursors(&self) -> Vec<Selection> {
+        let mut cursors = self.carets.clone();
+        cursors.push(self.selection());
+        cursors.sort_by_key(|cursor| cursor.bounds());
+
+        cursors
+    }
+
+    /// Replaces all the cursors, merging the overlapping ones.
+    ///
+    /// The cursor covering the position of the given `main` cursor becomes
+    /// the main one.
+    fn set_cursors(&mut self, mut cursors: Vec<Selection>, main: Selection) {
+        cursors.sort_by_key(|cursor| cursor.bounds());
+        cursors.dedup_by(|next, previous| {
+            let (start, _) = next.bounds();
+            let (previous_start, previous_end) = previous.bounds();
+
+            start < previous_end || start == previous_start
+        });
+
+        let main = cursors
+            .iter()
+            .position(|cursor| {
+                let (start, end) = cursor.bounds();
+
+                start <= main.cursor && main.cursor <= end
+            })
+            .unwrap_or(0);
+
+        let main = cursors.remove(main);
+
+        self.carets = cursors;
+        self.restore(main);
+    }
+
+    /// Performs the given motion [`Action`] at every cursor.
+    fn move_all(&mut self, action: Action) {
+        let cursors = self.cursors();
+        let main = self.selection();
+
+        let mut moved = Vec::with_capacity(cursors.len());
+        let mut new_main = main;
+
+        for cursor in cursors {
+            self.restore(cursor);
+            self.editor.perform(action.clone());
+
+            if cursor == main {
+                new_main = self.selection();
+            }
+
+            moved.push(self.selection());
+        }
+
+        self.history.is_grouping = false;
+        self.set_cursors(moved, new_main);
+    }
+
+    /// Performs the given [`Edit`] at every cursor, recording a single
+    /// change in the history.
+    fn edit_all(&mut self, edit: Edit) {
+        let mut cursors = self.cursors();
+        let before = self.selection();
+        let main = cursors
+            .iter()
+            .position(|cursor| *cursor == before)
+            .unwrap_or(0);
+
+        let line_count = self.editor.line_count();
+
+        let first = cursors[0].bounds().0 .0.saturating_sub(1);
+        let last = (cursors[cursors.len() - 1].bounds().1 .0 + 1)
+            .min(line_count.saturating_sub(1));
+
+        let old = self.text_between(first, last);
+
+        for i in 0..cursors.len() {
+            let (_, end) = cursors[i].bounds();
+            let line_length = |editor: &R::Editor, line| {
+                editor.line(line).map_or(0, str::len)
+            };
+
+            // Deleting at the end of a line joins the next one
+            let last_line = if edit == Edit::Delete
+                && cursors[i].anchor.is_none()
+                && end.1 >= line_length(&self.editor, end.0)
+            {
+                end.0 + 1
+            } else {
+                end.0
+            };
+
+            let old_line_count = self.editor.line_count();
+            let old_line_length = line_length(&self.editor, last_line);
+
+            self.restore(cursors[i]);
+            self.editor.perform(Action::Edit(edit.clone()));
+
+            cursors[i] = self.selection();
+
+            // The text after the edited region is left untouched, so the
+            // cursors after it keep their distance to its end
+            let new_last_line = (last_line + self.editor.line_count())
+                .saturating_sub(old_line_count);
+            let new_line_length = line_length(&self.editor, new_last_line);
+
+            let shift = |(line, column): (usize, usize)| {
+                if line == last_line {
+                    (
+                        new_last_line,
+                        new_line_length.saturating_sub(
+                            old_line_length.saturating_sub(column),
+                        ),
+                    )
+                } else if line > last_line {
+                    (
+                        (line + self.editor.line_count())
+                            .saturating_sub(old_line_count),
+                        column,
+                    )
+                } else {
+                    (line, column)
+                }
+            };
+
+            for cursor in &mut cursors[i + 1..] {
+                *cursor = Selection {
+                    cursor: shift(cursor.cursor),
+                    anchor: cursor.anchor.map(shift),
+                };
+            }
+        }
+
+        let main = cursors[main];
+        self.set_cursors(cursors, main);
+
+        let last = (last + self.editor.line_count()).saturating_sub(line_count);
+        let new = self.text_between(first, last);
+
+        if let Some(change) =
+            Change::diff((first, 0), &old, &new, before, self.selection())
+        {
+            self.history.record(change, false);
+        }
+
+        self.history.is_grouping = false;
+    }
+
+    /// Adds a cursor at the given [`Point`], making it the main one.
+    fn add_cursor(&mut self, point: Point) {
+        let mut cursors = self.cursors();
+
+        self.editor.perform(Action::Click(point));
+
+        let main = self.selection();
+        cursors.push(main);
+
+        self.history.is_grouping = false;
+        self.set_cursors(cursors, main);
+    }
+
+    /// Selects the block of text between the anchor of the current block
+    /// selection and the given [`Point`].
+    fn select_block(&mut self, point: Point) {
+        let block = self.block.take().unwrap_or_else(|| {
+            let (line, column) = self.editor.cursor_position();
+
+            Block {
+                anchor: (line, self.char_column(line, column)),
+                carets: self.carets.clone(),
+            }
+        });
+
+        self.editor.perform(Action::Click(point));
+
+        let position = self.editor.cursor_position();
+
+        self.extend_block(block, position);
+    }
+
+    /// Places a cursor on every line of the given [`Block`], up to the given
+    /// line and byte column.
+    fn extend_block(&mut self, block: Block, (line, column): (usize, usize)) {
+        let column = self.char_column(line, column);
+
+        let mut cursors = block.carets.clone();
+        let mut main = self.selection();
+
+        for i in line.min(block.anchor.0)..=line.max(block.anchor.0) {
+            let cursor = (i, self.byte_column(i, column));
+            let anchor = (i, self.byte_column(i, block.anchor.1));
+
+            let selection = Selection {
+                cursor,
+                anchor: Some(anchor).filter(|anchor| *anchor != cursor),
+            };
+
+            if i == line {
+                main = selection;
+            }
+
+            cursors.push(selection);
+        }
+
+        self.block = Some(block);
+        self.history.is_grouping = false;
+        self.set_cursors(cursors, main);
+    }
+
+    /// Adds a cursor selecting the next occurrence of the selected text, or
+    /// selects the word at the cursor if nothing is selected.
+    fn select_next_occurrence(&mut self) {
+        self.history.is_grouping = false;
+
+        let Some(query) = self.editor.selection() else {
+            self.carets.clear();
+            self.editor.perform(Action::SelectWord);
+            return;
+        };
+
+        let Some(matcher) = (!query.contains('\n'))
+            .then(|| Matcher::new(&Search::new(query).case_sensitive(true)))
+            .flatten()
+        else {
+            return;
+        };
+
+        let mut cursors = self.cursors();
+        let last = cursors
+            .iter()
+            .map(|cursor| cursor.bounds().1)
+            .max()
+            .unwrap_or_default();
+
+        let matches: Vec<_> = self
+            .find(&matcher)
+            .into_iter()
+            .filter(|found| {
+                !cursors
+                    .iter()
+                    .any(|cursor| cursor.bounds() == (found.start, found.end))
+            })
+            .collect();
+
+        let Some(next) = matches
+            .iter()
+            .find(|found| found.start >= last)
+            .or(matches.first())
+        else {
+            return;
+        };
+
+        let main = Selection {
+            cursor: next.end,
+            anchor: Some(next.start),
+        };
+
+        cursors.push(main);
+
+        self.set_cursors(cursors, main);
+    }
+
+    /// Returns the amount of characters before the given byte column of a
+    /// line.
+    fn char_column(&self, line: usize, column: usize) -> usize {
+        self.editor.line(line).map_or(0, |text| {
+            text.get(..column).unwrap_or(text).chars().count()
+        })
+    }
+
+    /// Returns the byte column of the character at the given index of a
+    /// line, clamped to its end.
+    fn byte_column(&self, line: usize, characters: usize) -> usize {
+        self.editor.line(line).map_or(0, |text| {
+            text.char_indices()
+                .nth(characters)
+                .map_or(text.len(), |(index, _)| index)
+        })
+    }
+
+    fn find(&self, matcher: &Matcher) -> Vec<Match> {
+        (0..self.editor.line_count())
+            .filter_map(|line| Some((line, self.editor.line(line)?)))
+            .flat_map(|(line, text)| {
+                matcher.find(text).into_iter().map(move |range| Match {
+                    start: (line, range.start),
+                    end: (line, range.end),
+                })
+            })
+            .collect()
+    }
+
+    fn select(&mut self, found: Match) {
+        self.editor.set_cursor(found.end, Some(found.start));
+        self.carets.clear();
+        self.history.is_grouping = false;
+        self.is_dirty = true;
+    }
+
+    fn select_next(&mut self, matcher: &Matcher) -> Option<Match> {
+        let (_, end) = self.selection().bounds();
+        let matches = self.find(matcher);
+
+        let next = matches
+            .iter()
+            .find(|found| found.start >= end)
+            .or(matches.first())
+            .copied()?;
+
+        self.select(next);
+
+        Some(next)
+    }
+
+    fn select_previous(&mut self, matcher: &Matcher) -> Option<Match> {
+        let (start, _) = self.selection().bounds();
+        let matches = self.find(matcher);
+
+        let previous = matches
+            .iter()
+            .rev()
+            .find(|found| found.start < start)
+            .or(matches.last())
+            .copied()?;
+
+        self.select(previous);
+
+        Some(previous)
+    }
+
+    /// Replaces the text between the given positions, recording the edit in
+    /// the history.
+    fn substitute(
+        &mut self,
+        start: (usize, usize),
+        end: (usize, usize),
+        text: &str,
+    ) {
+        self.editor
+            .set_cursor(end, Some(start).filter(|start| *start != end));
+
+        self.edit(Edit::Paste(Arc::new(text.to_owned())));
+        self.is_dirty = true;
+    }
+
+    fn text_range(&self, start: (usize, usize), end: (usize, usize)) -> String {
+        let mut text = String::new();
+
+        for line in start.0..=end.0 {
+            let Some(content) = self.editor.line(line) else {
+                break;
+            };
+
+            if line > start.0 {
+                text.push('\n');
+            }
+
+            let from = if line == start.0 { start.1 } else { 0 };
+            let to = if line == end.0 { end.1 } else { content.len() };
+
+            text.push_str(&content[from.min(to)..to]);
+        }
+
+        text
+    }
+
+    fn text_between(&self, first: usize, last: usize) -> String {
+        (first..=last)
+            .map_while(|line| self.editor.line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The edit history of a [`Content`].
+#[derive(Debug, Default)]
+struct History {
+    undo: Vec<Change>,
+    redo: Vec<Change>,
+    is_grouping: bool,
+}
+
+impl History {
+    fn record(&mut self, change: Change, is_insert: bool) {
+        self.redo.clear();
+
+        if is_insert && self.is_grouping {
+            if let Some(last) = self.undo.last_mut().filter(|last| {
+                change.removed.is_empty() && last.end() == change.start
+            }) {
+                last.inserted.push_str(&change.inserted);
+                last.after = change.after;
+
+                return;
+            }
+        }
+
+        self.undo.push(change);
+        self.is_grouping = is_insert;
+    }
+}
+
+/// A replacement of text in a [`Content`].
+#[derive(Debug, Clone)]
+struct Change {
+    start: (usize, usize),
+    removed: String,
+    inserted: String,
+    before: Selection,
+    after: Selection,
+}
+
+impl Change {
+    /// Computes the [`Change`] between two versions of the text starting at
+    /// the given position, if they differ.
+    fn diff(
+        position: (usize, usize),
+        old: &str,
+        new: &str,
+        before: Selection,
+        after: Selection,
+    ) -> Option<Self> {
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(old.len().min(new.len()), |((i, _), _)| i);
+
+        let suffix = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum::<usize>();
+
+        let removed = &old[prefix..old.len() - suffix];
+        let inserted = &new[prefix..new.len() - suffix];
+
+        if removed.is_empty() && inserted.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            start: advance(position, &old[..prefix]),
+            removed: removed.to_owned(),
+            inserted: inserted.to_owned(),
+            before,
+            after,
+        })
+    }
+
+    /// Returns the position right after the inserted text.
+    fn end(&self) -> (usize, usize) {
+        advance(self.start, &self.inserted)
+    }
+}
+
+/// A block selection of a [`Content`] in progress.
+#[derive(Debug, Clone)]
+struct Block {
+    /// The line and character column where the block selection started.
+    anchor: (usize, usize),
+    /// The additional cursors before the block selection started.
+    carets: Vec<Selection>,
+}
+
+/// The cursor and selection anchor of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    cursor: (usize, usize),
+    anchor: Option<(usize, usize)>,
+}
+
+impl Selection {
+    /// Returns the start and end positions of the [`Selection`].
+    fn bounds(self) -> ((usize, usize), (usize, usize)) {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+
+        (self.cursor.min(anchor), self.cursor.max(anchor))
+    }
+}
+
+/// Returns the index of the grapheme at the given line and column of `text`.
+#[cfg(feature = "a11y")]
+fn grapheme_index(text: &str, (line, column): (usize, usize)) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut position = (0, 0);
 
-        if !text.ends_with('\n') {
-            text.push('\n');
+    for (index, grapheme) in text.graphemes(true).enumerate() {
+        if position.0 == line && (position.1 >= column || grapheme == "\n") {
+            return index;
         }
 
-        text
+        if grapheme == "\n" {
+            position = (position.0 + 1, 0);
+        } else {
+            position.1 += grapheme.len();
+        }
     }
 
-    /// Returns the selected text of the [`Content`].
-    pub fn selection(&self) -> Option<String> {
-        self.0.borrow().editor.selection()
+    text.graphemes(true).count()
+}
+
+/// Returns the line and column of the grapheme at the given index of `text`.
+#[cfg(feature = "a11y")]
+fn grapheme_position(text: &str, index: usize) -> (usize, usize) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut position = (0, 0);
+
+    for grapheme in text.graphemes(true).take(index) {
+        if grapheme == "\n" {
+            position = (position.0 + 1, 0);
+        } else {
+            position.1 += grapheme.len();
+        }
     }
 
-    /// Returns the current cursor position of the [`Content`].
-    pub fn cursor_position(&self) -> (usize, usize) {
-        self.0.borrow().editor.cursor_position()
+    position
+}
+
+/// Returns the position reached after writing the given text at `position`.
+fn advance((line, column): (usize, usize), text: &str) -> (usize, usize) {
+    match text.rsplit_once('\n') {
+        Some((before, after)) => {
+            (line + before.matches('\n').count() + 1, after.len())
+        }
+        None => (line, column + text.len()),
     }
 }
 
@@ -454,6 +1494,9 @@ pub struct State<Highlighter: text::Highlighter> {
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
+    modifiers: keyboard::Modifiers,
+    preedit: Option<input_method::Preedit>,
+    gutter_width: f32,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
@@ -508,6 +1551,7 @@ impl<Highlighter: text::Highlighter> operation::Focusable
 
     fn unfocus(&mut self) {
         self.focus = None;
+        self.preedit = None;
     }
 }
 
@@ -528,6 +1572,9 @@ where
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
+            modifiers: keyboard::Modifiers::default(),
+            preedit: None,
+            gutter_width: 0.0,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
@@ -570,8 +1617,11 @@ where
 
         let limits = limits.width(self.width).height(self.height);
 
+        state.gutter_width =
+            self.gutter_width(renderer, internal.editor.line_count());
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
+            limits.shrink(self.text_padding(state)).max(),
             self.font.unwrap_or_else(|| renderer.default_font()),
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
@@ -602,7 +1652,7 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
@@ -619,6 +1669,9 @@ where
                     focus.is_window_focused = false;
                 }
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
             Event::Window(window::Event::Focused) => {
                 if let Some(focus) = &mut state.focus {
                     focus.is_window_focused = true;
@@ -644,6 +1697,104 @@ where
                         ));
                     }
                 }
+
+                shell.request_input_method(
+                    &self.input_method(state, layout, renderer),
+                );
+            }
+            Event::InputMethod(event) => {
+                if state.focus.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = (!content.is_empty()).then(|| {
+                            input_method::Preedit {
+                                content,
+                                selection,
+                                text_size: self.text_size,
+                            }
+                        });
+                    }
+                    input_method::Event::Commit(text) => {
+                        state.preedit = None;
+
+                        if let Some(focus) = &mut state.focus {
+                            focus.updated_at = Instant::now();
+                        }
+
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(text),
+                        ))));
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest {
+                    action,
+                    data,
+                    ..
+                },
+            ) if event_id == self.id => {
+                use iced_accessibility::accesskit::{self, ActionData};
+
+                match (action, data) {
+                    (accesskit::Action::Focus, _) => {
+                        if state.focus.is_none() {
+                            state.focus = Some(Focus::now());
+                        }
+                    }
+                    (
+                        accesskit::Action::SetValue,
+                        Some(ActionData::Value(text)),
+                    ) => {
+                        shell.publish(on_edit(Action::SelectAll));
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(text.into()),
+                        ))));
+                    }
+                    (
+                        accesskit::Action::ReplaceSelectedText,
+                        Some(ActionData::Value(text)),
+                    ) => {
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(text.into()),
+                        ))));
+                    }
+                    (
+                        accesskit::Action::SetTextSelection,
+                        Some(ActionData::SetTextSelection(selection)),
+                    ) => {
+                        let text = self.content.text();
+
+                        shell.publish(on_edit(Action::SetCursor {
+                            position: grapheme_position(
+                                &text,
+                                selection.focus.character_index,
+                            ),
+                            anchor: Some(grapheme_position(
+                                &text,
+                                selection.anchor.character_index,
+                            )),
+                        }));
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
             }
             _ => {}
         }
@@ -652,7 +1803,7 @@ where
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.text_padding(state),
             cursor,
             self.key_binding.as_deref(),
         ) else {
@@ -662,6 +1813,9 @@ where
         match update {
             Update::Click(click) => {
                 let action = match click.kind() {
+                    mouse::click::Kind::Single if state.modifiers.alt() => {
+                        Action::AddCursor(click.position())
+                    }
                     mouse::click::Kind::Single => {
                         Action::Click(click.position())
                     }
@@ -676,7 +1830,11 @@ where
                 shell.publish(on_edit(action));
             }
             Update::Drag(position) => {
-                shell.publish(on_edit(Action::Drag(position)));
+                shell.publish(on_edit(if state.modifiers.alt() {
+                    Action::SelectBlock(position)
+                } else {
+                    Action::Drag(position)
+                }));
             }
             Update::Release => {
                 state.drag_click = None;
@@ -711,9 +1869,15 @@ where
                     let mut publish = |action| shell.publish(on_edit(action));
 
                     match binding {
+                        Binding::Unfocus if content.has_carets() => {
+                            let (position, anchor) = content.main_cursor();
+
+                            publish(Action::SetCursor { position, anchor });
+                        }
                         Binding::Unfocus => {
                             state.focus = None;
                             state.drag_click = None;
+                            state.preedit = None;
                         }
                         Binding::Copy => {
                             if let Some(selection) = content.selection() {
@@ -757,6 +1921,9 @@ where
                         Binding::SelectAll => {
                             publish(Action::SelectAll);
                         }
+                        Binding::SelectNextOccurrence => {
+                            publish(Action::SelectNextOccurrence);
+                        }
                         Binding::Insert(c) => {
                             publish(Action::Edit(Edit::Insert(c)));
                         }
@@ -769,6 +1936,12 @@ where
                         Binding::Delete => {
                             publish(Action::Edit(Edit::Delete));
                         }
+                        Binding::Undo => {
+                            publish(Action::Undo);
+                        }
+                        Binding::Redo => {
+                            publish(Action::Redo);
+                        }
                         Binding::Sequence(sequence) => {
                             for binding in sequence {
                                 apply_binding(
@@ -845,10 +2018,133 @@ where
                 border: style.border,
                 ..renderer::Quad::default()
             },
-            style.background,
+            style.background.clone(),
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let visible_lines = internal.editor.visible_lines();
+        let (cursor_line, _) = internal.editor.cursor_position();
+
+        if let Some(background) = style.current_line.clone() {
+            if let Some(line) =
+                visible_lines.iter().find(|line| line.index == cursor_line)
+            {
+                let highlight = Rectangle {
+                    x: bounds.x + state.gutter_width,
+                    y: text_bounds.y + line.y,
+                    width: bounds.width - state.gutter_width,
+                    height: line.height,
+                };
+
+                let viewport = Rectangle {
+                    y: text_bounds.y,
+                    height: text_bounds.height,
+                    ..bounds
+                };
+
+                if let Some(highlight) = viewport.intersection(&highlight) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: highlight,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+            }
+        }
+
+        if state.gutter_width > 0.0 {
+            let border_width = style.border.width;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + border_width,
+                        y: bounds.y + border_width,
+                        width: state.gutter_width - border_width,
+                        height: bounds.height - border_width * 2.0,
+                    },
+                    border: Border {
+                        radius: border::Radius {
+                            top_right: 0.0,
+                            bottom_right: 0.0,
+                            ..style.border.radius
+                        },
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.gutter.clone(),
+            );
+
+            let gutter = Rectangle {
+                x: bounds.x,
+                y: text_bounds.y,
+                width: state.gutter_width,
+                height: text_bounds.height,
+            };
+
+            let marker_size: f32 =
+                self.line_height.to_absolute(text_size).into();
+
+            renderer.with_layer(gutter, |renderer| {
+                for line in &visible_lines {
+                    let y = text_bounds.y + line.y;
+
+                    if let Some(marker) = self
+                        .marker
+                        .as_ref()
+                        .and_then(|marker| marker(line.index))
+                    {
+                        let marker_bounds = Rectangle::new(
+                            Point::new(bounds.x + self.padding.left, y),
+                            Size::new(marker_size, marker_size),
+                        );
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: marker_bounds.shrink(marker_size / 5.0),
+                                border: marker.border,
+                                ..renderer::Quad::default()
+                            },
+                            marker.background,
+                        );
+                    }
+
+                    if self.line_numbers {
+                        renderer.fill_text(
+                            Text {
+                                content: (line.index + 1).to_string(),
+                                bounds: Size::new(gutter.width, line.height),
+                                size: text_size,
+                                line_height: self.line_height,
+                                font,
+                                horizontal_alignment:
+                                    alignment::Horizontal::Right,
+                                vertical_alignment: alignment::Vertical::Top,
+                                shaping: text::Shaping::Basic,
+                                wrapping: Wrapping::None,
+                            },
+                            Point::new(
+                                bounds.x + state.gutter_width
+                                    - self.padding.left,
+                                y,
+                            ),
+                            if line.index == cursor_line {
+                                style.current_line_number
+                            } else {
+                                style.line_number
+                            },
+                            gutter,
+                        );
+                    }
+                }
+            });
+        }
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -883,10 +2179,16 @@ where
         let translation = text_bounds.position() - Point::ORIGIN;
 
         if let Some(focus) = state.focus.as_ref() {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor =
-                        Rectangle::new(
+            let cursors = std::iter::once(internal.editor.cursor()).chain(
+                internal.carets.iter().map(|caret| {
+                    internal.editor.cursor_at(caret.cursor, caret.anchor)
+                }),
+            );
+
+            for cursor in cursors {
+                match cursor {
+                    Cursor::Caret(position) if focus.is_cursor_visible() => {
+                        let cursor = Rectangle::new(
                             position + translation,
                             Size::new(
                                 1.0,
@@ -898,32 +2200,57 @@ where
                             ),
                         );
 
-                    if let Some(clipped_cursor) =
-                        text_bounds.intersection(&cursor)
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: clipped_cursor,
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        );
+                        if let Some(clipped_cursor) =
+                            text_bounds.intersection(&cursor)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: clipped_cursor,
+                                    ..renderer::Quad::default()
+                                },
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        text_bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            text_bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
+                    Cursor::Caret(_) => {}
                 }
-                Cursor::Caret(_) => {}
+            }
+
+            if let Some(preedit) = &state.preedit {
+                let caret = caret_bounds(
+                    &internal.editor,
+                    text_bounds,
+                    self.line_height,
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                );
+
+                preedit::draw(
+                    renderer,
+                    preedit,
+                    font,
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    self.line_height,
+                    caret.position(),
+                    preedit::Style {
+                        background: style.background,
+                        value: style.value,
+                        selection: style.selection,
+                    },
+                    text_bounds,
+                );
             }
         }
     }
@@ -960,6 +2287,132 @@ where
 
         operation.focusable(state, None);
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                self, NodeBuilder, NodeId, Rect, Role, TextPosition,
+                TextSelection,
+            },
+            A11yId, A11yNode, A11yTree,
+        };
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let text = self.content.text();
+        let selection = self.content.0.borrow().selection();
+
+        let mut node = NodeBuilder::new(Role::MultilineTextInput);
+        node.add_action(accesskit::Action::Focus);
+        node.set_bounds(bounds);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter()
+                        .cloned()
+                        .map(|id| NodeId::from(id))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(placeholder) = self.placeholder.as_ref() {
+            node.set_placeholder(placeholder.to_string());
+        }
+
+        node.set_value(text.clone());
+
+        if self.on_edit.is_some() {
+            node.add_action(accesskit::Action::SetValue);
+            node.add_action(accesskit::Action::ReplaceSelectedText);
+            node.add_action(accesskit::Action::SetTextSelection);
+        } else {
+            node.set_read_only();
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        let value_node_id = NodeId::from(A11yId::Widget(self.value_id.clone()));
+
+        node.set_text_selection(TextSelection {
+            anchor: TextPosition {
+                node: value_node_id,
+                character_index: grapheme_index(
+                    &text,
+                    selection.anchor.unwrap_or(selection.cursor),
+                ),
+            },
+            focus: TextPosition {
+                node: value_node_id,
+                character_index: grapheme_index(&text, selection.cursor),
+            },
+        });
+
+        let mut value_node = NodeBuilder::new(Role::InlineTextBox);
+        value_node.set_bounds(bounds);
+        value_node.set_character_lengths(
+            text.graphemes(true)
+                .map(|grapheme| u8::try_from(grapheme.len()).unwrap_or(u8::MAX))
+                .collect::<Vec<_>>(),
+        );
+        value_node.set_value(text);
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::leaf(value_node, self.value_id.clone()),
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    fn id(&self) -> Option<widget::Id> {
+        use crate::core::id::Internal;
+
+        Some(widget::Id(Internal::Set(vec![
+            self.id.0.clone(),
+            self.value_id.0.clone(),
+        ])))
+    }
+
+    #[cfg(feature = "a11y")]
+    fn set_id(&mut self, id: widget::Id) {
+        use crate::core::id::Internal;
+
+        if let Internal::Set(list) = id.0 {
+            if list.len() == 2 {
+                self.id.0 = list[0].clone();
+                self.value_id.0 = list[1].clone();
+            }
+        }
+    }
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -978,10 +2431,39 @@ where
     }
 }
 
+/// Computes the bounds of the caret of an [`Editor`], in window coordinates.
+///
+/// If some text is selected, the caret is placed at the start of the
+/// selection.
+///
+/// [`Editor`]: text::Editor
+fn caret_bounds(
+    editor: &impl text::Editor,
+    text_bounds: Rectangle,
+    line_height: LineHeight,
+    text_size: Pixels,
+) -> Rectangle {
+    let position = match editor.cursor() {
+        Cursor::Caret(position) => position,
+        Cursor::Selection(ranges) => ranges
+            .first()
+            .map(Rectangle::position)
+            .unwrap_or(Point::ORIGIN),
+    };
+
+    Rectangle::new(
+        position + (text_bounds.position() - Point::ORIGIN),
+        Size::new(1.0, line_height.to_absolute(text_size).into()),
+    )
+}
+
 /// A binding to an action in the [`TextEditor`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Binding<Message> {
     /// Unfocus the [`TextEditor`].
+    ///
+    /// If the [`TextEditor`] has multiple cursors, only the main one is kept
+    /// instead.
     Unfocus,
     /// Copy the selection of the [`TextEditor`].
     Copy,
@@ -999,6 +2481,8 @@ pub enum Binding<Message> {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Add a cursor selecting the next occurrence of the selection.
+    SelectNextOccurrence,
     /// Insert the given character.
     Insert(char),
     /// Break the current line.
@@ -1007,6 +2491,10 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Undo the last group of edits.
+    Undo,
+    /// Redo the last group of undone edits.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -1065,6 +2553,16 @@ impl<Message> Binding<Message> {
             keyboard::Key::Character("a") if modifiers.command() => {
                 Some(Self::SelectAll)
             }
+            keyboard::Key::Character("d") if modifiers.command() => {
+                Some(Self::SelectNextOccurrence)
+            }
+            keyboard::Key::Character("z" | "Z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
             _ => {
                 if let Some(text) = text {
                     let c = text.chars().find(|c| !c.is_control())?;
@@ -1126,7 +2624,7 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
                         let cursor_position = cursor_position
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -1147,7 +2645,7 @@ impl<Message> Update<Message> {
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
                         let cursor_position = cursor.position_in(bounds)?
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         Some(Update::Drag(cursor_position))
                     }
@@ -1228,7 +2726,7 @@ pub enum Status {
 }
 
 /// The appearance of a text input.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the text input.
     pub background: Background,
@@ -1242,6 +2740,14 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Background`] of the gutter of the text input.
+    pub gutter: Background,
+    /// The [`Color`] of the line numbers of the text input.
+    pub line_number: Color,
+    /// The [`Color`] of the line number of the line with the cursor.
+    pub current_line_number: Color,
+    /// The [`Background`] of the line with the cursor, if any.
+    pub current_line: Option<Background>,
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1286,6 +2792,10 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        gutter: Background::Color(palette.background.weak.color),
+        line_number: palette.background.strong.color,
+        current_line_number: palette.background.base.text,
+        current_line: None,
     };
 
     match status {
@@ -1311,3 +2821,236 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Content = super::Content<crate::Renderer>;
+
+    fn with_cursors(text: &str, positions: &[(usize, usize)]) -> Content {
+        let mut content = Content::with_text(text);
+
+        let cursors: Vec<_> = positions
+            .iter()
+            .map(|&cursor| Selection {
+                cursor,
+                anchor: None,
+            })
+            .collect();
+
+        content.0.get_mut().set_cursors(cursors.clone(), cursors[0]);
+        content
+    }
+
+    #[test]
+    fn undo_groups_inserts() {
+        let mut content = Content::with_text("Hi");
+        content.perform(Action::Move(Motion::DocumentEnd));
+
+        for c in " there".chars() {
+            content.perform(Action::Edit(Edit::Insert(c)));
+        }
+
+        content.perform(Action::Edit(Edit::Enter));
+        assert_eq!(content.line_count(), 2);
+
+        content.undo();
+        assert_eq!(content.line_count(), 1);
+        assert_eq!(content.text(), "Hi there\n");
+        assert_eq!(content.cursor_position(), (0, 8));
+
+        content.undo();
+        assert_eq!(content.text(), "Hi\n");
+        assert_eq!(content.cursor_position(), (0, 2));
+        assert!(!content.can_undo());
+
+        content.redo();
+        content.redo();
+        assert_eq!(content.line_count(), 2);
+        assert_eq!(content.cursor_position(), (1, 0));
+    }
+
+    #[test]
+    fn undo_and_redo_restore_selection() {
+        let mut content = Content::with_text("hello world");
+
+        for _ in 0..5 {
+            content.perform(Action::Select(Motion::Right));
+        }
+
+        content.perform(Action::Edit(Edit::Insert('X')));
+        assert_eq!(content.text(), "X world\n");
+
+        content.undo();
+        assert_eq!(content.text(), "hello world\n");
+        assert_eq!(content.selection().as_deref(), Some("hello"));
+        assert_eq!(content.main_cursor(), ((0, 5), Some((0, 0))));
+
+        content.redo();
+        assert_eq!(content.text(), "X world\n");
+        assert_eq!(content.selection(), None);
+        assert_eq!(content.main_cursor(), ((0, 1), None));
+    }
+
+    fn found(start: (usize, usize), end: (usize, usize)) -> Option<Match> {
+        Some(Match { start, end })
+    }
+
+    #[test]
+    fn find_next_and_previous_wrap_around() {
+        let mut content = Content::with_text("foo bar\nbar foo\nfoo");
+        let search = Search::new("foo");
+
+        assert_eq!(content.find_next(&search), found((0, 0), (0, 3)));
+        assert_eq!(content.selection().as_deref(), Some("foo"));
+        assert_eq!(content.main_cursor(), ((0, 3), Some((0, 0))));
+
+        assert_eq!(content.find_next(&search), found((1, 4), (1, 7)));
+        assert_eq!(content.find_next(&search), found((2, 0), (2, 3)));
+        assert_eq!(content.find_next(&search), found((0, 0), (0, 3)));
+
+        assert_eq!(content.find_previous(&search), found((2, 0), (2, 3)));
+        assert_eq!(content.find_previous(&search), found((1, 4), (1, 7)));
+        assert_eq!(content.main_cursor(), ((1, 7), Some((1, 4))));
+
+        assert_eq!(content.find_next(&Search::new("baz")), None);
+    }
+
+    #[test]
+    fn replace_reviews_match_before_replacing_it() {
+        let mut content = Content::with_text("foo bar\nbar foo");
+        let search = Search::new("foo");
+
+        assert_eq!(content.replace(&search, "baz"), found((0, 0), (0, 3)));
+        assert_eq!(content.text(), "foo bar\nbar foo\n");
+
+        assert_eq!(content.replace(&search, "baz"), found((1, 4), (1, 7)));
+        assert_eq!(content.text(), "baz bar\nbar foo\n");
+        assert_eq!(content.selection().as_deref(), Some("foo"));
+
+        assert_eq!(content.replace(&search, "baz"), None);
+        assert_eq!(content.text(), "baz bar\nbar baz\n");
+    }
+
+    #[test]
+    fn replace_all_is_undone_at_once() {
+        let mut content = Content::with_text("foo bar\nbar foo\nfoo");
+
+        assert_eq!(content.replace_all(&Search::new("foo"), "qux"), 3);
+        assert_eq!(content.text(), "qux bar\nbar qux\nqux\n");
+
+        content.undo();
+        assert_eq!(content.text(), "foo bar\nbar foo\nfoo\n");
+        assert!(!content.can_undo());
+
+        assert_eq!(content.replace_all(&Search::new("baz"), "qux"), 0);
+    }
+
+    #[test]
+    fn typing_at_cursors_on_the_same_line() {
+        let mut content = with_cursors("ab cd", &[(0, 0), (0, 3)]);
+
+        content.perform(Action::Edit(Edit::Insert('x')));
+
+        assert_eq!(content.text(), "xab xcd\n");
+        assert_eq!(content.cursor_positions(), vec![(0, 1), (0, 5)]);
+    }
+
+    #[test]
+    fn backspace_at_start_of_lines_joins_them() {
+        let mut content = with_cursors("ab\ncd\nef", &[(1, 0), (2, 0)]);
+
+        content.perform(Action::Edit(Edit::Backspace));
+
+        assert_eq!(content.text(), "abcdef\n");
+        assert_eq!(content.cursor_positions(), vec![(0, 2), (0, 4)]);
+    }
+
+    #[test]
+    fn delete_at_end_of_lines_joins_them() {
+        let mut content = with_cursors("ab\ncd\nef", &[(0, 2), (1, 2)]);
+
+        content.perform(Action::Edit(Edit::Delete));
+
+        assert_eq!(content.text(), "abcdef\n");
+        assert_eq!(content.cursor_positions(), vec![(0, 2), (0, 4)]);
+    }
+
+    #[test]
+    fn multi_line_paste_at_every_cursor() {
+        let mut content = with_cursors("ab\ncd", &[(0, 1), (1, 1)]);
+
+        content
+            .perform(Action::Edit(Edit::Paste(Arc::new(String::from("x\ny")))));
+
+        assert_eq!(content.text(), "ax\nyb\ncx\nyd\n");
+        assert_eq!(content.cursor_positions(), vec![(1, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn overlapping_cursors_are_merged() {
+        let mut content = with_cursors("abc", &[(0, 1), (0, 2)]);
+
+        content.perform(Action::Edit(Edit::Backspace));
+
+        assert_eq!(content.text(), "c\n");
+        assert_eq!(content.cursor_positions(), vec![(0, 0)]);
+        assert!(!content.has_carets());
+
+        let mut content = Content::with_text("abcd");
+
+        content.0.get_mut().set_cursors(
+            vec![
+                Selection {
+                    cursor: (0, 3),
+                    anchor: Some((0, 0)),
+                },
+                Selection {
+                    cursor: (0, 2),
+                    anchor: None,
+                },
+            ],
+            Selection {
+                cursor: (0, 2),
+                anchor: None,
+            },
+        );
+
+        assert_eq!(content.cursor_positions(), vec![(0, 3)]);
+        assert_eq!(content.selection().as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn multi_cursor_edit_is_undone_at_once() {
+        let mut content = with_cursors("ab cd", &[(0, 0), (0, 3)]);
+
+        content.perform(Action::Edit(Edit::Insert('x')));
+        content.perform(Action::Edit(Edit::Insert('y')));
+
+        assert_eq!(content.text(), "xyab xycd\n");
+
+        content.undo();
+        assert_eq!(content.text(), "xab xcd\n");
+
+        content.undo();
+        assert_eq!(content.text(), "ab cd\n");
+        assert!(!content.can_undo());
+    }
+
+    #[test]
+    fn block_selection_clamps_to_shorter_lines() {
+        let mut content = Content::with_text("héllo\nab\nwörld");
+
+        content.0.get_mut().extend_block(
+            Block {
+                anchor: (0, 1),
+                carets: Vec::new(),
+            },
+            (2, 5),
+        );
+
+        assert_eq!(content.cursor_positions(), vec![(0, 5), (1, 2), (2, 5)]);
+        assert_eq!(content.selection().as_deref(), Some("éll\nb\nörl"));
+    }
+}
@@ -0,0 +1,342 @@
+//! Find text in the [`Content`] of a text editor.
+//!
+//! [`Content`]: super::Content
+use crate::core::text::highlighter;
+
+use std::ops::Range;
+
+/// A query to find text in the [`Content`] of a text editor.
+///
+/// Matches never span multiple lines.
+///
+/// [`Content`]: super::Content
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Search {
+    /// The text to find.
+    pub query: String,
+    /// Whether letter case must match exactly.
+    pub case_sensitive: bool,
+    /// Whether matches must be surrounded by word boundaries.
+    pub whole_word: bool,
+    /// Whether the query is a regular expression.
+    #[cfg(feature = "regex")]
+    pub regex: bool,
+}
+
+impl Search {
+    /// Creates a new [`Search`] for the given query.
+    ///
+    /// By default, the search ignores case and matches within words.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether the [`Search`] is case sensitive.
+    #[must_use]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Sets whether the [`Search`] only matches whole words.
+    #[must_use]
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.whole_word = whole_word;
+        self
+    }
+
+    /// Sets whether the query of the [`Search`] is a regular expression.
+    ///
+    /// Replacements of a regular expression [`Search`] may refer to its
+    /// capture groups (e.g. `$1` or `${name}`).
+    #[cfg(feature = "regex")]
+    #[must_use]
+    pub fn regex(mut self, regex: bool) -> Self {
+        self.regex = regex;
+        self
+    }
+
+    /// Returns whether the [`Search`] can produce any matches.
+    ///
+    /// An empty query or an invalid regular expression never matches.
+    pub fn is_valid(&self) -> bool {
+        Matcher::new(self).is_some()
+    }
+}
+
+/// A match of a [`Search`].
+///
+/// Positions are given as line and column, respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Match {
+    /// The position where the match starts.
+    pub start: (usize, usize),
+    /// The position right after the end of the match.
+    pub end: (usize, usize),
+}
+
+/// A [`Highlighter`] of the matches of a [`Search`].
+///
+/// It can be used with [`TextEditor::highlight_with`] to make matches
+/// stand out:
+///
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::text::highlighter;
+/// use iced::widget::text_editor::{self, search, Search};
+///
+/// fn view<'a>(
+///     content: &'a text_editor::Content,
+///     search: &Search,
+/// ) -> Element<'a, text_editor::Action> {
+///     text_editor(content)
+///         .highlight_with::<search::Highlighter>(
+///             search.clone(),
+///             |_match, theme| highlighter::Format {
+///                 color: Some(theme.palette().primary),
+///                 font: None,
+///             },
+///         )
+///         .on_action(std::convert::identity)
+///         .into()
+/// }
+/// ```
+///
+/// [`Highlighter`]: highlighter::Highlighter
+/// [`TextEditor::highlight_with`]: super::TextEditor::highlight_with
+#[derive(Debug)]
+pub struct Highlighter {
+    matcher: Option<Matcher>,
+    current_line: usize,
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Search;
+    type Highlight = ();
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, ())>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            matcher: Matcher::new(settings),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.matcher = Matcher::new(new_settings);
+        self.current_line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line += 1;
+
+        self.matcher
+            .as_ref()
+            .map(|matcher| matcher.find(line))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|range| (range, ()))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// A compiled [`Search`].
+#[derive(Debug)]
+pub(super) enum Matcher {
+    Literal {
+        query: String,
+        case_sensitive: bool,
+        whole_word: bool,
+    },
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Matcher {
+    /// Compiles the given [`Search`], unless it can never match.
+    pub fn new(search: &Search) -> Option<Self> {
+        if search.query.is_empty() {
+            return None;
+        }
+
+        #[cfg(feature = "regex")]
+        if search.regex {
+            let pattern = if search.whole_word {
+                format!(r"\b(?:{})\b", search.query)
+            } else {
+                search.query.clone()
+            };
+
+            return regex::RegexBuilder::new(&pattern)
+                .case_insensitive(!search.case_sensitive)
+                .build()
+                .ok()
+                .map(Self::Regex);
+        }
+
+        Some(Self::Literal {
+            query: search.query.clone(),
+            case_sensitive: search.case_sensitive,
+            whole_word: search.whole_word,
+        })
+    }
+
+    /// Returns the byte ranges of the non-overlapping matches in the
+    /// given line.
+    pub fn find(&self, line: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Literal {
+                query,
+                case_sensitive,
+                whole_word,
+            } => {
+                let mut matches = Vec::new();
+                let mut start = 0;
+
+                while start < line.len() {
+                    let end = match_at(&line[start..], query, *case_sensitive)
+                        .map(|length| start + length)
+                        .filter(|&end| {
+                            !whole_word || is_whole_word(line, start..end)
+                        });
+
+                    if let Some(end) = end {
+                        matches.push(start..end);
+                        start = end;
+                    } else {
+                        start += line[start..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8);
+                    }
+                }
+
+                matches
+            }
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(line)
+                .filter(|found| !found.is_empty())
+                .map(|found| found.range())
+                .collect(),
+        }
+    }
+
+    /// Produces the text that replaces the match at the given range of
+    /// the line.
+    #[cfg_attr(not(feature = "regex"), allow(unused_variables))]
+    pub fn replacement(
+        &self,
+        line: &str,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        match self {
+            Self::Literal { .. } => replacement.to_owned(),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => {
+                let mut expanded = String::new();
+
+                if let Some(captures) =
+                    regex.captures_at(line, range.start).filter(|captures| {
+                        captures
+                            .get(0)
+                            .is_some_and(|whole| whole.range() == range)
+                    })
+                {
+                    captures.expand(replacement, &mut expanded);
+                } else {
+                    expanded.push_str(replacement);
+                }
+
+                expanded
+            }
+        }
+    }
+}
+
+/// Returns the byte length of the prefix of `haystack` matching `query`,
+/// if any.
+fn match_at(
+    haystack: &str,
+    query: &str,
+    case_sensitive: bool,
+) -> Option<usize> {
+    let mut chars = haystack.chars();
+    let mut length = 0;
+
+    for expected in query.chars() {
+        let actual = chars.next()?;
+
+        let is_equal = if case_sensitive {
+            actual == expected
+        } else {
+            actual.to_lowercase().eq(expected.to_lowercase())
+        };
+
+        if !is_equal {
+            return None;
+        }
+
+        length += actual.len_utf8();
+    }
+
+    Some(length)
+}
+
+/// Returns whether the given byte range of `line` starts and ends at word
+/// boundaries.
+fn is_whole_word(line: &str, range: Range<usize>) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    !line[..range.start].chars().next_back().is_some_and(is_word)
+        && !line[range.end..].chars().next().is_some_and(is_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(search: Search, line: &str) -> Vec<Range<usize>> {
+        Matcher::new(&search)
+            .map(|matcher| matcher.find(line))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn literal_search_respects_case() {
+        let line = "Foo foo FOO";
+
+        assert_eq!(find(Search::new("foo"), line), vec![0..3, 4..7, 8..11]);
+        assert_eq!(
+            find(Search::new("foo").case_sensitive(true), line),
+            vec![4..7]
+        );
+    }
+
+    #[test]
+    fn literal_search_respects_word_boundaries() {
+        let line = "cat concat cat_s cat.";
+
+        assert_eq!(
+            find(Search::new("cat").whole_word(true), line),
+            vec![0..3, 17..20]
+        );
+        assert!(find(Search::new(""), line).is_empty());
+    }
+}
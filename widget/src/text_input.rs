@@ -43,6 +43,7 @@ use editor::Editor;
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -60,9 +61,13 @@ use crate::core::{
     Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::preedit;
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -106,6 +111,14 @@ pub struct TextInput<
     Renderer: text::Renderer,
 {
     id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    a11y_id: widget::Id,
+    #[cfg(feature = "a11y")]
+    value_id: widget::Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     placeholder: String,
     value: Value,
     is_secure: bool,
@@ -136,6 +149,14 @@ where
     pub fn new(placeholder: &str, value: &str) -> Self {
         TextInput {
             id: None,
+            #[cfg(feature = "a11y")]
+            a11y_id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            value_id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
@@ -155,7 +176,41 @@ where
 
     /// Sets the [`Id`] of the [`TextInput`].
     pub fn id(mut self, id: impl Into<Id>) -> Self {
-        self.id = Some(id.into());
+        let id = id.into();
+
+        #[cfg(feature = "a11y")]
+        {
+            self.a11y_id = id.0.clone();
+        }
+
+        self.id = Some(id);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`TextInput`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description_widget<T: iced_accessibility::Describes>(
+        mut self,
+        description: &T,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
         self
     }
 
@@ -390,6 +445,11 @@ where
         }
     }
 
+    /// Returns the [`Style`] of the [`TextInput`] with the given [`Status`].
+    pub(crate) fn style_of(&self, theme: &Theme, status: Status) -> Style {
+        theme.style(&self.class, status)
+    }
+
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
@@ -436,7 +496,7 @@ where
                 border: style.border,
                 ..renderer::Quad::default()
             },
-            style.background,
+            style.background.clone(),
         );
 
         if self.icon.is_some() {
@@ -581,6 +641,60 @@ where
         } else {
             draw(renderer, text_bounds);
         }
+
+        if let Some(preedit) = state
+            .preedit
+            .as_ref()
+            .filter(|_| state.is_focused.is_some())
+        {
+            let caret = caret_bounds(state, text_bounds, value, self.alignment);
+
+            preedit::draw(
+                renderer,
+                preedit,
+                self.font.unwrap_or_else(|| renderer.default_font()),
+                self.size.unwrap_or_else(|| renderer.default_size()),
+                self.line_height,
+                caret.position(),
+                preedit::Style {
+                    background: style.background,
+                    value: style.value,
+                    selection: style.selection,
+                },
+                text_bounds,
+            );
+        }
+    }
+
+    /// Returns the [`InputMethod`] requested by the [`TextInput`].
+    fn input_method(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+    ) -> InputMethod {
+        if self.on_input.is_none()
+            || !state
+                .is_focused
+                .as_ref()
+                .is_some_and(|focus| focus.is_window_focused)
+        {
+            return InputMethod::Disabled;
+        }
+
+        let secure_value = self.is_secure.then(|| self.value.secure());
+        let value = secure_value.as_ref().unwrap_or(&self.value);
+
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        InputMethod::Enabled {
+            cursor: caret_bounds(state, text_bounds, value, self.alignment),
+            purpose: if self.is_secure {
+                input_method::Purpose::Secure
+            } else {
+                input_method::Purpose::Normal
+            },
+            preedit: state.preedit.clone(),
+        }
     }
 }
 
@@ -627,14 +741,17 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let id = self.id.as_ref().map(|id| &id.0);
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.container(id, layout.bounds(), &mut |operation| {
+            operation.focusable(state, id);
+            operation.text_input(state, id);
+        });
     }
 
     fn on_event(
@@ -1164,6 +1281,119 @@ where
                         ));
                     }
                 }
+
+                shell.request_input_method(&self.input_method(state, layout));
+            }
+            Event::InputMethod(event) => {
+                let state = state::<Renderer>(tree);
+
+                if state.is_focused.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                // Composition is only shown while the value can be edited
+                let Some(on_input) = &self.on_input else {
+                    state.preedit = None;
+
+                    return event::Status::Ignored;
+                };
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = (!content.is_empty()).then(|| {
+                            input_method::Preedit {
+                                content,
+                                selection,
+                                text_size: self.size,
+                            }
+                        });
+                    }
+                    input_method::Event::Commit(text) => {
+                        state.preedit = None;
+                        state.is_pasting = None;
+
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor);
+                        editor.paste(Value::new(&text));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        if let Some(focus) = &mut state.is_focused {
+                            focus.updated_at = Instant::now();
+                        }
+
+                        update_cache(state, &self.value);
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest {
+                    action,
+                    data,
+                    ..
+                },
+            ) if event_id == self.a11y_id => {
+                use iced_accessibility::accesskit::{Action, ActionData};
+
+                let state = state::<Renderer>(tree);
+
+                match (action, data) {
+                    (Action::Focus, _) => {
+                        if state.is_focused.is_none() {
+                            state.focus();
+                        }
+                    }
+                    (
+                        Action::SetValue | Action::ReplaceSelectedText,
+                        Some(ActionData::Value(text)),
+                    ) => {
+                        let Some(on_input) = &self.on_input else {
+                            return event::Status::Ignored;
+                        };
+
+                        state.is_pasting = None;
+
+                        if action == Action::SetValue {
+                            state.cursor.select_all(&self.value);
+                        }
+
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor);
+                        editor.paste(Value::new(&text));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        update_cache(state, &self.value);
+                    }
+                    (
+                        Action::SetTextSelection,
+                        Some(ActionData::SetTextSelection(selection)),
+                    ) => {
+                        let len = self.value.len();
+
+                        state.cursor.select_range(
+                            selection.anchor.character_index.min(len),
+                            selection.focus.character_index.min(len),
+                        );
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
             }
             _ => {}
         }
@@ -1202,6 +1432,134 @@ where
             mouse::Interaction::default()
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, NodeBuilder, NodeId, Rect, Role, TextPosition,
+                TextSelection,
+            },
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let mut node = NodeBuilder::new(Role::TextInput);
+        node.add_action(Action::Focus);
+        node.set_bounds(bounds);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter()
+                        .cloned()
+                        .map(|id| NodeId::from(id))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if !self.placeholder.is_empty() {
+            node.set_placeholder(self.placeholder.clone());
+        }
+
+        node.set_value(value.to_string());
+
+        if self.on_input.is_none() {
+            node.set_disabled();
+        } else {
+            node.add_action(Action::SetValue);
+            node.add_action(Action::ReplaceSelectedText);
+            node.add_action(Action::SetTextSelection);
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        let value_node_id = NodeId::from(A11yId::Widget(self.value_id.clone()));
+
+        let (anchor, focus) = match state.cursor.state(&self.value) {
+            cursor::State::Index(index) => (index, index),
+            cursor::State::Selection { start, end } => (start, end),
+        };
+
+        node.set_text_selection(TextSelection {
+            anchor: TextPosition {
+                node: value_node_id,
+                character_index: anchor,
+            },
+            focus: TextPosition {
+                node: value_node_id,
+                character_index: focus,
+            },
+        });
+
+        let mut value_node = NodeBuilder::new(Role::InlineTextBox);
+        value_node.set_bounds(bounds);
+        value_node.set_value(value.to_string());
+        value_node.set_character_lengths(value.grapheme_lengths());
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.a11y_id.clone()),
+            A11yTree::leaf(value_node, self.value_id.clone()),
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    fn id(&self) -> Option<widget::Id> {
+        use crate::core::id::Internal;
+
+        Some(widget::Id(Internal::Set(vec![
+            self.a11y_id.0.clone(),
+            self.value_id.0.clone(),
+        ])))
+    }
+
+    #[cfg(feature = "a11y")]
+    fn set_id(&mut self, id: widget::Id) {
+        use crate::core::id::Internal;
+
+        if let Internal::Set(list) = id.0 {
+            if list.len() == 2 {
+                self.a11y_id.0 = list[0].clone();
+                self.value_id.0 = list[1].clone();
+            }
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
@@ -1324,6 +1682,7 @@ pub struct State<P: text::Paragraph> {
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
@@ -1375,6 +1734,7 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1469,6 +1829,44 @@ fn measure_cursor_and_scroll_offset(
     (grapheme_position.x, offset)
 }
 
+/// Computes the bounds of the caret of a [`TextInput`], taking scrolling and
+/// alignment into account.
+///
+/// If some text is selected, the caret is placed at the start of the
+/// selection.
+fn caret_bounds<P: text::Paragraph>(
+    state: &State<P>,
+    text_bounds: Rectangle,
+    value: &Value,
+    alignment: alignment::Horizontal,
+) -> Rectangle {
+    let index = match state.cursor.state(value) {
+        cursor::State::Index(index) => index,
+        cursor::State::Selection { start, end } => start.min(end),
+    };
+
+    let (caret_x, _) =
+        measure_cursor_and_scroll_offset(state.value.raw(), text_bounds, index);
+
+    let paragraph = if value.is_empty() {
+        state.placeholder.raw()
+    } else {
+        state.value.raw()
+    };
+
+    let alignment_offset =
+        alignment_offset(text_bounds.width, paragraph.min_width(), alignment);
+
+    Rectangle {
+        x: (text_bounds.x + caret_x + alignment_offset
+            - offset(text_bounds, value, state))
+        .floor(),
+        y: text_bounds.y,
+        width: 1.0,
+        height: text_bounds.height,
+    }
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1541,7 +1939,7 @@ pub enum Status {
 }
 
 /// The appearance of a text input.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the text input.
     pub background: Background,
@@ -1644,3 +2042,100 @@ fn alignment_offset(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Input(String),
+    }
+
+    fn simulate(
+        text_input: &mut TextInput<'_, Message, Theme, ()>,
+        events: impl IntoIterator<Item = Event>,
+    ) -> (
+        Vec<event::Status>,
+        Vec<Message>,
+        Option<input_method::Preedit>,
+    ) {
+        let mut tree =
+            Tree::new(&*text_input as &dyn Widget<Message, Theme, ()>);
+
+        let node = Widget::<Message, Theme, ()>::layout(
+            text_input,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+
+        state::<()>(&mut tree).focus();
+
+        let mut messages = Vec::new();
+
+        let statuses = events
+            .into_iter()
+            .map(|event| {
+                let mut shell = Shell::new(&mut messages);
+
+                text_input.on_event(
+                    &mut tree,
+                    event,
+                    Layout::new(&node),
+                    mouse::Cursor::Unavailable,
+                    &(),
+                    &mut clipboard::Null,
+                    &mut shell,
+                    &Rectangle::with_size(Size::INFINITY),
+                )
+            })
+            .collect();
+
+        let preedit = state::<()>(&mut tree).preedit.clone();
+
+        (statuses, messages, preedit)
+    }
+
+    fn composition() -> [Event; 3] {
+        [
+            Event::InputMethod(input_method::Event::Opened),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("にほん"),
+                Some(0..9),
+            )),
+            Event::InputMethod(input_method::Event::Commit(String::from(
+                "日本",
+            ))),
+        ]
+    }
+
+    #[test]
+    fn input_method_commit_into_focused_input() {
+        let mut text_input =
+            TextInput::new("Name", "").on_input(Message::Input);
+
+        let (statuses, messages, preedit) =
+            simulate(&mut text_input, composition());
+
+        assert!(statuses
+            .iter()
+            .all(|status| *status == event::Status::Captured));
+        assert_eq!(messages, vec![Message::Input(String::from("日本"))]);
+        assert!(preedit.is_none());
+    }
+
+    #[test]
+    fn input_method_is_ignored_when_not_editable() {
+        let mut text_input = TextInput::new("Name", "");
+
+        let (statuses, messages, preedit) =
+            simulate(&mut text_input, composition().into_iter().take(2));
+
+        assert!(statuses
+            .iter()
+            .all(|status| *status == event::Status::Ignored));
+        assert!(messages.is_empty());
+        assert!(preedit.is_none());
+    }
+}
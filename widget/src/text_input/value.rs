@@ -31,6 +31,15 @@ impl Value {
         self.graphemes.len()
     }
 
+    /// Returns the length in bytes of every grapheme in the [`Value`].
+    #[cfg(feature = "a11y")]
+    pub(crate) fn grapheme_lengths(&self) -> Vec<u8> {
+        self.graphemes
+            .iter()
+            .map(|grapheme| u8::try_from(grapheme.len()).unwrap_or(u8::MAX))
+            .collect()
+    }
+
     /// Returns the position of the previous start of a word from the given
     /// grapheme `index`.
     pub fn previous_start_of_word(&self, index: usize) -> usize {
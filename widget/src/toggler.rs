@@ -37,18 +37,24 @@ use std::borrow::Cow;
 use iced_runtime::core::border::Radius;
 
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolable};
 use crate::core::event;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Id};
+use crate::core::window;
 use crate::core::{
     id, Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::focus_ring;
 
 /// A toggler widget.
 ///
@@ -308,11 +314,17 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            is_focused: false,
+            label: widget::text::State::default(),
+            handle: Animation::new(progress(self.is_toggled))
+                .duration(ANIMATION_DURATION),
+            now: Instant::now(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -336,12 +348,11 @@ where
             |_| layout::Node::new(crate::core::Size::new(48., 24.)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -362,9 +373,23 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if self.on_toggle.is_some() {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+            operation.focusable(state, Some(&self.id));
+        }
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -373,6 +398,19 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+            state.handle.go_mut(progress(self.is_toggled), now);
+
+            if let Some(redraw_request) = state.handle.redraw_request(now) {
+                shell.request_redraw(redraw_request);
+            }
+
+            return event::Status::Ignored;
+        }
+
         let Some(on_toggle) = &self.on_toggle else {
             return event::Status::Ignored;
         };
@@ -387,9 +425,23 @@ where
 
                     event::Status::Captured
                 } else {
+                    state.is_focused = false;
+
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        keyboard::key::Named::Space
+                        | keyboard::key::Named::Enter,
+                    ),
+                ..
+            }) if state.is_focused => {
+                shell.publish(on_toggle(!self.is_toggled));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -425,17 +477,16 @@ where
     ) {
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         if self.label.is_some() {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 style,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style::default(),
                 viewport,
             );
@@ -444,19 +495,26 @@ where
         let bounds = toggler_layout.bounds();
         let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let status = if self.on_toggle.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            Status::Hovered {
-                is_toggled: self.is_toggled,
-            }
-        } else {
-            Status::Active {
-                is_toggled: self.is_toggled,
+        let status = |is_toggled| {
+            if self.on_toggle.is_none() {
+                Status::Disabled
+            } else if is_mouse_over {
+                Status::Hovered { is_toggled }
+            } else {
+                Status::Active { is_toggled }
             }
         };
 
-        let style = theme.style(&self.class, status);
+        let handle = state.handle.value(state.now);
+
+        let style = if state.handle.is_animating(state.now) {
+            theme.style(&self.class, status(false)).interpolate(
+                &theme.style(&self.class, status(true)),
+                handle.clamp(0.0, 1.0),
+            )
+        } else {
+            theme.style(&self.class, status(self.is_toggled))
+        };
 
         let space = style.handle_margin;
 
@@ -482,11 +540,11 @@ where
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - space - (bounds.height - (2.0 * space))
-                } else {
-                    space
-                },
+                + space
+                + handle
+                    * (bounds.width
+                        - 2.0 * space
+                        - (bounds.height - 2.0 * space)),
             y: bounds.y + space,
             width: bounds.height - (2.0 * space),
             height: bounds.height - (2.0 * space),
@@ -504,6 +562,17 @@ where
             },
             style.foreground,
         );
+
+        if state.is_focused && self.on_toggle.is_some() {
+            if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    style.border_radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     #[cfg(feature = "a11y")]
@@ -617,6 +686,41 @@ where
     }
 }
 
+/// The duration of the transition of the handle of a [`Toggler`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(150);
+
+/// Returns the progress of the handle of a [`Toggler`] in the given state.
+fn progress(is_toggled: bool) -> f32 {
+    if is_toggled {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// The local state of a [`Toggler`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    label: widget::text::State<P>,
+    handle: Animation<f32>,
+    now: Instant,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Toggler`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -657,6 +761,36 @@ pub struct Style {
     pub handle_margin: f32,
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            background_border_width: self
+                .background_border_width
+                .interpolate(&other.background_border_width, ratio),
+            background_border_color: self
+                .background_border_color
+                .interpolate(&other.background_border_color, ratio),
+            foreground: self.foreground.interpolate(&other.foreground, ratio),
+            foreground_border_width: self
+                .foreground_border_width
+                .interpolate(&other.foreground_border_width, ratio),
+            foreground_border_color: self
+                .foreground_border_color
+                .interpolate(&other.foreground_border_color, ratio),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, ratio),
+            handle_radius: self
+                .handle_radius
+                .interpolate(&other.handle_radius, ratio),
+            handle_margin: self
+                .handle_margin
+                .interpolate(&other.handle_margin, ratio),
+        }
+    }
+}
+
 /// The theme catalog of a [`Toggler`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -667,6 +801,15 @@ pub trait Catalog: Sized {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`focus_ring::Style`] of a focused [`Toggler`] of the given
+    /// class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
 }
 
 /// A styling function for a [`Toggler`].
@@ -684,6 +827,13 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
 }
 
 /// The default style of a [`Toggler`].
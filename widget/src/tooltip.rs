@@ -22,18 +22,24 @@
 //! }
 //! ```
 use crate::container;
+use crate::core::animation::Animation;
 use crate::core::event::{self, Event};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
     Vector,
 };
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// An element to display a widget over another.
 ///
 /// # Example
@@ -73,7 +79,12 @@ pub struct Tooltip<
     gap: f32,
     padding: f32,
     snap_within_viewport: bool,
+    delay: Duration,
     class: Theme::Class<'a>,
+    #[cfg(feature = "a11y")]
+    id: widget::Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
 }
 
 impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
@@ -99,10 +110,22 @@ where
             gap: 0.0,
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
+            delay: Duration::ZERO,
             class: Theme::default(),
+            #[cfg(feature = "a11y")]
+            id: widget::Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
         }
     }
 
+    #[cfg(feature = "a11y")]
+    /// Sets the text announced by assistive technologies for the [`Tooltip`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Sets the gap between the content and its [`Tooltip`].
     pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
         self.gap = gap.into().0;
@@ -121,6 +144,13 @@ where
         self
     }
 
+    /// Sets the time the content must be hovered before the [`Tooltip`] is
+    /// shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     #[must_use]
     pub fn style(
@@ -203,16 +233,36 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
 
-        let was_idle = *state == State::Idle;
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => now,
+            _ => Instant::now(),
+        };
+
+        let was_visible = state.is_visible(now);
 
-        *state = cursor
-            .position_over(layout.bounds())
-            .map(|cursor_position| State::Hovered { cursor_position })
-            .unwrap_or_default();
+        match cursor.position_over(layout.bounds()) {
+            Some(cursor_position) => {
+                if state.cursor_position.is_none() {
+                    state.visibility = Animation::new(0.0)
+                        .duration(Duration::ZERO)
+                        .delay(self.delay)
+                        .go(1.0, now);
+                }
 
-        let is_idle = *state == State::Idle;
+                state.cursor_position = Some(cursor_position);
 
-        if was_idle != is_idle {
+                if let Some(redraw_request) =
+                    state.visibility.redraw_request(now)
+                {
+                    shell.request_redraw(redraw_request);
+                }
+            }
+            None => {
+                *state = State::default();
+            }
+        }
+
+        if was_visible != state.is_visible(now) {
             shell.invalidate_layout();
         }
 
@@ -284,7 +334,10 @@ where
             translation,
         );
 
-        let tooltip = if let State::Hovered { cursor_position } = *state {
+        let tooltip = if let Some(cursor_position) = state
+            .cursor_position
+            .filter(|_| state.is_visible(Instant::now()))
+        {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &self.tooltip,
@@ -312,6 +365,77 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, NodeId, Rect, Role},
+            A11yId, A11yTree,
+        };
+
+        let is_hovered = state
+            .state
+            .downcast_ref::<State>()
+            .is_visible(Instant::now());
+
+        let mut content = self.content.as_widget().a11y_nodes(
+            layout,
+            &state.children[0],
+            cursor,
+        );
+
+        let Some(name) = self.name.as_ref() else {
+            return content;
+        };
+
+        let tooltip_node_id = NodeId::from(A11yId::Widget(self.id.clone()));
+
+        for root in content.root_mut() {
+            let node = root.node_mut();
+            let mut described_by = node.described_by().to_vec();
+            described_by.push(tooltip_node_id);
+            node.set_described_by(described_by);
+        }
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let mut node = NodeBuilder::new(Role::Tooltip);
+        node.set_bounds(bounds);
+        node.set_name(name.clone());
+        if !is_hovered {
+            node.set_hidden();
+        }
+
+        A11yTree::join(
+            [content, A11yTree::leaf(node, self.id.clone())].into_iter(),
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    fn id(&self) -> Option<widget::Id> {
+        Some(self.id.clone())
+    }
+
+    #[cfg(feature = "a11y")]
+    fn set_id(&mut self, id: widget::Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
@@ -344,13 +468,25 @@ pub enum Position {
     FollowCursor,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-enum State {
-    #[default]
-    Idle,
-    Hovered {
-        cursor_position: Point,
-    },
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct State {
+    cursor_position: Option<Point>,
+    visibility: Animation<f32>,
+}
+
+impl State {
+    fn is_visible(&self, now: Instant) -> bool {
+        self.cursor_position.is_some() && !self.visibility.is_animating(now)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            cursor_position: None,
+            visibility: Animation::new(0.0),
+        }
+    }
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
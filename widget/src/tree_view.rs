@@ -0,0 +1,1604 @@
+//! Browse hierarchical data with expandable and collapsible items.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tree_view::{Children, Select};
+//! use iced::widget::{text, tree_view};
+//!
+//! use std::collections::HashSet;
+//! use std::path::PathBuf;
+//!
+//! struct Entry {
+//!     path: PathBuf,
+//!     // `None` until the directory is read
+//!     children: Option<Vec<Entry>>,
+//!     is_directory: bool,
+//! }
+//!
+//! struct State {
+//!     entries: Vec<Entry>,
+//!     expanded: HashSet<PathBuf>,
+//!     selected: Option<PathBuf>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggle(PathBuf, bool),
+//!     Select(Select<PathBuf>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tree_view(
+//!         &state.entries,
+//!         |entry| entry.path.clone(),
+//!         |entry| text(entry.path.display().to_string()),
+//!     )
+//!     .children(|entry| match &entry.children {
+//!         _ if !entry.is_directory => Children::Leaf,
+//!         Some(children) => Children::Loaded(children),
+//!         None => Children::Unloaded,
+//!     })
+//!     .expanded(|path| state.expanded.contains(path))
+//!     .on_toggle(Message::Toggle)
+//!     .selected(|path| state.selected.as_ref() == Some(path))
+//!     .on_select(Message::Select)
+//!     .into()
+//! }
+//! ```
+use crate::anchored::{Anchored, Scroll};
+use crate::container::{self, Container};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::scrollable::{
+    AbsoluteOffset, RelativeOffset,
+};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::focus_ring;
+use crate::scrollable::{self, Scrollable};
+
+use std::ops::Range;
+
+/// A widget that displays a hierarchy of items as indented, expandable
+/// rows.
+///
+/// Only the children of expanded items are visited and only the rows
+/// visible inside its [`Scrollable`] are built, so a [`TreeView`] stays
+/// fast with large hierarchies. Expensive rows can be cached further by
+/// wrapping them in `lazy`.
+///
+/// Expansion and selection are driven by messages, so the hierarchy can be
+/// loaded on demand: an item with [`Children::Unloaded`] can be expanded,
+/// and its children can be provided once [`TreeView::on_toggle`] reports it.
+#[allow(missing_debug_implementations)]
+pub struct TreeView<
+    'a,
+    T,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    roots: &'a [T],
+    key: Box<dyn Fn(&'a T) -> Key + 'a>,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    children: Option<Box<dyn Fn(&'a T) -> Children<'a, T> + 'a>>,
+    is_expanded: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    is_selected: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Select<Key>) -> Message + 'a>>,
+    selection: Selection,
+    id: Option<Id>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    indent: f32,
+    padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+/// The children of an item of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Children<'a, T> {
+    /// The item cannot have children.
+    Leaf,
+    /// The item may have children, but they have not been loaded yet.
+    Unloaded,
+    /// The children of the item.
+    Loaded(&'a [T]),
+}
+
+/// How many items of a [`TreeView`] can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Selection {
+    /// A single item can be selected.
+    #[default]
+    Single,
+    /// Multiple items can be selected, with Ctrl or Shift.
+    Multiple,
+}
+
+/// A change to the selection of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Select<Key> {
+    /// Only the item with the given key should be selected.
+    Only(Key),
+    /// The selection of the item with the given key should be toggled.
+    Toggle(Key),
+    /// Only the items with the given keys should be selected.
+    ///
+    /// They are the visible items between the last one selected and the
+    /// one clicked, in order.
+    Range(Vec<Key>),
+}
+
+impl<'a, T, Key, Message, Theme, Renderer>
+    TreeView<'a, T, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of the rows of a [`TreeView`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 28.0;
+
+    /// The default indentation of each level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 18.0;
+
+    /// The default [`Padding`] of the rows of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 0.0,
+        bottom: 0.0,
+        right: 8.0,
+        left: 4.0,
+    };
+
+    /// Creates a new [`TreeView`] with the given root items, a closure that
+    /// identifies an item and a closure that produces its row.
+    pub fn new<E>(
+        roots: &'a [T],
+        key: impl Fn(&'a T) -> Key + 'a,
+        view: impl Fn(&'a T) -> E + 'a,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            roots,
+            key: Box::new(key),
+            view: Box::new(move |item| view(item).into()),
+            children: None,
+            is_expanded: None,
+            is_selected: None,
+            on_toggle: None,
+            on_select: None,
+            selection: Selection::default(),
+            id: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the closure that returns the [`Children`] of an item.
+    ///
+    /// It is only called for items whose parent is expanded. Without it,
+    /// every item is a [`Children::Leaf`].
+    pub fn children(
+        mut self,
+        children: impl Fn(&'a T) -> Children<'a, T> + 'a,
+    ) -> Self {
+        self.children = Some(Box::new(children));
+        self
+    }
+
+    /// Sets the function used to know whether the item with the given key
+    /// is expanded.
+    pub fn expanded(mut self, is_expanded: impl Fn(&Key) -> bool + 'a) -> Self {
+        self.is_expanded = Some(Box::new(is_expanded));
+        self
+    }
+
+    /// Sets the message that should be produced when an item is expanded
+    /// or collapsed, by clicking its toggle or with the arrow keys.
+    ///
+    /// The closure receives the key of the item and whether it should be
+    /// expanded.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(Key, bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the function used to know whether the item with the given key
+    /// is selected.
+    pub fn selected(mut self, is_selected: impl Fn(&Key) -> bool + 'a) -> Self {
+        self.is_selected = Some(Box::new(is_selected));
+        self
+    }
+
+    /// Sets the message that should be produced when the selection of the
+    /// [`TreeView`] changes, by clicking an item or with the keyboard.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Select<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the [`Selection`] mode of the [`TreeView`].
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    ///
+    /// This lets you focus the [`TreeView`] to navigate it with the keyboard,
+    /// and scroll it with the [`Task`] functions in the [`scrollable`]
+    /// module, including
+    /// [`scroll_to_index`](scrollable::scroll_to_index) with the index of a
+    /// visible row.
+    ///
+    /// [`Task`]: crate::runtime::Task
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of every row of the [`TreeView`].
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the rows of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeView`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, T, Key, Message, Theme, Renderer>
+    From<TreeView<'a, T, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + container::Catalog + scrollable::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, T, Key, Message, Theme, Renderer>) -> Self {
+        let mut entries = Vec::new();
+
+        flatten(
+            tree_view.roots,
+            0,
+            None,
+            &*tree_view.key,
+            tree_view.children.as_deref(),
+            tree_view.is_expanded.as_deref(),
+            &mut entries,
+        );
+
+        let body = Body {
+            id: tree_view.id.clone(),
+            entries,
+            view: tree_view.view,
+            row_height: tree_view.row_height,
+            indent: tree_view.indent,
+            padding: tree_view.padding,
+            is_selected: tree_view.is_selected,
+            on_toggle: tree_view.on_toggle,
+            on_select: tree_view.on_select,
+            selection: tree_view.selection,
+            class: tree_view.class,
+            range: 0..0,
+            rows: Vec::new(),
+        };
+
+        let mut scrollable = Scrollable::new(Element::new(body))
+            .width(tree_view.width)
+            .height(tree_view.height);
+
+        if let Some(id) = tree_view.id {
+            scrollable = scrollable.id(id);
+        }
+
+        Anchored::new(scrollable, pending::<Key>, pending::<Key>).into()
+    }
+}
+
+/// The number of rows built when the visible rows of a [`TreeView`] are not
+/// known yet.
+const INITIAL_ROWS: usize = 50;
+
+/// The number of rows built above and below the visible ones, so fast
+/// scrolling does not reveal empty space.
+const OVERSCAN: usize = 5;
+
+/// Whether an item of a [`TreeView`] can be expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expansion {
+    Leaf,
+    Collapsed,
+    Expanded,
+}
+
+/// A visible item of a [`TreeView`].
+struct Entry<'a, T, Key> {
+    item: &'a T,
+    key: Key,
+    depth: usize,
+    parent: Option<usize>,
+    expansion: Expansion,
+}
+
+/// Appends the given items and the descendants of the expanded ones, in
+/// the order they are displayed.
+fn flatten<'a, T, Key>(
+    items: &'a [T],
+    depth: usize,
+    parent: Option<usize>,
+    key: &dyn Fn(&'a T) -> Key,
+    children: Option<&(dyn Fn(&'a T) -> Children<'a, T> + 'a)>,
+    is_expanded: Option<&(dyn Fn(&Key) -> bool + 'a)>,
+    entries: &mut Vec<Entry<'a, T, Key>>,
+) {
+    for item in items {
+        let item_key = key(item);
+        let item_children =
+            children.map_or(Children::Leaf, |children| children(item));
+
+        let expansion = match item_children {
+            Children::Leaf => Expansion::Leaf,
+            _ if is_expanded
+                .is_some_and(|is_expanded| is_expanded(&item_key)) =>
+            {
+                Expansion::Expanded
+            }
+            _ => Expansion::Collapsed,
+        };
+
+        let index = entries.len();
+
+        entries.push(Entry {
+            item,
+            key: item_key,
+            depth,
+            parent,
+            expansion,
+        });
+
+        if let (Expansion::Expanded, Children::Loaded(descendants)) =
+            (expansion, item_children)
+        {
+            flatten(
+                descendants,
+                depth + 1,
+                Some(index),
+                key,
+                children,
+                is_expanded,
+                entries,
+            );
+        }
+    }
+}
+
+struct Body<'a, T, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    id: Option<Id>,
+    entries: Vec<Entry<'a, T, Key>>,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    row_height: f32,
+    indent: f32,
+    padding: Padding,
+    is_selected: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Select<Key>) -> Message + 'a>>,
+    selection: Selection,
+    class: Theme::Class<'a>,
+    range: Range<usize>,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The local state of the rows of a [`TreeView`].
+///
+/// The children of its [`Tree`] hold a single [`Tree`] whose children are
+/// the states of the rows currently built, in the stored range.
+struct BodyState<Key> {
+    range: Option<Range<usize>>,
+    /// The keys of the rows currently built.
+    keys: Vec<Key>,
+    /// The item navigated to with the keyboard or clicked last.
+    cursor: Option<Key>,
+    /// The item where a range selection starts.
+    anchor: Option<Key>,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+    /// The scroll requested by the [`TreeView`], taken by its [`Anchored`]
+    /// scrollable.
+    scroll: Option<Scroll>,
+    row_height: f32,
+}
+
+impl<Key> Default for BodyState<Key> {
+    fn default() -> Self {
+        Self {
+            range: None,
+            keys: Vec::new(),
+            cursor: None,
+            anchor: None,
+            is_focused: false,
+            modifiers: keyboard::Modifiers::default(),
+            scroll: None,
+            row_height: 0.0,
+        }
+    }
+}
+
+impl<Key> operation::Focusable for BodyState<Key> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Key> operation::Scrollable for BodyState<Key> {
+    fn snap_to(&mut self, _offset: RelativeOffset) {}
+
+    fn scroll_to(&mut self, _offset: AbsoluteOffset) {}
+
+    fn scroll_by(
+        &mut self,
+        _offset: AbsoluteOffset,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+    ) {
+    }
+
+    fn scroll_to_index(&mut self, index: usize) {
+        self.scroll = Some(Scroll::To(index as f32 * self.row_height));
+    }
+}
+
+/// Takes the scroll requested by the [`Body`] of a [`TreeView`].
+fn pending<Key: 'static>(tree: &mut Tree) -> Option<Scroll> {
+    tree.state.downcast_mut::<BodyState<Key>>().scroll.take()
+}
+
+impl<'a, T, Key, Message, Theme, Renderer>
+    Body<'a, T, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + container::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn row(&self, index: usize) -> Element<'a, Message, Theme, Renderer> {
+        let entry = &self.entries[index];
+
+        Container::new((self.view)(entry.item))
+            .width(Length::Fill)
+            .height(self.row_height)
+            .padding(Padding {
+                left: self.toggle_x(entry.depth + 1),
+                ..self.padding
+            })
+            .align_y(alignment::Vertical::Center)
+            .clip(true)
+            .into()
+    }
+
+    /// Returns the horizontal offset of the toggle of the given level.
+    fn toggle_x(&self, depth: usize) -> f32 {
+        self.padding.left + depth as f32 * self.indent
+    }
+
+    fn is_selected(&self, index: usize) -> bool {
+        self.is_selected
+            .as_ref()
+            .is_some_and(|is_selected| is_selected(&self.entries[index].key))
+    }
+
+    fn position(&self, key: &Key) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.key == *key)
+    }
+
+    /// Builds the rows in the given range and reconciles their state with
+    /// the rows built previously, matching them by key.
+    fn build(&mut self, tree: &mut Tree, range: Range<usize>) {
+        let range = range.start.min(self.entries.len())
+            ..range.end.min(self.entries.len());
+
+        self.rows = range.clone().map(|index| self.row(index)).collect();
+        self.range = range.clone();
+
+        let keys: Vec<Key> = self.entries[range.clone()]
+            .iter()
+            .map(|entry| entry.key.clone())
+            .collect();
+
+        let state = tree.state.downcast_mut::<BodyState<Key>>();
+        state.range = Some(range);
+        state.row_height = self.row_height;
+
+        let previous_keys = std::mem::replace(&mut state.keys, keys.clone());
+
+        let rows = &mut tree.children[0];
+        let mut previous: Vec<(Key, Option<Tree>)> = previous_keys
+            .into_iter()
+            .zip(std::mem::take(&mut rows.children).into_iter().map(Some))
+            .collect();
+
+        rows.children = keys
+            .iter()
+            .zip(&mut self.rows)
+            .map(|(key, row)| {
+                let tree = previous
+                    .iter_mut()
+                    .find(|(previous, _)| previous == key)
+                    .and_then(|(_, tree)| tree.take());
+
+                match tree {
+                    Some(mut tree) => {
+                        tree.diff(row.as_widget_mut());
+                        tree
+                    }
+                    None => Tree::new(row.as_widget()),
+                }
+            })
+            .collect();
+    }
+
+    /// Moves the cursor to the given row, selecting it, and scrolls it into
+    /// view.
+    fn navigate(
+        &self,
+        state: &mut BodyState<Key>,
+        index: usize,
+        bounds: Rectangle,
+        viewport: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let key = self.entries[index].key.clone();
+
+        if let Some(on_select) = &self.on_select {
+            let select = if self.selection == Selection::Multiple
+                && state.modifiers.shift()
+            {
+                Select::Range(self.range_to(state.anchor.as_ref(), index))
+            } else {
+                state.anchor = Some(key.clone());
+
+                Select::Only(key.clone())
+            };
+
+            shell.publish(on_select(select));
+        }
+
+        state.cursor = Some(key);
+        state.scroll = reveal(
+            bounds.y + index as f32 * self.row_height,
+            self.row_height,
+            viewport,
+        );
+    }
+
+    /// Returns the keys of the visible items from the anchor to the given
+    /// row.
+    fn range_to(&self, anchor: Option<&Key>, index: usize) -> Vec<Key> {
+        let start = anchor.and_then(|key| self.position(key)).unwrap_or(index);
+
+        self.entries[start.min(index)..=start.max(index)]
+            .iter()
+            .map(|entry| entry.key.clone())
+            .collect()
+    }
+
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) -> bool {
+        let entry = &self.entries[index];
+
+        let Some(on_toggle) = &self.on_toggle else {
+            return false;
+        };
+
+        match entry.expansion {
+            Expansion::Leaf => false,
+            Expansion::Collapsed | Expansion::Expanded => {
+                shell.publish(on_toggle(
+                    entry.key.clone(),
+                    entry.expansion == Expansion::Collapsed,
+                ));
+
+                true
+            }
+        }
+    }
+}
+
+/// Returns the range of rows of the given height that are visible in the
+/// viewport, including some overscan.
+fn visible_rows(
+    bounds: Rectangle,
+    viewport: Rectangle,
+    row_height: f32,
+    total: usize,
+) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let top = ((viewport.y - bounds.y) / row_height).floor().max(0.0) as usize;
+    let bottom = ((viewport.y + viewport.height - bounds.y) / row_height)
+        .ceil()
+        .max(0.0) as usize;
+
+    top.saturating_sub(OVERSCAN).min(total)..(bottom + OVERSCAN).min(total)
+}
+
+/// Returns the scroll needed to bring a row into the viewport, if any.
+fn reveal(y: f32, height: f32, viewport: Rectangle) -> Option<Scroll> {
+    if y < viewport.y {
+        Some(Scroll::By(y - viewport.y))
+    } else if y + height > viewport.y + viewport.height {
+        Some(Scroll::By(y + height - viewport.y - viewport.height))
+    } else {
+        None
+    }
+}
+
+impl<'a, T, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Body<'a, T, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + container::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<BodyState<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(BodyState::<Key>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::empty()]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let range = tree
+            .state
+            .downcast_ref::<BodyState<Key>>()
+            .range
+            .clone()
+            .unwrap_or(0..INITIAL_ROWS);
+
+        self.build(tree, range);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = limits.max().width;
+        let height = self.entries.len() as f32 * self.row_height;
+
+        let row_limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, self.row_height));
+
+        let children = self
+            .range
+            .clone()
+            .zip(&self.rows)
+            .zip(&mut tree.children[0].children)
+            .map(|((index, row), tree)| {
+                row.as_widget()
+                    .layout(tree, renderer, &row_limits)
+                    .move_to(Point::new(0.0, index as f32 * self.row_height))
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(
+                Length::Fill,
+                Length::Shrink,
+                Size::new(width, height),
+            ),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<BodyState<Key>>();
+
+        operation.focusable(state, self.id.as_ref());
+        operation.scrollable(state, None, bounds, bounds, Vector::ZERO);
+
+        operation.container(None, bounds, &mut |operation| {
+            self.rows
+                .iter()
+                .zip(&mut tree.children[0].children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let range = visible_rows(
+                bounds,
+                *viewport,
+                self.row_height,
+                self.entries.len(),
+            );
+
+            if range != self.range {
+                self.build(tree, range);
+                shell.invalidate_layout();
+
+                return event::Status::Ignored;
+            }
+        }
+
+        let status = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children[0].children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let state = tree.state.downcast_mut::<BodyState<Key>>();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.modifiers = modifiers;
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let index = (position.y / self.row_height) as usize;
+
+                let Some(entry) = self.entries.get(index) else {
+                    return event::Status::Ignored;
+                };
+
+                state.is_focused = true;
+
+                let toggle_x = self.toggle_x(entry.depth);
+
+                if (toggle_x..toggle_x + self.indent).contains(&position.x)
+                    && self.toggle(index, shell)
+                {
+                    return event::Status::Captured;
+                }
+
+                let Some(on_select) = &self.on_select else {
+                    return event::Status::Captured;
+                };
+
+                let key = entry.key.clone();
+
+                let select = match self.selection {
+                    Selection::Multiple if state.modifiers.command() => {
+                        state.anchor = Some(key.clone());
+
+                        Select::Toggle(key.clone())
+                    }
+                    Selection::Multiple if state.modifiers.shift() => {
+                        Select::Range(
+                            self.range_to(state.anchor.as_ref(), index),
+                        )
+                    }
+                    _ => {
+                        state.anchor = Some(key.clone());
+
+                        Select::Only(key.clone())
+                    }
+                };
+
+                state.cursor = Some(key);
+                shell.publish(on_select(select));
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if state.is_focused && !self.entries.is_empty() => {
+                let current = state.cursor.as_ref().and_then(|key| {
+                    self.entries.iter().position(|entry| entry.key == *key)
+                });
+                let last = self.entries.len() - 1;
+
+                let target = match (named, current) {
+                    (key::Named::ArrowUp, Some(index)) => {
+                        Some(index.saturating_sub(1))
+                    }
+                    (key::Named::ArrowDown, Some(index)) => {
+                        Some((index + 1).min(last))
+                    }
+                    (key::Named::ArrowUp | key::Named::End, None)
+                    | (key::Named::End, Some(_)) => Some(last),
+                    (key::Named::ArrowDown | key::Named::Home, None)
+                    | (key::Named::Home, Some(_)) => Some(0),
+                    (key::Named::ArrowLeft, Some(index)) => {
+                        let entry = &self.entries[index];
+
+                        if entry.expansion == Expansion::Expanded {
+                            let _ = self.toggle(index, shell);
+
+                            None
+                        } else {
+                            entry.parent
+                        }
+                    }
+                    (key::Named::ArrowRight, Some(index)) => {
+                        match self.entries[index].expansion {
+                            Expansion::Collapsed => {
+                                let _ = self.toggle(index, shell);
+
+                                None
+                            }
+                            Expansion::Expanded
+                                if self.entries.get(index + 1).is_some_and(
+                                    |child| child.parent == Some(index),
+                                ) =>
+                            {
+                                Some(index + 1)
+                            }
+                            _ => None,
+                        }
+                    }
+                    (key::Named::Enter, Some(index)) => {
+                        let _ = self.toggle(index, shell);
+
+                        None
+                    }
+                    (key::Named::Space, Some(index)) => {
+                        if let Some(on_select) = &self.on_select {
+                            let key = self.entries[index].key.clone();
+
+                            shell.publish(on_select(
+                                if self.selection == Selection::Multiple {
+                                    Select::Toggle(key)
+                                } else {
+                                    Select::Only(key)
+                                },
+                            ));
+                        }
+
+                        None
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(index) = target {
+                    self.navigate(state, index, bounds, *viewport, shell);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .rows
+            .iter()
+            .zip(&tree.children[0].children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        let Some(position) = cursor.position_in(layout.bounds()) else {
+            return interaction;
+        };
+
+        let is_over_toggle = self
+            .entries
+            .get((position.y / self.row_height) as usize)
+            .is_some_and(|entry| {
+                let toggle_x = self.toggle_x(entry.depth);
+
+                entry.expansion != Expansion::Leaf
+                    && self.on_toggle.is_some()
+                    && (toggle_x..toggle_x + self.indent).contains(&position.x)
+            });
+
+        if is_over_toggle || self.on_select.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<BodyState<Key>>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+
+        let hovered_row = cursor
+            .position_in(bounds)
+            .filter(|_| self.on_select.is_some())
+            .map(|position| (position.y / self.row_height) as usize);
+
+        for ((index, (row, row_state)), layout) in self
+            .range
+            .clone()
+            .zip(self.rows.iter().zip(&tree.children[0].children))
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            let entry = &self.entries[index];
+            let row_bounds = layout.bounds();
+            let is_selected = self.is_selected(index);
+
+            let background = if is_selected {
+                Some(style.selected_row_background.clone())
+            } else if hovered_row == Some(index) {
+                style.hovered_row_background.clone()
+            } else {
+                style.row_background.clone()
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            for depth in 0..entry.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (row_bounds.x
+                                + self.toggle_x(depth)
+                                + self.indent / 2.0)
+                                .round(),
+                            y: row_bounds.y,
+                            width: 1.0,
+                            height: row_bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.guide_color,
+                );
+            }
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                renderer_style.text_color
+            };
+
+            if entry.expansion != Expansion::Leaf {
+                let size = renderer.default_size() * 0.7;
+                let line_height = text::LineHeight::default();
+
+                renderer.fill_text(
+                    Text {
+                        content: match entry.expansion {
+                            Expansion::Expanded => String::from("▼"),
+                            _ => String::from("▶"),
+                        },
+                        size,
+                        line_height,
+                        font: renderer.default_font(),
+                        bounds: Size::new(
+                            self.indent,
+                            f32::from(line_height.to_absolute(size)),
+                        ),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                    },
+                    Point::new(
+                        row_bounds.x
+                            + self.toggle_x(entry.depth)
+                            + self.indent / 2.0,
+                        row_bounds.center_y(),
+                    ),
+                    style.toggle_color.unwrap_or(text_color),
+                    row_bounds,
+                );
+            }
+
+            row.as_widget().draw(
+                row_state,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color,
+                    ..*renderer_style
+                },
+                layout,
+                cursor,
+                viewport,
+            );
+
+            if state.is_focused && state.cursor.as_ref() == Some(&entry.key) {
+                if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                    focus_ring::draw(
+                        renderer,
+                        row_bounds.shrink(focus_ring.offset + focus_ring.width),
+                        0.0.into(),
+                        focus_ring,
+                    );
+                }
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.rows,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.rows
+                .iter()
+                .zip(layout.children())
+                .zip(state.children[0].children.iter())
+                .map(|((row, layout), state)| {
+                    row.as_widget().a11y_nodes(layout, state, cursor)
+                }),
+        )
+    }
+}
+
+/// The appearance of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the rows, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of a hovered row, if any.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of a selected row.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the expand and collapse toggles, if different from
+    /// the text of their row.
+    pub toggle_color: Option<Color>,
+    /// The [`Color`] of the indentation guides.
+    pub guide_color: Color,
+}
+
+/// The theme catalog of a [`TreeView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+
+    /// The [`focus_ring::Style`] of the row under the keyboard cursor of a
+    /// focused [`TreeView`] of the given class, if any.
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        None
+    }
+}
+
+/// A styling function for a [`TreeView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+
+    fn focus_ring(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<focus_ring::Style> {
+        Some(focus_ring::default(self))
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        row_background: None,
+        hovered_row_background: Some(
+            palette.primary.weak.color.scale_alpha(0.3).into(),
+        ),
+        selected_row_background: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+        toggle_color: Some(palette.background.strong.text),
+        guide_color: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node {
+        name: &'static str,
+        children: Option<Vec<Node>>,
+    }
+
+    fn node(name: &'static str, children: Option<Vec<Node>>) -> Node {
+        Node { name, children }
+    }
+
+    #[test]
+    fn only_expanded_items_are_flattened() {
+        let roots = vec![
+            node(
+                "src",
+                Some(vec![
+                    node("lib.rs", None),
+                    node("widget", Some(vec![node("tree_view.rs", None)])),
+                ]),
+            ),
+            node("target", Some(vec![node("debug", None)])),
+            node("docs", Some(Vec::new())),
+        ];
+
+        fn key(node: &Node) -> &'static str {
+            node.name
+        }
+
+        fn children(node: &Node) -> Children<'_, Node> {
+            match &node.children {
+                Some(children) => Children::Loaded(children),
+                None if node.name.ends_with(".rs") => Children::Leaf,
+                None => Children::Unloaded,
+            }
+        }
+
+        let is_expanded = |name: &&str| matches!(*name, "src" | "docs");
+
+        let mut entries = Vec::new();
+
+        flatten(
+            &roots,
+            0,
+            None,
+            &key,
+            Some(&children),
+            Some(&is_expanded),
+            &mut entries,
+        );
+
+        let flattened: Vec<_> = entries
+            .iter()
+            .map(|entry| {
+                (entry.key, entry.depth, entry.parent, entry.expansion)
+            })
+            .collect();
+
+        assert_eq!(
+            flattened,
+            [
+                ("src", 0, None, Expansion::Expanded),
+                ("lib.rs", 1, Some(0), Expansion::Leaf),
+                ("widget", 1, Some(0), Expansion::Collapsed),
+                ("target", 0, None, Expansion::Collapsed),
+                ("docs", 0, None, Expansion::Expanded),
+            ]
+        );
+
+        let viewport =
+            Rectangle::new(Point::new(0.0, 100.0), Size::new(200.0, 100.0));
+
+        assert_eq!(reveal(120.0, 28.0, viewport), None);
+        assert_eq!(reveal(80.0, 28.0, viewport), Some(Scroll::By(-20.0)));
+        assert_eq!(reveal(190.0, 28.0, viewport), Some(Scroll::By(18.0)));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggle(&'static str, bool),
+        Select(Select<&'static str>),
+    }
+
+    /// Returns an expanded `src` with a file and an unloaded `widget`
+    /// directory, followed by an unloaded `docs` directory.
+    fn roots() -> Vec<Node> {
+        vec![
+            node(
+                "src",
+                Some(vec![node("lib.rs", None), node("widget", None)]),
+            ),
+            node("docs", None),
+        ]
+    }
+
+    fn tree_view(
+        roots: &[Node],
+        selection: Selection,
+    ) -> Element<'_, Message, Theme, ()> {
+        TreeView::new(roots, |node| node.name, |node| crate::text(node.name))
+            .children(|node| match &node.children {
+                Some(children) => Children::Loaded(children),
+                None if node.name.ends_with(".rs") => Children::Leaf,
+                None => Children::Unloaded,
+            })
+            .expanded(|name| *name == "src")
+            .on_toggle(Message::Toggle)
+            .on_select(Message::Select)
+            .selection(selection)
+            .id(Id::new("tree_view"))
+            .into()
+    }
+
+    /// Lays out the given tree view, focuses it and sends it the given
+    /// events, with the cursor at the given positions.
+    fn run(
+        mut element: Element<'_, Message, Theme, ()>,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> Vec<Message> {
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        element.as_widget().operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::focusable::focus(Id::new("tree_view")),
+        );
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        messages
+    }
+
+    fn press(key: key::Named) -> (Event, Point) {
+        let key = keyboard::Key::Named(key);
+
+        (
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+            }),
+            Point::ORIGIN,
+        )
+    }
+
+    /// Clicks the row at the given index, at the given horizontal position.
+    fn click(row: usize, x: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, (row as f32 + 0.5) * 28.0),
+        )
+    }
+
+    fn modifiers(modifiers: keyboard::Modifiers) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+            Point::ORIGIN,
+        )
+    }
+
+    #[test]
+    fn arrow_keys_navigate_and_toggle_items() {
+        let roots = roots();
+
+        let messages = run(
+            tree_view(&roots, Selection::Single),
+            [
+                press(key::Named::ArrowDown),
+                press(key::Named::ArrowDown),
+                press(key::Named::ArrowDown),
+                press(key::Named::ArrowRight),
+                press(key::Named::ArrowLeft),
+                press(key::Named::ArrowLeft),
+                press(key::Named::End),
+                press(key::Named::Enter),
+                press(key::Named::Home),
+            ],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Select(Select::Only("src")),
+                Message::Select(Select::Only("lib.rs")),
+                Message::Select(Select::Only("widget")),
+                Message::Toggle("widget", true),
+                Message::Select(Select::Only("src")),
+                Message::Toggle("src", false),
+                Message::Select(Select::Only("docs")),
+                Message::Toggle("docs", true),
+                Message::Select(Select::Only("src")),
+            ]
+        );
+    }
+
+    #[test]
+    fn clicks_select_single_items() {
+        let roots = roots();
+
+        let messages = run(
+            tree_view(&roots, Selection::Single),
+            [
+                click(1, 100.0),
+                modifiers(keyboard::Modifiers::COMMAND),
+                click(3, 100.0),
+                modifiers(keyboard::Modifiers::SHIFT),
+                click(0, 100.0),
+            ],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Select(Select::Only("lib.rs")),
+                Message::Select(Select::Only("docs")),
+                Message::Select(Select::Only("src")),
+            ]
+        );
+    }
+
+    #[test]
+    fn clicks_with_modifiers_select_multiple_items() {
+        let roots = roots();
+
+        let messages = run(
+            tree_view(&roots, Selection::Multiple),
+            [
+                click(1, 100.0),
+                modifiers(keyboard::Modifiers::COMMAND),
+                click(3, 100.0),
+                modifiers(keyboard::Modifiers::SHIFT),
+                click(0, 100.0),
+                press(key::Named::ArrowDown),
+            ],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Select(Select::Only("lib.rs")),
+                Message::Select(Select::Toggle("docs")),
+                Message::Select(Select::Range(vec![
+                    "src", "lib.rs", "widget", "docs"
+                ])),
+                Message::Select(Select::Range(vec![
+                    "lib.rs", "widget", "docs"
+                ])),
+            ]
+        );
+    }
+
+    #[test]
+    fn clicking_toggles_of_unloaded_items_expands_them() {
+        let roots = roots();
+
+        // The toggle of `widget` is indented one level, after the padding
+        let toggle = 4.0 + 18.0 * 1.5;
+
+        let messages = run(
+            tree_view(&roots, Selection::Single),
+            [click(2, toggle), click(0, 10.0), click(3, 10.0)],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Toggle("widget", true),
+                Message::Toggle("src", false),
+                Message::Toggle("docs", true),
+            ]
+        );
+    }
+}
@@ -43,11 +43,17 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     self, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
 };
+use crate::focus_ring;
+
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
 
 /// An vertical bar and a handle that selects a single value from a range of
 /// values.
@@ -90,6 +96,13 @@ pub struct VerticalSlider<'a, T, Message, Theme = crate::Theme>
 where
     Theme: Catalog,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     range: RangeInclusive<T>,
     step: T,
     shift_step: Option<T>,
@@ -136,6 +149,13 @@ where
         };
 
         VerticalSlider {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             value,
             default: None,
             range,
@@ -211,6 +231,41 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`VerticalSlider`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`VerticalSlider`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`VerticalSlider`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`VerticalSlider`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -245,6 +300,18 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, Some(&self.id));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -353,6 +420,8 @@ where
 
                     return event::Status::Captured;
                 }
+
+                state.is_focused = false;
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
@@ -393,6 +462,26 @@ where
                 }
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if state.is_focused {
+                    let new_value = match key {
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => increment(current_value),
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => decrement(current_value),
+                        Key::Named(key::Named::Home) => {
+                            Some(*self.range.start())
+                        }
+                        Key::Named(key::Named::End) => Some(*self.range.end()),
+                        _ => return event::Status::Ignored,
+                    };
+
+                    let _ = new_value.map(change);
+
+                    return event::Status::Captured;
+                }
+
                 if cursor.is_over(layout.bounds()) {
                     match key {
                         Key::Named(key::Named::ArrowUp) => {
@@ -410,6 +499,28 @@ where
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = modifiers;
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest { action, .. },
+            ) if event_id == self.id => {
+                use iced_accessibility::accesskit::Action;
+
+                let new_value = match action {
+                    Action::Increment => increment(current_value),
+                    Action::Decrement => decrement(current_value),
+                    Action::Focus => {
+                        state.is_focused = true;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                let _ = new_value.map(change);
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -497,14 +608,16 @@ where
             style.rail.backgrounds.0,
         );
 
+        let handle_bounds = Rectangle {
+            x: rail_x - handle_height / 2.0,
+            y: bounds.y + offset,
+            width: handle_height,
+            height: handle_width,
+        };
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
-                    x: rail_x - handle_height / 2.0,
-                    y: bounds.y + offset,
-                    width: handle_height,
-                    height: handle_width,
-                },
+                bounds: handle_bounds,
                 border: Border {
                     radius: handle_border_radius,
                     width: style.handle.border_width,
@@ -514,6 +627,17 @@ where
             },
             style.handle.background,
         );
+
+        if state.is_focused {
+            if let Some(focus_ring) = theme.focus_ring(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    handle_bounds,
+                    handle_border_radius,
+                    focus_ring,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -536,6 +660,83 @@ where
             mouse::Interaction::default()
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, Live, NodeBuilder, NodeId, Orientation, Rect, Role,
+            },
+            A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::Slider);
+        node.set_bounds(bounds);
+        node.set_orientation(Orientation::Vertical);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter()
+                        .cloned()
+                        .map(|id| NodeId::from(id))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        node.set_min_numeric_value((*self.range.start()).into());
+        node.set_max_numeric_value((*self.range.end()).into());
+        node.set_numeric_value(self.value.into());
+        node.set_numeric_value_step(self.step.into());
+        node.set_live(Live::Polite);
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer>
@@ -557,5 +758,20 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
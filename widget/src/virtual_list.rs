@@ -0,0 +1,909 @@
+//! Display very large collections of items, building only the visible ones.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{text, virtual_list};
+//!
+//! struct Line {
+//!     id: u64,
+//!     message: String,
+//! }
+//!
+//! struct State {
+//!     lines: Vec<Line>,
+//! }
+//!
+//! enum Message {}
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     virtual_list(state.lines.len(), |index| {
+//!         text(&state.lines[index].message).into()
+//!     })
+//!     .key(|index| state.lines[index].id)
+//!     .estimated_height(20)
+//!     .into()
+//! }
+//! ```
+use crate::anchored::{Anchored, Scroll};
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+use crate::scrollable::{self, Scrollable};
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// A vertical list that builds only the items visible inside its
+/// [`Scrollable`].
+///
+/// Items are produced on demand by a closure, so a [`VirtualList`] stays
+/// responsive with hundreds of thousands of items. Their heights may vary:
+/// items that have not been laid out yet are assumed to have the average
+/// height of the ones that have, and the scroll position is corrected as
+/// their actual heights become known.
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: scrollable::Catalog,
+    Renderer: crate::core::Renderer,
+{
+    len: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    id: Option<Id>,
+    width: Length,
+    height: Length,
+    estimated_height: f32,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Theme: scrollable::Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// The default estimated height of the items of a [`VirtualList`].
+    pub const DEFAULT_ESTIMATED_HEIGHT: f32 = 32.0;
+
+    /// Creates a new [`VirtualList`] with the given amount of items, built
+    /// by the given closure from their index.
+    pub fn new(
+        len: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            len,
+            view: Box::new(view),
+            key: None,
+            id: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            estimated_height: Self::DEFAULT_ESTIMATED_HEIGHT,
+            spacing: 0.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the closure that identifies the item at a given index.
+    ///
+    /// Keys let the [`VirtualList`] follow items when they move: their state
+    /// and measured height are kept, and the scroll position stays stable
+    /// when items are inserted or removed above the visible ones.
+    ///
+    /// Without keys, items are identified by their index.
+    pub fn key<K>(mut self, key: impl Fn(usize) -> K + 'a) -> Self
+    where
+        K: Hash,
+    {
+        self.key = Some(Box::new(move |index| {
+            let mut hasher = DefaultHasher::new();
+            key(index).hash(&mut hasher);
+            hasher.finish()
+        }));
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Scrollable`] containing the items of the
+    /// [`VirtualList`].
+    ///
+    /// This lets you scroll the [`VirtualList`] with the [`Task`] functions
+    /// in the [`scrollable`] module, including
+    /// [`scroll_to_index`](scrollable::scroll_to_index).
+    ///
+    /// [`Task`]: crate::runtime::Task
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height assumed for items that have not been laid out yet.
+    ///
+    /// The closer it is to the actual heights, the less the scroll position
+    /// needs to be corrected.
+    pub fn estimated_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.estimated_height = height.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing between the items of the [`VirtualList`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Scrollable`] of the [`VirtualList`].
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<scrollable::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as scrollable::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Scrollable`] of the [`VirtualList`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: scrollable::Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        let body = Body {
+            len: list.len,
+            view: list.view,
+            key: list.key,
+            estimated_height: list.estimated_height,
+            spacing: list.spacing,
+            range: 0..0,
+            items: Vec::new(),
+        };
+
+        let mut scrollable = Scrollable::new(Element::new(body))
+            .width(list.width)
+            .height(list.height)
+            .class(list.class);
+
+        if let Some(id) = list.id {
+            scrollable = scrollable.id(id);
+        }
+
+        Anchored::new(scrollable, correction, requested_scroll).into()
+    }
+}
+
+/// The number of items built when the visible items of a [`VirtualList`]
+/// are not known yet.
+const INITIAL_ITEMS: usize = 50;
+
+/// The number of items built above and below the visible ones, so fast
+/// scrolling does not reveal empty space.
+const OVERSCAN: usize = 5;
+
+/// Takes the scroll correction of the [`Body`] of a [`VirtualList`].
+fn correction(tree: &mut Tree) -> Option<Scroll> {
+    let state = tree.state.downcast_mut::<BodyState>();
+    let correction = std::mem::take(&mut state.correction);
+
+    (correction != 0.0).then_some(Scroll::By(correction))
+}
+
+/// Takes the scroll to an item requested from the [`Body`] of a
+/// [`VirtualList`].
+fn requested_scroll(tree: &mut Tree) -> Option<Scroll> {
+    let state = tree.state.downcast_mut::<BodyState>();
+
+    state
+        .scroll_to
+        .take()
+        .filter(|index| *index <= state.len)
+        .map(|index| state.offset(index))
+        .map(Scroll::To)
+}
+
+struct Body<'a, Message, Theme, Renderer> {
+    len: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    estimated_height: f32,
+    spacing: f32,
+    range: Range<usize>,
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The local state of the items of a [`VirtualList`].
+///
+/// The children of its [`Tree`] hold a single [`Tree`] whose children are
+/// the states of the items currently built, in the stored range.
+///
+/// Offsets are computed on demand from the measured heights, so a layout
+/// only costs as much as the items built.
+#[derive(Debug, Clone, Default)]
+struct BodyState {
+    range: Option<Range<usize>>,
+    /// The keys of the items currently built.
+    keys: Vec<u64>,
+    /// The measured heights of the items laid out so far, sorted by index.
+    heights: Vec<(usize, f32)>,
+    /// The sum of the measured heights preceding each of them, followed by
+    /// their total.
+    sums: Vec<f32>,
+    /// The amount of items.
+    len: usize,
+    /// The spacing between the items.
+    spacing: f32,
+    /// The height of the items when none has been measured yet.
+    fallback: f32,
+    /// The first visible item, which must not move when the offsets change.
+    anchor: Option<Anchor>,
+    /// The distance the anchor moved during the last layout, which has yet
+    /// to be scrolled.
+    correction: f32,
+    /// The item that was requested to be scrolled to.
+    scroll_to: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Anchor {
+    key: u64,
+    index: usize,
+    offset: f32,
+}
+
+impl Anchor {
+    /// Finds the current index of the anchored item, searching outwards
+    /// from its last known index.
+    fn find(&self, len: usize, key: impl Fn(usize) -> u64) -> Option<usize> {
+        for distance in 0..=self.index.max(len) {
+            let after = self.index + distance;
+
+            if after < len && key(after) == self.key {
+                return Some(after);
+            }
+
+            if distance > 0 && distance <= self.index {
+                let before = self.index - distance;
+
+                if before < len && key(before) == self.key {
+                    return Some(before);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl BodyState {
+    /// Returns the height of the items that have not been measured yet.
+    fn estimate(&self) -> f32 {
+        match self.sums.last() {
+            Some(total) if !self.heights.is_empty() => {
+                total / self.heights.len() as f32
+            }
+            _ => self.fallback,
+        }
+    }
+
+    /// Returns the vertical offset of the item at the given index, or the
+    /// total height of the items including their trailing spacing if the
+    /// index is the amount of items.
+    fn offset(&self, index: usize) -> f32 {
+        let estimate = self.estimate();
+        let measured = self.heights.partition_point(|(i, _)| *i < index);
+        let sum = self.sums.get(measured).copied().unwrap_or_default();
+
+        index as f32 * (estimate + self.spacing) + sum
+            - measured as f32 * estimate
+    }
+
+    /// Stores the measured heights of the items starting at the given index.
+    fn measure(&mut self, start: usize, heights: impl Iterator<Item = f32>) {
+        let heights: Vec<(usize, f32)> =
+            heights.enumerate().map(|(i, h)| (start + i, h)).collect();
+
+        let end = start + heights.len();
+        let from = self.heights.partition_point(|(i, _)| *i < start);
+        let to = self.heights.partition_point(|(i, _)| *i < end);
+
+        if self.heights[from..to] != heights[..] {
+            let _ = self.heights.splice(from..to, heights);
+            self.sum_from(from);
+        }
+    }
+
+    /// Moves the measured heights along with the items they belong to,
+    /// dropping the ones of items that no longer exist.
+    fn shift(&mut self, from: usize, to: usize, len: usize) {
+        if from == to {
+            let kept = self.heights.partition_point(|(i, _)| *i < len);
+
+            self.heights.truncate(kept);
+            self.sums.truncate(kept + 1);
+
+            return;
+        }
+
+        let heights = std::mem::take(&mut self.heights);
+
+        self.heights = heights
+            .into_iter()
+            .filter_map(|(index, height)| {
+                let index = (index + to).checked_sub(from)?;
+
+                (index < len).then_some((index, height))
+            })
+            .collect();
+
+        self.sum_from(0);
+    }
+
+    /// Recomputes the sums of the measured heights starting at the given
+    /// position.
+    fn sum_from(&mut self, position: usize) {
+        self.sums.truncate(position + 1);
+
+        if self.sums.is_empty() {
+            self.sums.push(0.0);
+        }
+
+        let mut sum = self.sums[position];
+
+        for (_, height) in &self.heights[position..] {
+            sum += height;
+            self.sums.push(sum);
+        }
+    }
+
+    /// Follows the anchored item to its current index, moving the measured
+    /// heights along with it.
+    ///
+    /// Returns the previous and the current index of the anchored item, if
+    /// it still exists.
+    fn follow(
+        &mut self,
+        len: usize,
+        key: impl Fn(usize) -> u64,
+    ) -> Option<(usize, usize)> {
+        let Some(anchor) = &mut self.anchor else {
+            self.shift(0, 0, len);
+            return None;
+        };
+
+        match anchor.find(len, key) {
+            Some(index) => {
+                let previous = std::mem::replace(&mut anchor.index, index);
+
+                self.shift(previous, index, len);
+
+                Some((previous, index))
+            }
+            None => {
+                self.anchor = None;
+                self.shift(0, 0, len);
+
+                None
+            }
+        }
+    }
+
+    /// Updates the layout parameters of the items and computes how much the
+    /// anchor moved since it was set.
+    fn update(&mut self, len: usize, spacing: f32, fallback: f32) {
+        self.len = len;
+        self.spacing = spacing;
+        self.fallback = fallback;
+
+        let Some(anchor) = self.anchor else {
+            return;
+        };
+
+        if anchor.index < len {
+            let offset = self.offset(anchor.index);
+
+            self.correction += offset - anchor.offset;
+            self.anchor = Some(Anchor { offset, ..anchor });
+        } else {
+            self.anchor = None;
+        }
+    }
+
+    /// Returns the total height of the items.
+    fn height(&self) -> f32 {
+        if self.len == 0 {
+            0.0
+        } else {
+            self.offset(self.len) - self.spacing
+        }
+    }
+
+    /// Returns the amount of offsets, including the total height, that
+    /// satisfy the given predicate, which must hold for a prefix of them.
+    fn partition_point(&self, predicate: impl Fn(f32) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.len + 1);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if predicate(self.offset(middle)) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    /// Returns the index of the first visible item and the range of items
+    /// that are visible in the viewport, including some overscan.
+    fn visible(
+        &self,
+        bounds: Rectangle,
+        viewport: Rectangle,
+    ) -> Option<(usize, Range<usize>)> {
+        let len = self.len;
+
+        if len == 0 {
+            return None;
+        }
+
+        let top = viewport.y - bounds.y;
+        let bottom = top + viewport.height;
+
+        let first = self
+            .partition_point(|offset| offset <= top)
+            .saturating_sub(1)
+            .min(len - 1);
+
+        let last = self.partition_point(|offset| offset < bottom);
+
+        Some((
+            first,
+            first.saturating_sub(OVERSCAN)..(last + OVERSCAN).min(len),
+        ))
+    }
+}
+
+impl operation::Scrollable for BodyState {
+    fn snap_to(&mut self, _offset: operation::scrollable::RelativeOffset) {}
+
+    fn scroll_to(&mut self, _offset: AbsoluteOffset) {}
+
+    fn scroll_by(
+        &mut self,
+        _offset: AbsoluteOffset,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+    ) {
+    }
+
+    fn scroll_to_index(&mut self, index: usize) {
+        self.scroll_to = Some(index);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Body<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn key(&self, index: usize) -> u64 {
+        match &self.key {
+            Some(key) => key(index),
+            None => index as u64,
+        }
+    }
+
+    /// Builds the items in the given range and reconciles their state with
+    /// the items built previously, matching them by key.
+    fn build(&mut self, tree: &mut Tree, range: Range<usize>) {
+        let range = range.start.min(self.len)..range.end.min(self.len);
+
+        self.items = range.clone().map(|index| (self.view)(index)).collect();
+        self.range = range.clone();
+
+        let keys: Vec<u64> =
+            range.clone().map(|index| self.key(index)).collect();
+
+        let state = tree.state.downcast_mut::<BodyState>();
+        state.range = Some(range);
+        let previous_keys = std::mem::replace(&mut state.keys, keys.clone());
+
+        let items = &mut tree.children[0];
+        let mut previous: HashMap<u64, Tree> = previous_keys
+            .into_iter()
+            .zip(std::mem::take(&mut items.children))
+            .collect();
+
+        items.children = keys
+            .iter()
+            .zip(&mut self.items)
+            .map(|(key, item)| match previous.remove(key) {
+                Some(mut tree) => {
+                    tree.diff(item.as_widget_mut());
+                    tree
+                }
+                None => Tree::new(item.as_widget()),
+            })
+            .collect();
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Body<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<BodyState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(BodyState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::empty()]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<BodyState>();
+
+        // Follow the anchored item if it moved
+        let moved = state.follow(self.len, |index| self.key(index));
+
+        let range = match (state.range.clone(), moved) {
+            (Some(range), Some((previous, index))) => {
+                let start = (range.start + index).saturating_sub(previous);
+
+                start..start + range.len()
+            }
+            (Some(range), None) => range,
+            (None, _) => 0..INITIAL_ITEMS,
+        };
+
+        self.build(tree, range);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = limits.max().width;
+        let item_limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+
+        let mut children: Vec<layout::Node> = self
+            .items
+            .iter()
+            .zip(&mut tree.children[0].children)
+            .map(|(item, tree)| {
+                item.as_widget().layout(tree, renderer, &item_limits)
+            })
+            .collect();
+
+        let state = tree.state.downcast_mut::<BodyState>();
+
+        state.measure(
+            self.range.start,
+            children.iter().map(|node| node.size().height),
+        );
+        state.update(self.len, self.spacing, self.estimated_height);
+
+        for (index, node) in self.range.clone().zip(&mut children) {
+            node.move_to_mut(Point::new(0.0, state.offset(index)));
+        }
+
+        layout::Node::with_children(
+            limits.resolve(
+                Length::Fill,
+                Length::Shrink,
+                Size::new(width, state.height()),
+            ),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let bounds = layout.bounds();
+
+        operation.scrollable(
+            tree.state.downcast_mut::<BodyState>(),
+            None,
+            bounds,
+            bounds,
+            Vector::ZERO,
+        );
+
+        operation.container(None, bounds, &mut |operation| {
+            self.items
+                .iter()
+                .zip(&mut tree.children[0].children)
+                .zip(layout.children())
+                .for_each(|((item, state), layout)| {
+                    item.as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<BodyState>();
+            let visible = state.visible(layout.bounds(), *viewport);
+
+            state.anchor = visible.as_ref().map(|(first, _)| Anchor {
+                key: self.key(*first),
+                index: *first,
+                offset: state.offset(*first),
+            });
+
+            let range = visible.map(|(_, range)| range).unwrap_or(0..0);
+
+            if range != self.range {
+                self.build(tree, range);
+                shell.invalidate_layout();
+
+                return event::Status::Ignored;
+            }
+        }
+
+        self.items
+            .iter_mut()
+            .zip(&mut tree.children[0].children)
+            .zip(layout.children())
+            .map(|((item, state), layout)| {
+                item.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.items
+            .iter()
+            .zip(&tree.children[0].children)
+            .zip(layout.children())
+            .map(|((item, state), layout)| {
+                item.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((item, state), layout) in self
+            .items
+            .iter()
+            .zip(&tree.children[0].children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            item.as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.items,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.items
+                .iter()
+                .zip(layout.children())
+                .zip(state.children[0].children.iter())
+                .map(|((item, layout), state)| {
+                    item.as_widget().a11y_nodes(layout, state, cursor)
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anchor_stays_put_when_items_are_inserted_above() {
+        let mut keys: Vec<u64> = (0..100).collect();
+        let mut state = BodyState::default();
+
+        state.measure(10, [40.0; 10].into_iter());
+        state.update(keys.len(), 0.0, 20.0);
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(300.0, 4000.0));
+        let viewport =
+            Rectangle::new(Point::new(0.0, 400.0), Size::new(300.0, 200.0));
+
+        let (first, range) = state.visible(bounds, viewport).unwrap();
+
+        assert_eq!(first, 10);
+        assert_eq!(range, 10 - OVERSCAN..15 + OVERSCAN);
+
+        state.anchor = Some(Anchor {
+            key: keys[first],
+            index: first,
+            offset: state.offset(first),
+        });
+
+        keys.splice(0..0, [1000, 1001, 1002]);
+
+        assert_eq!(
+            state.follow(keys.len(), |index| keys[index]),
+            Some((10, 13))
+        );
+
+        state.update(keys.len(), 0.0, 20.0);
+
+        assert_eq!(state.anchor.map(|anchor| anchor.index), Some(13));
+        assert_eq!(state.correction, 3.0 * 40.0);
+        assert_eq!(state.heights.first(), Some(&(13, 40.0)));
+    }
+
+    #[test]
+    fn unmeasured_items_have_the_average_height() {
+        let mut state = BodyState::default();
+
+        state.update(100, 5.0, 20.0);
+
+        assert_eq!(state.offset(10), 10.0 * 25.0);
+        assert_eq!(state.height(), 100.0 * 25.0 - 5.0);
+
+        state.measure(2, [10.0, 30.0].into_iter());
+
+        assert_eq!(state.offset(2), 2.0 * 25.0);
+        assert_eq!(state.offset(3), 2.0 * 25.0 + 15.0);
+        assert_eq!(state.offset(4), 4.0 * 25.0);
+    }
+
+    #[test]
+    fn heights_of_removed_items_are_pruned() {
+        let mut keys: Vec<u64> = (0..100).collect();
+        let mut state = BodyState::default();
+
+        state.measure(0, [20.0; 100].into_iter());
+        state.update(keys.len(), 0.0, 20.0);
+
+        state.anchor = Some(Anchor {
+            key: keys[50],
+            index: 50,
+            offset: state.offset(50),
+        });
+
+        // Items are removed above and below the anchored one
+        keys.drain(90..);
+        keys.drain(..30);
+
+        assert_eq!(
+            state.follow(keys.len(), |index| keys[index]),
+            Some((50, 20))
+        );
+        assert_eq!(state.heights.len(), keys.len());
+        assert_eq!(state.sums.len(), keys.len() + 1);
+
+        // The anchored item is removed too
+        keys.clear();
+
+        assert_eq!(state.follow(keys.len(), |index| keys[index]), None);
+        assert!(state.heights.is_empty());
+        assert_eq!(state.anchor, None);
+    }
+}
@@ -6,6 +6,7 @@ use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
 
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
@@ -299,6 +300,7 @@ pub fn window_event(
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(touch, scale_factor)))
         }
+        WindowEvent::Ime(ime) => Some(Event::InputMethod(self::ime(ime))),
         WindowEvent::Moved(position) => {
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
@@ -336,6 +338,31 @@ pub fn window_level(level: window::Level) -> winit::window::WindowLevel {
     }
 }
 
+/// Converts a [`window::PointerGrab`] to a [`winit`] cursor grab mode, along
+/// with the mode to fall back to on platforms that do not support it.
+///
+/// The region of a confinement is ignored, since [`winit`] can only confine
+/// the cursor to the whole window.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn cursor_grab(
+    grab: window::PointerGrab,
+) -> (winit::window::CursorGrabMode, winit::window::CursorGrabMode) {
+    use winit::window::CursorGrabMode;
+
+    match grab {
+        window::PointerGrab::None => {
+            (CursorGrabMode::None, CursorGrabMode::None)
+        }
+        window::PointerGrab::Confined(_) => {
+            (CursorGrabMode::Confined, CursorGrabMode::Locked)
+        }
+        window::PointerGrab::Locked => {
+            (CursorGrabMode::Locked, CursorGrabMode::Confined)
+        }
+    }
+}
+
 /// Converts a [`window::Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -1144,6 +1171,36 @@ pub fn native_key_code(
     }
 }
 
+/// Converts some [`winit`] IME event into an [`input_method::Event`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime(ime: winit::event::Ime) -> input_method::Event {
+    match ime {
+        winit::event::Ime::Enabled => input_method::Event::Opened,
+        winit::event::Ime::Preedit(content, selection) => {
+            input_method::Event::Preedit(
+                content,
+                selection.map(|(start, end)| start..end),
+            )
+        }
+        winit::event::Ime::Commit(content) => {
+            input_method::Event::Commit(content)
+        }
+        winit::event::Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Converts some [`input_method::Purpose`] into its `winit` counterpart.
+pub fn ime_purpose(
+    purpose: input_method::Purpose,
+) -> winit::window::ImePurpose {
+    match purpose {
+        input_method::Purpose::Normal => winit::window::ImePurpose::Normal,
+        input_method::Purpose::Secure => winit::window::ImePurpose::Password,
+        input_method::Purpose::Terminal => winit::window::ImePurpose::Terminal,
+    }
+}
+
 /// Converts some [`UserAttention`] into it's `winit` counterpart.
 ///
 /// [`UserAttention`]: window::UserAttention
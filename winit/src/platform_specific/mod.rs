@@ -60,6 +60,70 @@ impl PlatformSpecific {
         }
     }
 
+    /// Constrains the pointer of the given window, if it is a surface of the
+    /// platform rather than a winit window.
+    ///
+    /// Returns whether the grab was handled.
+    pub(crate) fn grab_pointer(
+        &mut self,
+        id: window::Id,
+        grab: window::PointerGrab,
+    ) -> bool {
+        #[cfg(all(feature = "wayland", target_os = "linux"))]
+        {
+            if self.wayland.is_sctk_surface(id) {
+                self.send_wayland(wayland::Action::GrabPointer(id, grab));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Sets or clears the idle inhibitor of the given window.
+    ///
+    /// Returns whether the platform supports idle inhibit.
+    pub(crate) fn inhibit_idle(
+        &mut self,
+        id: window::Id,
+        inhibit: bool,
+    ) -> bool {
+        #[cfg(all(feature = "wayland", target_os = "linux"))]
+        {
+            if self.wayland.conn().is_some() {
+                self.send_wayland(wayland::Action::InhibitIdle(id, inhibit));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Whether the decorations of new windows are only known once they are
+    /// negotiated with the window system.
+    ///
+    /// On Wayland, the compositor decides whether it draws the decorations
+    /// of a toplevel after it is tracked.
+    pub(crate) fn negotiates_decorations(&self) -> bool {
+        #[cfg(all(feature = "wayland", target_os = "linux"))]
+        {
+            if self.wayland.conn().is_some() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Whether the given window receives its raw mouse motion from the
+    /// platform instead of winit device events.
+    pub(crate) fn has_raw_motion(&self, id: window::Id) -> bool {
+        #[cfg(all(feature = "wayland", target_os = "linux"))]
+        {
+            if self.wayland.is_sctk_surface(id) {
+                return true;
+            }
+        }
+        false
+    }
+
     pub(crate) fn clear_subsurface_list(&mut self) {
         #[cfg(all(feature = "wayland", target_os = "linux"))]
         {
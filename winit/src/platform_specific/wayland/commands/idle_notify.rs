@@ -0,0 +1,27 @@
+use iced_futures::{
+    core::event::{wayland, PlatformSpecific},
+    event, Subscription,
+};
+use iced_runtime::{platform_specific, task, Action, Task};
+use std::time::Duration;
+
+/// Request idle notification events after the user has been inactive for
+/// the given timeout, or stop them with `None`.
+pub fn idle_notify<Message>(timeout: Option<Duration>) -> Task<Message> {
+    task::effect(Action::PlatformSpecific(
+        platform_specific::Action::Wayland(
+            platform_specific::wayland::Action::IdleNotify(timeout),
+        ),
+    ))
+}
+
+/// Subscribes to the idle notification events requested with
+/// [`idle_notify`].
+pub fn idle_events() -> Subscription<wayland::IdleEvent> {
+    event::listen_raw(|event, _status, _window| match event {
+        iced_futures::core::Event::PlatformSpecific(
+            PlatformSpecific::Wayland(wayland::Event::Idle(event)),
+        ) => Some(event),
+        _ => None,
+    })
+}
@@ -1,6 +1,7 @@
 //! Interact with the wayland objects of your application.
 
 pub mod activation;
+pub mod idle_notify;
 pub mod layer_surface;
 pub mod overlap_notify;
 pub mod popup;
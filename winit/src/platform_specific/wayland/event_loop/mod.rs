@@ -6,7 +6,14 @@ pub mod state;
 use crate::platform_specific::SurfaceIdWrapper;
 use crate::{
     futures::futures::channel::mpsc,
-    handlers::overlap::OverlapNotifyV1,
+    handlers::{
+        idle::{IdleInhibitManager, IdleNotifier},
+        overlap::OverlapNotifyV1,
+        seat::{
+            pointer_gestures::PointerGesturesManager, tablet::TabletManager,
+        },
+        text_input::TextInputManager,
+    },
     platform_specific::wayland::{
         handlers::{
             wp_fractional_scaling::FractionalScalingManager,
@@ -35,7 +42,10 @@ use cctk::{
             },
         },
         registry::RegistryState,
-        seat::SeatState,
+        seat::{
+            pointer_constraints::PointerConstraintsState,
+            relative_pointer::RelativePointerState, SeatState,
+        },
         session_lock::SessionLockState,
         shell::{wlr_layer::LayerShell, xdg::XdgShell, WaylandSurface},
         shm::Shm,
@@ -119,10 +129,12 @@ impl SctkEventLoop {
                                 id,
                             ) => {
                                 state.windows.push(SctkWindow { window, id });
+                                state.track_decorations(id);
                             }
                             crate::Action::RemoveWindow(id) => {
                                 // TODO clean up popups matching the window.
                                 state.windows.retain(|window| id != window.id);
+                                state.inhibit_idle(id, false);
                             }
                             crate::platform_specific::Action::SetCursor(
                                 icon,
@@ -146,6 +158,21 @@ impl SctkEventLoop {
                             crate::Action::Dropped(id) => {
                                 _ = state.destroyed.remove(&id.inner());
                             }
+                            crate::platform_specific::Action::UpdateIme(id) => {
+                                state.update_text_input(&id);
+                            }
+                            crate::platform_specific::Action::GrabPointer(
+                                id,
+                                grab,
+                            ) => {
+                                state.grab_pointer(id, grab);
+                            }
+                            crate::platform_specific::Action::InhibitIdle(
+                                id,
+                                inhibit,
+                            ) => {
+                                state.inhibit_idle(id, inhibit);
+                            }
                         },
                         calloop::channel::Event::Closed => {
                             log::info!("Calloop channel closed.");
@@ -208,6 +235,8 @@ impl SctkEventLoop {
                     session_lock_state: SessionLockState::new(&globals, &qh),
                     session_lock: None,
                     overlap_notify: OverlapNotifyV1::bind(&globals, &qh).ok(),
+                    text_input_manager: TextInputManager::bind(&globals, &qh)
+                        .ok(),
                     toplevel_info: ToplevelInfoState::try_new(
                         &registry_state,
                         &qh,
@@ -216,6 +245,23 @@ impl SctkEventLoop {
                         &registry_state,
                         &qh,
                     ),
+                    pointer_constraints_state: PointerConstraintsState::bind(
+                        &globals, &qh,
+                    ),
+                    relative_pointer_state: RelativePointerState::bind(
+                        &globals, &qh,
+                    ),
+                    pointer_gestures_manager: PointerGesturesManager::bind(
+                        &globals, &qh,
+                    )
+                    .ok(),
+                    tablet_manager: TabletManager::bind(&globals, &qh).ok(),
+                    pointer_constraint: None,
+                    idle_inhibit_manager: IdleInhibitManager::bind(
+                        &globals, &qh,
+                    )
+                    .ok(),
+                    idle_notifier: IdleNotifier::bind(&globals, &qh).ok(),
                     registry_state,
 
                     queue_handle: qh,
@@ -245,6 +291,8 @@ impl SctkEventLoop {
                     activation_token_ctr: 0,
                     token_senders: HashMap::new(),
                     overlap_notifications: HashMap::new(),
+                    idle_inhibitors: HashMap::new(),
+                    idle_notification: None,
                 },
                 _features: Default::default(),
             };
@@ -292,6 +340,11 @@ impl SctkEventLoop {
                         wl_shm,
                         wp_dmabuf,
                         wp_alpha_modifier,
+                        wp_idle_inhibit: state
+                            .state
+                            .idle_inhibit_manager
+                            .as_ref()
+                            .map(|manager| manager.manager.clone()),
                         qh: state.state.queue_handle.clone(),
                         buffers: HashMap::new(),
                         unmapped_subsurfaces: Vec::new(),
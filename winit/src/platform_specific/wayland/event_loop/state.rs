@@ -1,7 +1,14 @@
 use crate::{
     handlers::{
         activation::IcedRequestData,
+        idle::{IdleInhibitManager, IdleNotifier},
         overlap::{OverlapNotificationV1, OverlapNotifyV1},
+        seat::{
+            pointer_constraints::PointerConstraint,
+            pointer_gestures::{PointerGestures, PointerGesturesManager},
+            tablet::TabletManager,
+        },
+        text_input::TextInputManager,
     },
     platform_specific::{
         wayland::{
@@ -28,6 +35,7 @@ use wayland_backend::client::ObjectId;
 use winit::{
     dpi::{LogicalPosition, LogicalSize},
     platform::wayland::WindowExtWayland,
+    window::ImePurpose,
 };
 
 use cctk::{cosmic_protocols::overlap_notify::v1::client::zcosmic_overlap_notification_v1::ZcosmicOverlapNotificationV1, sctk::{
@@ -56,6 +64,8 @@ use cctk::{cosmic_protocols::overlap_notify::v1::client::zcosmic_overlap_notific
     seat::{
         keyboard::KeyEvent,
         pointer::{CursorIcon, PointerData, ThemedPointer},
+        pointer_constraints::PointerConstraintsState,
+        relative_pointer::RelativePointerState,
         touch::TouchData,
         SeatState,
     },
@@ -89,10 +99,15 @@ use iced_runtime::{
     },
 };
 use wayland_protocols::{
+    ext::idle_notify::v1::client::ext_idle_notification_v1::ExtIdleNotificationV1,
+    wp::idle_inhibit::zv1::client::zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1,
     wp::{
         fractional_scale::v1::client::wp_fractional_scale_v1::WpFractionalScaleV1,
         viewporter::client::wp_viewport::WpViewport,
     },
+    wp::relative_pointer::zv1::client::zwp_relative_pointer_v1::ZwpRelativePointerV1,
+    wp::tablet::zv2::client::zwp_tablet_seat_v2::ZwpTabletSeatV2,
+    wp::text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3,
     xdg::shell::client::xdg_surface::XdgSurface,
 };
 
@@ -105,6 +120,8 @@ pub(crate) struct SctkSeat {
     pub(crate) kbd_focus: Option<WlSurface>,
     pub(crate) last_kbd_press: Option<(KeyEvent, u32)>,
     pub(crate) ptr: Option<ThemedPointer>,
+    pub(crate) relative_ptr: Option<ZwpRelativePointerV1>,
+    pub(crate) gestures: Option<PointerGestures>,
     pub(crate) ptr_focus: Option<WlSurface>,
     pub(crate) last_ptr_press: Option<(u32, u32, u32)>, // (time, button, serial)
     pub(crate) touch: Option<WlTouch>,
@@ -114,6 +131,11 @@ pub(crate) struct SctkSeat {
     pub(crate) active_icon: Option<CursorIcon>,
     // Cursor icon set by application
     pub(crate) icon: Option<CursorIcon>,
+    pub(crate) text_input: Option<ZwpTextInputV3>,
+    pub(crate) text_input_focus: Option<WlSurface>,
+    // Whether the text input is enabled for the focused surface
+    pub(crate) ime_enabled: bool,
+    pub(crate) tablet_seat: Option<ZwpTabletSeatV2>,
 }
 
 impl SctkSeat {
@@ -202,6 +224,8 @@ impl CommonSurface {
 pub struct Common {
     pub(crate) fractional_scale: Option<f64>,
     pub(crate) has_focus: bool,
+    pub(crate) ime_allowed: bool,
+    pub(crate) ime_purpose: ImePurpose,
     pub(crate) ime_pos: LogicalPosition<u32>,
     pub(crate) ime_size: LogicalSize<u32>,
     pub(crate) size: LogicalSize<u32>,
@@ -214,6 +238,8 @@ impl Default for Common {
         Self {
             fractional_scale: Default::default(),
             has_focus: Default::default(),
+            ime_allowed: false,
+            ime_purpose: ImePurpose::Normal,
             ime_pos: Default::default(),
             ime_size: Default::default(),
             size: LogicalSize::new(1, 1),
@@ -333,6 +359,10 @@ pub struct SctkState {
     /// all notification objects
     pub(crate) overlap_notifications:
         HashMap<ObjectId, ZcosmicOverlapNotificationV1>,
+    /// idle inhibitors of surfaces
+    pub(crate) idle_inhibitors: HashMap<core::window::Id, ZwpIdleInhibitorV1>,
+    /// the idle notification of the active seat
+    pub(crate) idle_notification: Option<ExtIdleNotificationV1>,
 
     /// all present outputs
     pub(crate) outputs: Vec<WlOutput>,
@@ -388,8 +418,16 @@ pub struct SctkState {
     pub(crate) destroyed: HashSet<core::window::Id>,
     pub(crate) pending_popup: Option<(SctkPopupSettings, usize)>,
     pub(crate) overlap_notify: Option<OverlapNotifyV1>,
+    pub(crate) text_input_manager: Option<TextInputManager>,
     pub(crate) toplevel_info: Option<ToplevelInfoState>,
     pub(crate) toplevel_manager: Option<ToplevelManagerState>,
+    pub(crate) pointer_constraints_state: PointerConstraintsState,
+    pub(crate) relative_pointer_state: RelativePointerState,
+    pub(crate) pointer_gestures_manager: Option<PointerGesturesManager>,
+    pub(crate) tablet_manager: Option<TabletManager>,
+    pub(crate) pointer_constraint: Option<PointerConstraint>,
+    pub(crate) idle_inhibit_manager: Option<IdleInhibitManager>,
+    pub(crate) idle_notifier: Option<IdleNotifier>,
 
     pub(crate) activation_token_ctr: u32,
     pub(crate) token_senders: HashMap<u32, oneshot::Sender<Option<String>>>,
@@ -524,6 +562,33 @@ impl SctkState {
 
         // TODO winit sets cursor size after handling the change for the window, so maybe that should be done as well.
     }
+
+    /// Returns the surface of the layer surface, popup, lock surface or
+    /// window with the given id.
+    pub(crate) fn surface(&self, id: core::window::Id) -> Option<WlSurface> {
+        self.layer_surfaces
+            .iter()
+            .find(|l| l.id == id)
+            .map(|l| l.surface.wl_surface().clone())
+            .or_else(|| {
+                self.popups
+                    .iter()
+                    .find(|p| p.data.id == id)
+                    .map(|p| p.popup.wl_surface().clone())
+            })
+            .or_else(|| {
+                self.lock_surfaces
+                    .iter()
+                    .find(|s| s.id == id)
+                    .map(|s| s.session_lock_surface.wl_surface().clone())
+            })
+            .or_else(|| {
+                self.windows
+                    .iter()
+                    .find(|w| w.id == id)
+                    .map(|w| w.wl_surface(&self.connection))
+            })
+    }
 }
 
 impl SctkState {
@@ -923,6 +988,7 @@ impl SctkState {
                                 if let Some(destroyed) = self.id_map.remove(&l.surface.wl_surface().id()) {
                                     _ = self.destroyed.insert(destroyed);
                                 }
+                                self.inhibit_idle(id, false);
                                 send_event(&self.events_sender, &self.proxy, SctkEvent::LayerSurfaceEvent {
                                             variant: LayerSurfaceEventVariant::Done,
                                             id: l.surface.wl_surface().clone(),
@@ -1079,6 +1145,7 @@ impl SctkState {
                         if let Some(id) = self.id_map.remove(&popup.popup.wl_surface().id()) {
                             _ = self.destroyed.insert(id);
                         }
+                        self.inhibit_idle(popup.data.id, false);
                         _ = send_event(&self.events_sender, &self.proxy,
                             SctkEvent::PopupEvent { variant: crate::sctk_event::PopupEventVariant::Done, toplevel_id: popup.data.toplevel.clone(), parent_id: popup.data.parent.wl_surface().clone(), id: popup.popup.wl_surface().clone() });
                     }
@@ -1182,11 +1249,15 @@ impl SctkState {
                         if let Some(id) = self.id_map.remove(&surface.session_lock_surface.wl_surface().id()) {
                             _ = self.destroyed.insert(id);
                         }
+                        self.inhibit_idle(id, false);
 
                         send_event(&self.events_sender, &self.proxy, SctkEvent::SessionLockSurfaceDone { surface: surface.session_lock_surface.wl_surface().clone() });
                     }
                 }
             }
+            Action::IdleNotify(timeout) => {
+                self.idle_notify(timeout);
+            }
             Action::OverlapNotify(id, enabled) => {
                 if let Some(layer_surface) = self.layer_surfaces.iter_mut().find(|l| l.id == id) {
                     let Some(overlap_notify_state) = self.overlap_notify.as_ref() else {
@@ -0,0 +1,182 @@
+use crate::platform_specific::wayland::{
+    event_loop::state::SctkState, sctk_event::SctkEvent,
+};
+use cctk::sctk::{
+    globals::GlobalData,
+    reexports::client::{
+        delegate_noop,
+        globals::{BindError, GlobalList},
+        protocol::{wl_seat::WlSeat, wl_surface::WlSurface},
+        Connection, Dispatch, Proxy, QueueHandle,
+    },
+};
+use iced_runtime::core::window;
+use std::time::Duration;
+use wayland_protocols::{
+    ext::idle_notify::v1::client::{
+        ext_idle_notification_v1::{self, ExtIdleNotificationV1},
+        ext_idle_notifier_v1::ExtIdleNotifierV1,
+    },
+    wp::idle_inhibit::zv1::client::{
+        zwp_idle_inhibit_manager_v1::ZwpIdleInhibitManagerV1,
+        zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct IdleInhibitManager {
+    pub(crate) manager: ZwpIdleInhibitManagerV1,
+}
+
+impl IdleInhibitManager {
+    pub fn bind(
+        globals: &GlobalList,
+        qh: &QueueHandle<SctkState>,
+    ) -> Result<IdleInhibitManager, BindError> {
+        let manager = globals.bind(qh, 1..=1, GlobalData)?;
+        Ok(IdleInhibitManager { manager })
+    }
+
+    pub fn create_inhibitor(
+        &self,
+        surface: &WlSurface,
+        qh: &QueueHandle<SctkState>,
+    ) -> ZwpIdleInhibitorV1 {
+        self.manager.create_inhibitor(surface, qh, ())
+    }
+}
+
+impl Dispatch<ZwpIdleInhibitManagerV1, GlobalData, SctkState>
+    for IdleInhibitManager
+{
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpIdleInhibitManagerV1,
+        _: <ZwpIdleInhibitManagerV1 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IdleNotifier {
+    notifier: ExtIdleNotifierV1,
+}
+
+impl IdleNotifier {
+    pub fn bind(
+        globals: &GlobalList,
+        qh: &QueueHandle<SctkState>,
+    ) -> Result<IdleNotifier, BindError> {
+        let notifier = globals.bind(qh, 1..=1, GlobalData)?;
+        Ok(IdleNotifier { notifier })
+    }
+
+    pub fn get_idle_notification(
+        &self,
+        timeout: Duration,
+        seat: &WlSeat,
+        qh: &QueueHandle<SctkState>,
+    ) -> ExtIdleNotificationV1 {
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+
+        self.notifier.get_idle_notification(timeout, seat, qh, ())
+    }
+}
+
+impl Dispatch<ExtIdleNotifierV1, GlobalData, SctkState> for IdleNotifier {
+    fn event(
+        _: &mut SctkState,
+        _: &ExtIdleNotifierV1,
+        _: <ExtIdleNotifierV1 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+}
+
+impl Dispatch<ExtIdleNotificationV1, (), SctkState> for IdleNotifier {
+    fn event(
+        state: &mut SctkState,
+        _: &ExtIdleNotificationV1,
+        event: <ExtIdleNotificationV1 as Proxy>::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        match event {
+            ext_idle_notification_v1::Event::Idled => {
+                state.sctk_events.push(SctkEvent::Idled);
+            }
+            ext_idle_notification_v1::Event::Resumed => {
+                state.sctk_events.push(SctkEvent::Resumed);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl SctkState {
+    /// Sets or clears the idle inhibitor of the surface with the given id.
+    ///
+    /// The compositor only honors the inhibitor while the surface is visible.
+    pub(crate) fn inhibit_idle(&mut self, id: window::Id, inhibit: bool) {
+        if !inhibit {
+            if let Some(inhibitor) = self.idle_inhibitors.remove(&id) {
+                inhibitor.destroy();
+            }
+            return;
+        }
+
+        if self.idle_inhibitors.contains_key(&id) {
+            return;
+        }
+
+        let Some(manager) = self.idle_inhibit_manager.as_ref() else {
+            tracing::error!("Idle inhibit is not supported.");
+            return;
+        };
+        let Some(surface) = self.surface(id) else {
+            tracing::error!("No surface found to inhibit idle for {id:?}.");
+            return;
+        };
+
+        let inhibitor = manager.create_inhibitor(&surface, &self.queue_handle);
+        _ = self.idle_inhibitors.insert(id, inhibitor);
+    }
+
+    /// Replaces the idle notification of the active seat with one using the
+    /// given timeout, or removes it.
+    pub(crate) fn idle_notify(&mut self, timeout: Option<Duration>) {
+        if let Some(notification) = self.idle_notification.take() {
+            notification.destroy();
+        }
+
+        let Some(timeout) = timeout else {
+            return;
+        };
+        let Some(notifier) = self.idle_notifier.as_ref() else {
+            tracing::error!("Idle notify is not supported.");
+            return;
+        };
+        let Some(seat) = self.seats.first() else {
+            tracing::error!("Cannot be notified of idle without a seat.");
+            return;
+        };
+
+        self.idle_notification = Some(notifier.get_idle_notification(
+            timeout,
+            &seat.seat,
+            &self.queue_handle,
+        ));
+    }
+}
+
+delegate_noop!(SctkState: ignore ZwpIdleInhibitorV1);
+
+wayland_client::delegate_dispatch!(SctkState: [ZwpIdleInhibitManagerV1: GlobalData] => IdleInhibitManager);
+wayland_client::delegate_dispatch!(SctkState: [ExtIdleNotifierV1: GlobalData] => IdleNotifier);
+wayland_client::delegate_dispatch!(SctkState: [ExtIdleNotificationV1: ()] => IdleNotifier);
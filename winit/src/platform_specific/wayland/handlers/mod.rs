@@ -1,12 +1,14 @@
 // handlers
 pub mod activation;
 pub mod compositor;
+pub mod idle;
 pub mod output;
 pub mod overlap;
 pub mod seat;
 pub mod session_lock;
 pub mod shell;
 pub mod subcompositor;
+pub mod text_input;
 pub mod toplevel;
 pub mod wp_fractional_scaling;
 pub mod wp_viewporter;
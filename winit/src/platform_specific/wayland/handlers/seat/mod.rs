@@ -1,5 +1,9 @@
 // TODO support multi-seat handling
 pub mod keyboard;
 pub mod pointer;
+pub mod pointer_constraints;
+pub mod pointer_gestures;
+pub mod relative_pointer;
 pub mod seat;
+pub mod tablet;
 pub mod touch;
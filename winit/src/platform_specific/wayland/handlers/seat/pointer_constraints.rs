@@ -0,0 +1,174 @@
+use crate::platform_specific::wayland::event_loop::state::SctkState;
+use cctk::sctk::{
+    delegate_pointer_constraints,
+    reexports::client::{
+        protocol::{wl_pointer::WlPointer, wl_surface::WlSurface},
+        Connection, QueueHandle,
+    },
+    seat::pointer_constraints::PointerConstraintsHandler,
+    shell::WaylandSurface,
+};
+use iced_runtime::core::window::{self, PointerGrab};
+use wayland_protocols::wp::pointer_constraints::zv1::client::{
+    zwp_confined_pointer_v1::ZwpConfinedPointerV1,
+    zwp_locked_pointer_v1::ZwpLockedPointerV1,
+    zwp_pointer_constraints_v1::Lifetime,
+};
+
+/// The constraint currently applied to the pointer of the active seat.
+#[derive(Debug)]
+pub(crate) enum PointerConstraint {
+    Locked(WlSurface, ZwpLockedPointerV1),
+    Confined(WlSurface, ZwpConfinedPointerV1),
+}
+
+impl PointerConstraint {
+    /// Returns the surface the pointer is constrained to.
+    pub(crate) fn surface(&self) -> &WlSurface {
+        match self {
+            PointerConstraint::Locked(surface, _)
+            | PointerConstraint::Confined(surface, _) => surface,
+        }
+    }
+
+    fn destroy(&self) {
+        match self {
+            PointerConstraint::Locked(_, locked) => locked.destroy(),
+            PointerConstraint::Confined(_, confined) => confined.destroy(),
+        }
+    }
+}
+
+impl SctkState {
+    /// Locks or confines the pointer of the active seat to the surface with
+    /// the given id, replacing any previous constraint.
+    pub(crate) fn grab_pointer(&mut self, id: window::Id, grab: PointerGrab) {
+        if let Some(constraint) = self.pointer_constraint.take() {
+            constraint.destroy();
+        }
+
+        if grab == PointerGrab::None {
+            return;
+        }
+
+        let Some(surface) = self.surface(id) else {
+            tracing::error!("No surface found to grab the pointer for {id:?}.");
+            return;
+        };
+        let Some(pointer) = self
+            .seats
+            .first()
+            .and_then(|seat| seat.ptr.as_ref())
+            .map(|ptr| ptr.pointer().clone())
+        else {
+            tracing::error!("Cannot grab the pointer without a pointer.");
+            return;
+        };
+
+        let region = match grab {
+            PointerGrab::Confined(Some(bounds)) => {
+                let region = self
+                    .compositor_state
+                    .wl_compositor()
+                    .create_region(&self.queue_handle, ());
+                region.add(
+                    bounds.x as i32,
+                    bounds.y as i32,
+                    bounds.width as i32,
+                    bounds.height as i32,
+                );
+                Some(region)
+            }
+            _ => None,
+        };
+
+        // A persistent constraint is reactivated whenever the pointer enters
+        // the surface again, until it is released.
+        let constraint = match grab {
+            PointerGrab::Locked => self
+                .pointer_constraints_state
+                .lock_pointer(
+                    &surface,
+                    &pointer,
+                    None,
+                    Lifetime::Persistent,
+                    &self.queue_handle,
+                )
+                .map(|locked| {
+                    PointerConstraint::Locked(surface.clone(), locked)
+                }),
+            _ => self
+                .pointer_constraints_state
+                .confine_pointer(
+                    &surface,
+                    &pointer,
+                    region.as_ref(),
+                    Lifetime::Persistent,
+                    &self.queue_handle,
+                )
+                .map(|confined| {
+                    PointerConstraint::Confined(surface.clone(), confined)
+                }),
+        };
+
+        // The compositor copies the region, so it can go right away.
+        if let Some(region) = region {
+            region.destroy();
+        }
+
+        match constraint {
+            Ok(constraint) => {
+                self.pointer_constraint = Some(constraint);
+            }
+            Err(error) => {
+                tracing::error!(
+                    "Pointer constraints are not supported: {error}"
+                );
+            }
+        }
+    }
+}
+
+impl PointerConstraintsHandler for SctkState {
+    fn confined(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _confined_pointer: &ZwpConfinedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn unconfined(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _confined_pointer: &ZwpConfinedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn locked(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _locked_pointer: &ZwpLockedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn unlocked(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _locked_pointer: &ZwpLockedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+}
+
+delegate_pointer_constraints!(SctkState);
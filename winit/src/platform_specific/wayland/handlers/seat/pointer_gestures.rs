@@ -0,0 +1,341 @@
+use crate::platform_specific::wayland::{
+    event_loop::state::SctkState, sctk_event::SctkEvent,
+};
+use cctk::sctk::{
+    globals::GlobalData,
+    reexports::client::{
+        globals::{BindError, GlobalList},
+        protocol::{wl_pointer::WlPointer, wl_surface::WlSurface},
+        Connection, Dispatch, Proxy, QueueHandle,
+    },
+};
+use iced_runtime::core::{
+    mouse::{gesture::Phase, Gesture},
+    Vector,
+};
+use std::sync::Mutex;
+use wayland_protocols::wp::pointer_gestures::zv1::client::{
+    zwp_pointer_gesture_hold_v1::{self, ZwpPointerGestureHoldV1},
+    zwp_pointer_gesture_pinch_v1::{self, ZwpPointerGesturePinchV1},
+    zwp_pointer_gesture_swipe_v1::{self, ZwpPointerGestureSwipeV1},
+    zwp_pointer_gestures_v1::ZwpPointerGesturesV1,
+};
+
+#[derive(Debug, Clone)]
+pub struct PointerGesturesManager {
+    manager: ZwpPointerGesturesV1,
+}
+
+impl PointerGesturesManager {
+    pub fn bind(
+        globals: &GlobalList,
+        qh: &QueueHandle<SctkState>,
+    ) -> Result<PointerGesturesManager, BindError> {
+        let manager = globals.bind(qh, 1..=3, GlobalData)?;
+        Ok(PointerGesturesManager { manager })
+    }
+
+    pub fn get_gestures(
+        &self,
+        pointer: &WlPointer,
+        qh: &QueueHandle<SctkState>,
+    ) -> PointerGestures {
+        PointerGestures {
+            swipe: self.manager.get_swipe_gesture(
+                pointer,
+                qh,
+                GestureData::default(),
+            ),
+            pinch: self.manager.get_pinch_gesture(
+                pointer,
+                qh,
+                GestureData::default(),
+            ),
+            // Hold gestures were introduced in version 3
+            hold: (self.manager.version() >= 3).then(|| {
+                self.manager.get_hold_gesture(
+                    pointer,
+                    qh,
+                    GestureData::default(),
+                )
+            }),
+        }
+    }
+}
+
+impl Dispatch<ZwpPointerGesturesV1, GlobalData, SctkState>
+    for PointerGesturesManager
+{
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpPointerGesturesV1,
+        _: <ZwpPointerGesturesV1 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+}
+
+/// The gesture objects of a pointer.
+#[derive(Debug)]
+pub struct PointerGestures {
+    swipe: ZwpPointerGestureSwipeV1,
+    pinch: ZwpPointerGesturePinchV1,
+    hold: Option<ZwpPointerGestureHoldV1>,
+}
+
+impl PointerGestures {
+    pub fn destroy(self) {
+        self.swipe.destroy();
+        self.pinch.destroy();
+
+        if let Some(hold) = self.hold {
+            hold.destroy();
+        }
+    }
+}
+
+/// The state of the ongoing gesture of a gesture object.
+#[derive(Debug, Default)]
+pub struct GestureData {
+    ongoing: Mutex<Option<Ongoing>>,
+}
+
+#[derive(Debug)]
+struct Ongoing {
+    surface: WlSurface,
+    fingers: u32,
+    scale: f64,
+}
+
+impl GestureData {
+    fn begin(&self, surface: WlSurface, fingers: u32) {
+        *self.ongoing.lock().unwrap() = Some(Ongoing {
+            surface,
+            fingers,
+            scale: 1.0,
+        });
+    }
+
+    fn end(&self) -> Option<(WlSurface, u32)> {
+        self.ongoing
+            .lock()
+            .unwrap()
+            .take()
+            .map(|ongoing| (ongoing.surface, ongoing.fingers))
+    }
+}
+
+fn phase(cancelled: i32) -> Phase {
+    if cancelled != 0 {
+        Phase::Cancelled
+    } else {
+        Phase::Ended
+    }
+}
+
+impl Dispatch<ZwpPointerGestureSwipeV1, GestureData, SctkState>
+    for GestureData
+{
+    fn event(
+        state: &mut SctkState,
+        _: &ZwpPointerGestureSwipeV1,
+        event: <ZwpPointerGestureSwipeV1 as Proxy>::Event,
+        data: &GestureData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        let (surface, gesture) = match event {
+            zwp_pointer_gesture_swipe_v1::Event::Begin {
+                surface,
+                fingers,
+                ..
+            } => {
+                data.begin(surface.clone(), fingers);
+
+                (
+                    surface,
+                    Gesture::Swipe {
+                        phase: Phase::Started,
+                        fingers,
+                        delta: Vector::ZERO,
+                    },
+                )
+            }
+            zwp_pointer_gesture_swipe_v1::Event::Update { dx, dy, .. } => {
+                let guard = data.ongoing.lock().unwrap();
+                let Some(ongoing) = guard.as_ref() else {
+                    return;
+                };
+
+                (
+                    ongoing.surface.clone(),
+                    Gesture::Swipe {
+                        phase: Phase::Moved,
+                        fingers: ongoing.fingers,
+                        delta: Vector::new(dx as f32, dy as f32),
+                    },
+                )
+            }
+            zwp_pointer_gesture_swipe_v1::Event::End { cancelled, .. } => {
+                let Some((surface, fingers)) = data.end() else {
+                    return;
+                };
+
+                (
+                    surface,
+                    Gesture::Swipe {
+                        phase: phase(cancelled),
+                        fingers,
+                        delta: Vector::ZERO,
+                    },
+                )
+            }
+            _ => return,
+        };
+
+        state
+            .sctk_events
+            .push(SctkEvent::PointerGestureEvent { gesture, surface });
+    }
+}
+
+impl Dispatch<ZwpPointerGesturePinchV1, GestureData, SctkState>
+    for GestureData
+{
+    fn event(
+        state: &mut SctkState,
+        _: &ZwpPointerGesturePinchV1,
+        event: <ZwpPointerGesturePinchV1 as Proxy>::Event,
+        data: &GestureData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        let (surface, gesture) = match event {
+            zwp_pointer_gesture_pinch_v1::Event::Begin {
+                surface,
+                fingers,
+                ..
+            } => {
+                data.begin(surface.clone(), fingers);
+
+                (
+                    surface,
+                    Gesture::Pinch {
+                        phase: Phase::Started,
+                        fingers,
+                        delta: Vector::ZERO,
+                        scale: 1.0,
+                        rotation: 0.0,
+                    },
+                )
+            }
+            zwp_pointer_gesture_pinch_v1::Event::Update {
+                dx,
+                dy,
+                scale,
+                rotation,
+                ..
+            } => {
+                let mut guard = data.ongoing.lock().unwrap();
+                let Some(ongoing) = guard.as_mut() else {
+                    return;
+                };
+
+                // The protocol reports the scale relative to the start of
+                // the gesture, but we report it relative to the last update
+                let change = if ongoing.scale > 0.0 {
+                    scale / ongoing.scale
+                } else {
+                    1.0
+                };
+                ongoing.scale = scale;
+
+                (
+                    ongoing.surface.clone(),
+                    Gesture::Pinch {
+                        phase: Phase::Moved,
+                        fingers: ongoing.fingers,
+                        delta: Vector::new(dx as f32, dy as f32),
+                        scale: change as f32,
+                        rotation: rotation as f32,
+                    },
+                )
+            }
+            zwp_pointer_gesture_pinch_v1::Event::End { cancelled, .. } => {
+                let Some((surface, fingers)) = data.end() else {
+                    return;
+                };
+
+                (
+                    surface,
+                    Gesture::Pinch {
+                        phase: phase(cancelled),
+                        fingers,
+                        delta: Vector::ZERO,
+                        scale: 1.0,
+                        rotation: 0.0,
+                    },
+                )
+            }
+            _ => return,
+        };
+
+        state
+            .sctk_events
+            .push(SctkEvent::PointerGestureEvent { gesture, surface });
+    }
+}
+
+impl Dispatch<ZwpPointerGestureHoldV1, GestureData, SctkState> for GestureData {
+    fn event(
+        state: &mut SctkState,
+        _: &ZwpPointerGestureHoldV1,
+        event: <ZwpPointerGestureHoldV1 as Proxy>::Event,
+        data: &GestureData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        let (surface, gesture) = match event {
+            zwp_pointer_gesture_hold_v1::Event::Begin {
+                surface,
+                fingers,
+                ..
+            } => {
+                data.begin(surface.clone(), fingers);
+
+                (
+                    surface,
+                    Gesture::Hold {
+                        phase: Phase::Started,
+                        fingers,
+                    },
+                )
+            }
+            zwp_pointer_gesture_hold_v1::Event::End { cancelled, .. } => {
+                let Some((surface, fingers)) = data.end() else {
+                    return;
+                };
+
+                (
+                    surface,
+                    Gesture::Hold {
+                        phase: phase(cancelled),
+                        fingers,
+                    },
+                )
+            }
+            _ => return,
+        };
+
+        state
+            .sctk_events
+            .push(SctkEvent::PointerGestureEvent { gesture, surface });
+    }
+}
+
+wayland_client::delegate_dispatch!(SctkState: [ZwpPointerGesturesV1: GlobalData] => PointerGesturesManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpPointerGestureSwipeV1: GestureData] => GestureData);
+wayland_client::delegate_dispatch!(SctkState: [ZwpPointerGesturePinchV1: GestureData] => GestureData);
+wayland_client::delegate_dispatch!(SctkState: [ZwpPointerGestureHoldV1: GestureData] => GestureData);
@@ -0,0 +1,56 @@
+use crate::platform_specific::wayland::{
+    event_loop::state::SctkState, sctk_event::SctkEvent,
+};
+use cctk::sctk::{
+    delegate_relative_pointer,
+    reexports::client::{
+        protocol::wl_pointer::WlPointer, Connection, Proxy, QueueHandle,
+    },
+    seat::relative_pointer::{RelativeMotionEvent, RelativePointerHandler},
+};
+use wayland_protocols::wp::relative_pointer::zv1::client::zwp_relative_pointer_v1::ZwpRelativePointerV1;
+
+impl RelativePointerHandler for SctkState {
+    fn relative_pointer_motion(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _relative_pointer: &ZwpRelativePointerV1,
+        pointer: &WlPointer,
+        event: RelativeMotionEvent,
+    ) {
+        // only forward for the active seat
+        let Some(seat) = self.seats.first() else {
+            return;
+        };
+        if seat.ptr.as_ref().map(|p| p.pointer()) != Some(pointer) {
+            return;
+        }
+        let Some(surface) = seat.ptr_focus.clone() else {
+            return;
+        };
+
+        // raw motion is only reported while the pointer is grabbed
+        if !self
+            .pointer_constraint
+            .as_ref()
+            .is_some_and(|constraint| constraint.surface() == &surface)
+        {
+            return;
+        }
+
+        // winit reports the motion of its own windows as device events
+        let id = winit::window::WindowId::from(surface.id().as_ptr() as u64);
+        if self.windows.iter().any(|w| w.window.id() == id) {
+            return;
+        }
+
+        self.sctk_events.push(SctkEvent::RelativePointerEvent {
+            delta: event.delta_unaccel,
+            surface,
+            seat_id: seat.seat.clone(),
+        });
+    }
+}
+
+delegate_relative_pointer!(SctkState);
@@ -25,10 +25,21 @@ impl SeatHandler for SctkState {
             id: seat.clone(),
         });
 
+        let text_input = self
+            .text_input_manager
+            .as_ref()
+            .map(|manager| manager.get_text_input(&seat, qh));
+        let tablet_seat = self
+            .tablet_manager
+            .as_ref()
+            .map(|manager| manager.get_tablet_seat(&seat, qh));
+
         self.seats.push(SctkSeat {
             seat,
             kbd: None,
             ptr: None,
+            relative_ptr: None,
+            gestures: None,
             touch: None,
             _modifiers: Modifiers::default(),
             kbd_focus: None,
@@ -38,6 +49,10 @@ impl SeatHandler for SctkState {
             last_touch_down: None,
             icon: None,
             active_icon: None,
+            text_input,
+            text_input_focus: None,
+            ime_enabled: false,
+            tablet_seat,
         });
     }
 
@@ -51,10 +66,21 @@ impl SeatHandler for SctkState {
         let my_seat = match self.seats.iter_mut().find(|s| s.seat == seat) {
             Some(s) => s,
             None => {
+                let text_input = self
+                    .text_input_manager
+                    .as_ref()
+                    .map(|manager| manager.get_text_input(&seat, qh));
+                let tablet_seat = self
+                    .tablet_manager
+                    .as_ref()
+                    .map(|manager| manager.get_tablet_seat(&seat, qh));
+
                 self.seats.push(SctkSeat {
                     seat: seat.clone(),
                     kbd: None,
                     ptr: None,
+                    relative_ptr: None,
+                    gestures: None,
                     touch: None,
 
                     _modifiers: Modifiers::default(),
@@ -65,6 +91,10 @@ impl SeatHandler for SctkState {
                     last_touch_down: None,
                     icon: None,
                     active_icon: None,
+                    text_input,
+                    text_input_focus: None,
+                    ime_enabled: false,
+                    tablet_seat,
                 });
                 self.seats.last_mut().unwrap()
             }
@@ -124,6 +154,14 @@ impl SeatHandler for SctkState {
                         ),
                         id: seat.clone(),
                     });
+                    my_seat.relative_ptr = self
+                        .relative_pointer_state
+                        .get_relative_pointer(ptr.pointer(), qh)
+                        .ok();
+                    my_seat.gestures = self
+                        .pointer_gestures_manager
+                        .as_ref()
+                        .map(|manager| manager.get_gestures(ptr.pointer(), qh));
                     _ = my_seat.ptr.replace(ptr);
                 }
             }
@@ -170,6 +208,12 @@ impl SeatHandler for SctkState {
                 }
             }
             cctk::sctk::seat::Capability::Pointer => {
+                if let Some(relative_ptr) = my_seat.relative_ptr.take() {
+                    relative_ptr.destroy();
+                }
+                if let Some(gestures) = my_seat.gestures.take() {
+                    gestures.destroy();
+                }
                 if let Some(ptr) = my_seat.ptr.take() {
                     self.sctk_events.push(SctkEvent::SeatEvent {
                         variant: SeatEventVariant::RemoveCapability(
@@ -206,7 +250,14 @@ impl SeatHandler for SctkState {
             id: seat.clone(),
         });
         if let Some(i) = self.seats.iter().position(|s| s.seat == seat) {
-            _ = self.seats.remove(i);
+            let removed = self.seats.remove(i);
+
+            if let Some(text_input) = removed.text_input {
+                text_input.destroy();
+            }
+            if let Some(tablet_seat) = removed.tablet_seat {
+                tablet_seat.destroy();
+            }
         }
     }
 }
@@ -0,0 +1,306 @@
+use crate::platform_specific::wayland::{
+    event_loop::state::SctkState, sctk_event::SctkEvent,
+};
+use cctk::sctk::{
+    globals::GlobalData,
+    reexports::client::{
+        delegate_noop,
+        globals::{BindError, GlobalList},
+        protocol::{wl_seat::WlSeat, wl_surface::WlSurface},
+        Connection, Dispatch, Proxy, QueueHandle, WEnum,
+    },
+};
+use iced_runtime::core::{
+    pen::{self, Pen},
+    Point, Vector,
+};
+use std::sync::Mutex;
+use wayland_protocols::wp::tablet::zv2::client::{
+    zwp_tablet_manager_v2::ZwpTabletManagerV2,
+    zwp_tablet_pad_group_v2::{self, ZwpTabletPadGroupV2},
+    zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2,
+    zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2,
+    zwp_tablet_pad_v2::{self, ZwpTabletPadV2},
+    zwp_tablet_seat_v2::{self, ZwpTabletSeatV2},
+    zwp_tablet_tool_v2::{self, ZwpTabletToolV2},
+    zwp_tablet_v2::{self, ZwpTabletV2},
+};
+
+// These values are coming from <linux/input-event-codes.h>.
+const BTN_STYLUS: u32 = 0x14b;
+const BTN_STYLUS2: u32 = 0x14c;
+const BTN_STYLUS3: u32 = 0x149;
+
+/// The largest value of the pressure and distance axes.
+const AXIS_MAX: f32 = 65535.0;
+
+#[derive(Debug, Clone)]
+pub struct TabletManager {
+    manager: ZwpTabletManagerV2,
+}
+
+impl TabletManager {
+    pub fn bind(
+        globals: &GlobalList,
+        qh: &QueueHandle<SctkState>,
+    ) -> Result<TabletManager, BindError> {
+        let manager = globals.bind(qh, 1..=1, GlobalData)?;
+        Ok(TabletManager { manager })
+    }
+
+    pub fn get_tablet_seat(
+        &self,
+        seat: &WlSeat,
+        qh: &QueueHandle<SctkState>,
+    ) -> ZwpTabletSeatV2 {
+        self.manager.get_tablet_seat(seat, qh, GlobalData)
+    }
+}
+
+impl Dispatch<ZwpTabletManagerV2, GlobalData, SctkState> for TabletManager {
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpTabletManagerV2,
+        _: <ZwpTabletManagerV2 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpTabletSeatV2, GlobalData, SctkState> for TabletManager {
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpTabletSeatV2,
+        _: <ZwpTabletSeatV2 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        // Tablets, tools and pads are set up by their own events
+    }
+
+    wayland_client::event_created_child!(SctkState, ZwpTabletSeatV2, [
+        zwp_tablet_seat_v2::EVT_TABLET_ADDED_OPCODE => (ZwpTabletV2, ()),
+        zwp_tablet_seat_v2::EVT_TOOL_ADDED_OPCODE => (ZwpTabletToolV2, TabletToolData::default()),
+        zwp_tablet_seat_v2::EVT_PAD_ADDED_OPCODE => (ZwpTabletPadV2, ()),
+    ]);
+}
+
+impl Dispatch<ZwpTabletV2, (), SctkState> for TabletManager {
+    fn event(
+        _: &mut SctkState,
+        tablet: &ZwpTabletV2,
+        event: <ZwpTabletV2 as Proxy>::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        if let zwp_tablet_v2::Event::Removed = event {
+            tablet.destroy();
+        }
+    }
+}
+
+// Pads are not supported yet, but their objects still need to be managed
+impl Dispatch<ZwpTabletPadV2, (), SctkState> for TabletManager {
+    fn event(
+        _: &mut SctkState,
+        pad: &ZwpTabletPadV2,
+        event: <ZwpTabletPadV2 as Proxy>::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        if let zwp_tablet_pad_v2::Event::Removed = event {
+            pad.destroy();
+        }
+    }
+
+    wayland_client::event_created_child!(SctkState, ZwpTabletPadV2, [
+        zwp_tablet_pad_v2::EVT_GROUP_OPCODE => (ZwpTabletPadGroupV2, ()),
+    ]);
+}
+
+impl Dispatch<ZwpTabletPadGroupV2, (), SctkState> for TabletManager {
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpTabletPadGroupV2,
+        _: <ZwpTabletPadGroupV2 as Proxy>::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+
+    wayland_client::event_created_child!(SctkState, ZwpTabletPadGroupV2, [
+        zwp_tablet_pad_group_v2::EVT_RING_OPCODE => (ZwpTabletPadRingV2, ()),
+        zwp_tablet_pad_group_v2::EVT_STRIP_OPCODE => (ZwpTabletPadStripV2, ()),
+    ]);
+}
+
+/// The state of a tablet tool, accumulated until the end of each frame.
+#[derive(Debug, Default)]
+pub struct TabletToolData {
+    state: Mutex<ToolState>,
+}
+
+#[derive(Debug, Default)]
+struct ToolState {
+    surface: Option<WlSurface>,
+    pen: Pen,
+    pending: Vec<Pending>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pending {
+    Entered,
+    Moved,
+    Touched,
+    Lifted,
+    Button(pen::Button, bool),
+    Left,
+}
+
+impl ToolState {
+    fn moved(&mut self) {
+        if !self.pending.contains(&Pending::Moved) {
+            self.pending.push(Pending::Moved);
+        }
+    }
+}
+
+impl Dispatch<ZwpTabletToolV2, TabletToolData, SctkState> for TabletToolData {
+    fn event(
+        state: &mut SctkState,
+        tool: &ZwpTabletToolV2,
+        event: <ZwpTabletToolV2 as Proxy>::Event,
+        data: &TabletToolData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        let mut tool_state = data.state.lock().unwrap();
+
+        match event {
+            zwp_tablet_tool_v2::Event::Type { tool_type } => {
+                tool_state.pen.tool = match tool_type {
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Eraser) => {
+                        pen::Tool::Eraser
+                    }
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Brush) => {
+                        pen::Tool::Brush
+                    }
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Pencil) => {
+                        pen::Tool::Pencil
+                    }
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Airbrush) => {
+                        pen::Tool::Airbrush
+                    }
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Mouse) => {
+                        pen::Tool::Mouse
+                    }
+                    WEnum::Value(zwp_tablet_tool_v2::Type::Lens) => {
+                        pen::Tool::Lens
+                    }
+                    _ => pen::Tool::Pen,
+                };
+            }
+            zwp_tablet_tool_v2::Event::ProximityIn { surface, .. } => {
+                tool_state.surface = Some(surface);
+                tool_state.pending.push(Pending::Entered);
+            }
+            zwp_tablet_tool_v2::Event::ProximityOut => {
+                tool_state.pending.push(Pending::Left);
+            }
+            zwp_tablet_tool_v2::Event::Down { .. } => {
+                tool_state.pen.is_touching = true;
+                tool_state.pending.push(Pending::Touched);
+            }
+            zwp_tablet_tool_v2::Event::Up => {
+                tool_state.pen.is_touching = false;
+                tool_state.pending.push(Pending::Lifted);
+            }
+            zwp_tablet_tool_v2::Event::Motion { x, y } => {
+                tool_state.pen.position = Point::new(x as f32, y as f32);
+                tool_state.moved();
+            }
+            zwp_tablet_tool_v2::Event::Pressure { pressure } => {
+                tool_state.pen.pressure = pressure as f32 / AXIS_MAX;
+                tool_state.moved();
+            }
+            zwp_tablet_tool_v2::Event::Distance { distance } => {
+                tool_state.pen.distance = distance as f32 / AXIS_MAX;
+                tool_state.moved();
+            }
+            zwp_tablet_tool_v2::Event::Tilt { tilt_x, tilt_y } => {
+                tool_state.pen.tilt = Vector::new(tilt_x as f32, tilt_y as f32);
+                tool_state.moved();
+            }
+            zwp_tablet_tool_v2::Event::Button {
+                button,
+                state: button_state,
+                ..
+            } => {
+                let button = match button {
+                    BTN_STYLUS => pen::Button::Primary,
+                    BTN_STYLUS2 => pen::Button::Secondary,
+                    BTN_STYLUS3 => pen::Button::Tertiary,
+                    button => pen::Button::Other(button as u16),
+                };
+                let is_pressed = matches!(
+                    button_state,
+                    WEnum::Value(zwp_tablet_tool_v2::ButtonState::Pressed)
+                );
+
+                tool_state.pending.push(Pending::Button(button, is_pressed));
+            }
+            zwp_tablet_tool_v2::Event::Frame { .. } => {
+                let pending = std::mem::take(&mut tool_state.pending);
+                let pen = tool_state.pen;
+
+                let Some(surface) = tool_state.surface.clone() else {
+                    return;
+                };
+
+                for pending in pending {
+                    let variant = match pending {
+                        Pending::Entered => pen::Event::Entered(pen),
+                        Pending::Moved => pen::Event::Moved(pen),
+                        Pending::Touched => pen::Event::Touched(pen),
+                        Pending::Lifted => pen::Event::Lifted(pen),
+                        Pending::Button(button, true) => {
+                            pen::Event::ButtonPressed(pen, button)
+                        }
+                        Pending::Button(button, false) => {
+                            pen::Event::ButtonReleased(pen, button)
+                        }
+                        Pending::Left => {
+                            tool_state.surface = None;
+                            pen::Event::Left(pen)
+                        }
+                    };
+
+                    state.sctk_events.push(SctkEvent::TabletToolEvent {
+                        variant,
+                        surface: surface.clone(),
+                    });
+                }
+            }
+            zwp_tablet_tool_v2::Event::Removed => {
+                tool.destroy();
+            }
+            _ => {}
+        }
+    }
+}
+
+delegate_noop!(SctkState: ignore ZwpTabletPadRingV2);
+delegate_noop!(SctkState: ignore ZwpTabletPadStripV2);
+
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletManagerV2: GlobalData] => TabletManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletSeatV2: GlobalData] => TabletManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletV2: ()] => TabletManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletPadV2: ()] => TabletManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletPadGroupV2: ()] => TabletManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTabletToolV2: TabletToolData] => TabletToolData);
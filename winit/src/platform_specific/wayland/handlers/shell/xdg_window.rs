@@ -1,7 +1,12 @@
-use crate::platform_specific::wayland::event_loop::state::SctkState;
+use crate::platform_specific::wayland::{
+    event_loop::state::SctkState, sctk_event::SctkEvent,
+};
 use cctk::sctk::{
-    delegate_xdg_shell, delegate_xdg_window, shell::xdg::window::WindowHandler,
+    delegate_xdg_shell, delegate_xdg_window, reexports::client::Proxy,
+    shell::xdg::window::WindowHandler,
 };
+use iced_runtime::core::window;
+use wayland_protocols::xdg::decoration::zv1::client::zxdg_decoration_manager_v1::ZxdgDecorationManagerV1;
 
 impl WindowHandler for SctkState {
     fn request_close(
@@ -23,5 +28,27 @@ impl WindowHandler for SctkState {
     }
 }
 
+impl SctkState {
+    /// Reports whether the compositor draws the decorations of the winit
+    /// toplevel with the given id.
+    ///
+    /// winit creates the `zxdg_toplevel_decoration_v1` of its toplevels and
+    /// asks for server-side decorations with it. A toplevel cannot have a
+    /// second one, so the decorations are first left to the client only
+    /// when the compositor does not advertise `zxdg_decoration_manager_v1`.
+    /// The mode the compositor configures later is followed through the
+    /// frame winit draws for the toplevel.
+    pub(crate) fn track_decorations(&mut self, id: window::Id) {
+        let server_side = self
+            .registry_state
+            .globals_by_interface(ZxdgDecorationManagerV1::interface().name)
+            .next()
+            .is_some();
+
+        self.sctk_events
+            .push(SctkEvent::ToplevelDecorations { id, server_side });
+    }
+}
+
 delegate_xdg_window!(SctkState);
 delegate_xdg_shell!(SctkState);
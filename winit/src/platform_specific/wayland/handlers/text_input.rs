@@ -0,0 +1,276 @@
+use crate::platform_specific::wayland::{
+    event_loop::state::{Common, SctkState},
+    sctk_event::SctkEvent,
+};
+use cctk::sctk::{
+    globals::GlobalData,
+    reexports::client::{
+        globals::{BindError, GlobalList},
+        protocol::wl_seat::WlSeat,
+        Connection, Dispatch, Proxy, QueueHandle,
+    },
+    shell::WaylandSurface,
+};
+use std::sync::{Arc, Mutex};
+use wayland_backend::client::ObjectId;
+use wayland_protocols::wp::text_input::zv3::client::{
+    zwp_text_input_manager_v3::ZwpTextInputManagerV3,
+    zwp_text_input_v3::{self, ContentHint, ContentPurpose, ZwpTextInputV3},
+};
+use winit::{
+    event::{Ime, WindowEvent},
+    window::{ImePurpose, WindowId},
+};
+
+#[derive(Debug, Clone)]
+pub struct TextInputManager {
+    manager: ZwpTextInputManagerV3,
+}
+
+impl TextInputManager {
+    pub fn bind(
+        globals: &GlobalList,
+        qh: &QueueHandle<SctkState>,
+    ) -> Result<TextInputManager, BindError> {
+        let manager = globals.bind(qh, 1..=1, GlobalData)?;
+        Ok(TextInputManager { manager })
+    }
+
+    pub fn get_text_input(
+        &self,
+        seat: &WlSeat,
+        qh: &QueueHandle<SctkState>,
+    ) -> ZwpTextInputV3 {
+        self.manager
+            .get_text_input(seat, qh, TextInputData::default())
+    }
+}
+
+impl Dispatch<ZwpTextInputManagerV3, GlobalData, SctkState>
+    for TextInputManager
+{
+    fn event(
+        _: &mut SctkState,
+        _: &ZwpTextInputManagerV3,
+        _: <ZwpTextInputManagerV3 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+    }
+}
+
+/// The pending state of a text input, applied on `done`.
+#[derive(Debug, Default)]
+pub struct TextInputData {
+    pending: Mutex<Pending>,
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    preedit: Option<(String, Option<(usize, usize)>)>,
+    commit: Option<String>,
+}
+
+impl Dispatch<ZwpTextInputV3, TextInputData, SctkState> for TextInputData {
+    fn event(
+        state: &mut SctkState,
+        text_input: &ZwpTextInputV3,
+        event: <ZwpTextInputV3 as Proxy>::Event,
+        data: &TextInputData,
+        _: &Connection,
+        _: &QueueHandle<SctkState>,
+    ) {
+        match event {
+            zwp_text_input_v3::Event::Enter { surface } => {
+                let Some(seat) = state
+                    .seats
+                    .iter_mut()
+                    .find(|s| s.text_input.as_ref() == Some(text_input))
+                else {
+                    return;
+                };
+
+                _ = seat.text_input_focus.replace(surface.clone());
+
+                state.update_text_input(&surface.id());
+            }
+            zwp_text_input_v3::Event::Leave { surface } => {
+                let Some(seat) = state
+                    .seats
+                    .iter_mut()
+                    .find(|s| s.text_input.as_ref() == Some(text_input))
+                else {
+                    return;
+                };
+
+                _ = seat.text_input_focus.take();
+                *data.pending.lock().unwrap() = Pending::default();
+
+                if seat.ime_enabled {
+                    seat.ime_enabled = false;
+
+                    text_input.disable();
+                    text_input.commit();
+
+                    state.sctk_events.push(SctkEvent::Winit(
+                        WindowId::from(surface.id().as_ptr() as u64),
+                        WindowEvent::Ime(Ime::Disabled),
+                    ));
+                }
+            }
+            zwp_text_input_v3::Event::PreeditString {
+                text,
+                cursor_begin,
+                cursor_end,
+            } => {
+                // A negative cursor means it should be hidden
+                let cursor = usize::try_from(cursor_begin)
+                    .ok()
+                    .zip(usize::try_from(cursor_end).ok());
+
+                data.pending.lock().unwrap().preedit =
+                    Some((text.unwrap_or_default(), cursor));
+            }
+            zwp_text_input_v3::Event::CommitString { text } => {
+                data.pending.lock().unwrap().commit =
+                    Some(text.unwrap_or_default());
+            }
+            zwp_text_input_v3::Event::DeleteSurroundingText { .. } => {
+                // Surrounding text is never sent to the input method
+            }
+            zwp_text_input_v3::Event::Done { .. } => {
+                let Pending { preedit, commit } =
+                    std::mem::take(&mut *data.pending.lock().unwrap());
+
+                let Some(surface) = state
+                    .seats
+                    .iter()
+                    .find(|s| s.text_input.as_ref() == Some(text_input))
+                    .filter(|s| s.ime_enabled)
+                    .and_then(|s| s.text_input_focus.as_ref())
+                else {
+                    return;
+                };
+
+                let id = WindowId::from(surface.id().as_ptr() as u64);
+
+                if let Some(text) = commit {
+                    state.sctk_events.push(SctkEvent::Winit(
+                        id,
+                        WindowEvent::Ime(Ime::Commit(text)),
+                    ));
+                }
+
+                let (text, cursor) = preedit.unwrap_or_default();
+
+                state.sctk_events.push(SctkEvent::Winit(
+                    id,
+                    WindowEvent::Ime(Ime::Preedit(text, cursor)),
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl SctkState {
+    /// Applies the input method state requested for the given surface to the
+    /// text inputs focused on it.
+    pub(crate) fn update_text_input(&mut self, surface: &ObjectId) {
+        let Some(common) = self.surface_common(surface) else {
+            return;
+        };
+
+        let Common {
+            ime_allowed,
+            ime_purpose,
+            ime_pos,
+            ime_size,
+            ..
+        } = common.lock().unwrap().clone();
+
+        let id = WindowId::from(surface.as_ptr() as u64);
+
+        for seat in self.seats.iter_mut().filter(|s| {
+            s.text_input_focus
+                .as_ref()
+                .is_some_and(|focus| focus.id() == *surface)
+        }) {
+            let Some(text_input) = seat.text_input.as_ref() else {
+                continue;
+            };
+
+            if ime_allowed {
+                if !seat.ime_enabled {
+                    text_input.enable();
+                }
+
+                let (hint, purpose) = content_type(ime_purpose);
+
+                text_input.set_content_type(hint, purpose);
+                text_input.set_cursor_rectangle(
+                    ime_pos.x as i32,
+                    ime_pos.y as i32,
+                    ime_size.width as i32,
+                    ime_size.height as i32,
+                );
+                text_input.commit();
+
+                if !seat.ime_enabled {
+                    seat.ime_enabled = true;
+
+                    self.sctk_events.push(SctkEvent::Winit(
+                        id,
+                        WindowEvent::Ime(Ime::Enabled),
+                    ));
+                }
+            } else if seat.ime_enabled {
+                seat.ime_enabled = false;
+
+                text_input.disable();
+                text_input.commit();
+
+                self.sctk_events.push(SctkEvent::Winit(
+                    id,
+                    WindowEvent::Ime(Ime::Disabled),
+                ));
+            }
+        }
+    }
+
+    fn surface_common(&self, surface: &ObjectId) -> Option<Arc<Mutex<Common>>> {
+        self.layer_surfaces
+            .iter()
+            .find(|s| s.surface.wl_surface().id() == *surface)
+            .map(|s| s.common.clone())
+            .or_else(|| {
+                self.popups
+                    .iter()
+                    .find(|s| s.popup.wl_surface().id() == *surface)
+                    .map(|s| s.common.clone())
+            })
+            .or_else(|| {
+                self.lock_surfaces
+                    .iter()
+                    .find(|s| {
+                        s.session_lock_surface.wl_surface().id() == *surface
+                    })
+                    .map(|s| s.common.clone())
+            })
+    }
+}
+
+fn content_type(purpose: ImePurpose) -> (ContentHint, ContentPurpose) {
+    match purpose {
+        ImePurpose::Password => (
+            ContentHint::SensitiveData | ContentHint::HiddenText,
+            ContentPurpose::Password,
+        ),
+        ImePurpose::Terminal => (ContentHint::None, ContentPurpose::Terminal),
+        _ => (ContentHint::None, ContentPurpose::Normal),
+    }
+}
+
+wayland_client::delegate_dispatch!(SctkState: [ZwpTextInputManagerV3: GlobalData] => TextInputManager);
+wayland_client::delegate_dispatch!(SctkState: [ZwpTextInputV3: TextInputData] => TextInputData);
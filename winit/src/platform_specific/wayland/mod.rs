@@ -31,6 +31,9 @@ pub(crate) enum Action {
     Action(iced_runtime::platform_specific::wayland::Action),
     SetCursor(CursorIcon),
     RequestRedraw(ObjectId),
+    UpdateIme(ObjectId),
+    GrabPointer(window::Id, window::PointerGrab),
+    InhibitIdle(window::Id, bool),
     TrackWindow(Arc<dyn winit::window::Window>, window::Id),
     RemoveWindow(window::Id),
     Dropped(SurfaceIdWrapper),
@@ -46,6 +49,15 @@ impl std::fmt::Debug for Action {
             Self::RequestRedraw(arg0) => {
                 f.debug_tuple("RequestRedraw").field(arg0).finish()
             }
+            Self::UpdateIme(arg0) => {
+                f.debug_tuple("UpdateIme").field(arg0).finish()
+            }
+            Self::GrabPointer(arg0, arg1) => {
+                f.debug_tuple("GrabPointer").field(arg0).field(arg1).finish()
+            }
+            Self::InhibitIdle(arg0, arg1) => {
+                f.debug_tuple("InhibitIdle").field(arg0).field(arg1).finish()
+            }
             Self::TrackWindow(_arg0, arg1) => {
                 f.debug_tuple("TrackWindow").field(arg1).finish()
             }
@@ -141,6 +153,15 @@ impl WaylandSpecific {
         self.conn.as_ref()
     }
 
+    /// Whether the window with the given id is a layer surface, popup or
+    /// lock surface managed by SCTK, rather than a winit window.
+    pub(crate) fn is_sctk_surface(&self, id: window::Id) -> bool {
+        self.surface_ids.values().any(|surface| {
+            !matches!(surface, SurfaceIdWrapper::Window(_))
+                && surface.inner() == id
+        })
+    }
+
     pub(crate) fn handle_event<'a, P, C>(
         &mut self,
         e: SctkEvent,
@@ -32,9 +32,9 @@ use iced_graphics::Compositor;
 use iced_runtime::{
     core::{
         event::wayland,
-        keyboard, mouse, touch,
+        keyboard, mouse, pen, touch,
         window::{self, Id as SurfaceId},
-        Point,
+        Point, Vector,
     },
     keyboard::{key, Key, Location},
     user_interface, Debug,
@@ -116,6 +116,19 @@ pub enum SctkEvent {
         seat_id: WlSeat,
         surface: WlSurface,
     },
+    RelativePointerEvent {
+        delta: (f64, f64),
+        surface: WlSurface,
+        seat_id: WlSeat,
+    },
+    PointerGestureEvent {
+        gesture: mouse::Gesture,
+        surface: WlSurface,
+    },
+    TabletToolEvent {
+        variant: pen::Event,
+        surface: WlSurface,
+    },
     // TODO data device & touch
 
     //
@@ -200,7 +213,17 @@ pub enum SctkEvent {
         surface: WlSurface,
     },
     SessionUnlocked,
+
+    /// idle notification events
+    Idled,
+    Resumed,
+
     SurfaceScaleFactorChanged(f64, WlSurface, window::Id),
+    /// Whether the compositor draws the decorations of a winit toplevel.
+    ToplevelDecorations {
+        id: window::Id,
+        server_side: bool,
+    },
     Winit(WindowId, WindowEvent),
     Subcompositor(SubsurfaceState),
 }
@@ -646,6 +669,83 @@ impl SctkEvent {
                 surface_ids.get(&surface.id()).map(|id| id.inner()),
                 iced_runtime::core::Event::Touch(variant),
             )),
+            SctkEvent::RelativePointerEvent {
+                delta,
+                surface,
+                seat_id: _,
+            } => events.push((
+                surface_ids.get(&surface.id()).map(|id| id.inner()),
+                iced_runtime::core::Event::Mouse(mouse::Event::RawMotion {
+                    delta: Vector::new(delta.0 as f32, delta.1 as f32),
+                }),
+            )),
+            SctkEvent::PointerGestureEvent { gesture, surface } => {
+                // Gestures are not supported by winit, so they are reported
+                // for its windows too
+                let id = surface_ids
+                    .get(&surface.id())
+                    .map(|id| id.inner())
+                    .or_else(|| {
+                        window_manager
+                            .get_mut_alias(WindowId::from(
+                                surface.id().as_ptr() as u64,
+                            ))
+                            .map(|(id, _)| id)
+                    });
+
+                events.push((
+                    id,
+                    iced_runtime::core::Event::Mouse(mouse::Event::Gesture(
+                        gesture,
+                    )),
+                ));
+            }
+            SctkEvent::TabletToolEvent { variant, surface } => {
+                // Tablets are not supported by winit either, and binding the
+                // protocol stops the compositor from emulating a pointer, so
+                // the pen also drives the mouse
+                let id = surface_ids
+                    .get(&surface.id())
+                    .map(|id| id.inner())
+                    .or_else(|| {
+                        window_manager
+                            .get_mut_alias(WindowId::from(
+                                surface.id().as_ptr() as u64,
+                            ))
+                            .map(|(id, _)| id)
+                    });
+                let position = variant.pen().position;
+
+                let mouse_events = match variant {
+                    pen::Event::Entered(_) => vec![
+                        mouse::Event::CursorEntered,
+                        mouse::Event::CursorMoved { position },
+                    ],
+                    pen::Event::Moved(_) => {
+                        vec![mouse::Event::CursorMoved { position }]
+                    }
+                    pen::Event::Touched(_) => {
+                        vec![mouse::Event::ButtonPressed(mouse::Button::Left)]
+                    }
+                    pen::Event::Lifted(_) => {
+                        vec![mouse::Event::ButtonReleased(mouse::Button::Left)]
+                    }
+                    pen::Event::Left(_) => vec![mouse::Event::CursorLeft],
+                    pen::Event::ButtonPressed(..)
+                    | pen::Event::ButtonReleased(..) => Vec::new(),
+                };
+
+                if let Some(w) = id.and_then(|id| window_manager.get_mut(id)) {
+                    w.state.set_logical_cursor_pos(
+                        (position.x as f64, position.y as f64).into(),
+                    );
+                }
+
+                events.push((id, iced_runtime::core::Event::Pen(variant)));
+                events.extend(mouse_events.into_iter().map(|event| {
+                    (id, iced_runtime::core::Event::Mouse(event))
+                }));
+            }
             SctkEvent::WindowEvent { .. } => {}
             SctkEvent::LayerSurfaceEvent {
                 variant,
@@ -743,6 +843,7 @@ impl SctkEvent {
                         surface_id, sctk_winit, program, compositor,
                         false, // TODO do we want to get this value here?
                         0,
+                        None,
                     );
                     _ = surface_ids.insert(object_id, wrapper.clone());
                     let logical_size = window.size();
@@ -765,6 +866,7 @@ impl SctkEvent {
                         debug,
                         surface_id,
                         window.raw.clone(),
+                        None,
                         window.prev_dnd_destination_rectangles_count,
                         clipboard,
                     );
@@ -983,6 +1085,7 @@ impl SctkEvent {
                             surface_id, sctk_winit, program, compositor,
                             false, // TODO do we want to get this value here?
                             0,
+                            None,
                         );
                         let logical_size = window.size();
 
@@ -994,6 +1097,7 @@ impl SctkEvent {
                             debug,
                             surface_id,
                             window.raw.clone(),
+                            None,
                             window.prev_dnd_destination_rectangles_count,
                             clipboard,
                         );
@@ -1134,6 +1238,22 @@ impl SctkEvent {
                     )),
                 ),
             )),
+            SctkEvent::Idled => events.push((
+                None,
+                iced_runtime::core::Event::PlatformSpecific(
+                    PlatformSpecific::Wayland(wayland::Event::Idle(
+                        wayland::IdleEvent::Idled,
+                    )),
+                ),
+            )),
+            SctkEvent::Resumed => events.push((
+                None,
+                iced_runtime::core::Event::PlatformSpecific(
+                    PlatformSpecific::Wayland(wayland::Event::Idle(
+                        wayland::IdleEvent::Resumed,
+                    )),
+                ),
+            )),
             SctkEvent::SessionLockSurfaceCreated {
                 queue_handle,
                 surface,
@@ -1207,6 +1327,7 @@ impl SctkEvent {
                     surface_id, sctk_winit, program, compositor,
                     false, // TODO do we want to get this value here?
                     0,
+                    None,
                 );
                 _ = surface_ids.insert(object_id, wrapper.clone());
                 let logical_size = window.size();
@@ -1221,6 +1342,7 @@ impl SctkEvent {
                         debug,
                         surface_id,
                         window.raw.clone(),
+                        None,
                         window.prev_dnd_destination_rectangles_count,
                         clipboard,
                     ),
@@ -1277,6 +1399,23 @@ impl SctkEvent {
                     w.state.update_scale_factor(scale);
                 }
             }
+            SctkEvent::ToplevelDecorations { id, server_side } => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.server_side_decorations = Some(server_side);
+                    window.set_decorations(
+                        window.decorations != window::Decorations::None,
+                    );
+
+                    events.push((
+                        Some(id),
+                        iced_runtime::core::Event::Window(
+                            window::Event::DecorationsChanged(
+                                window.decorations,
+                            ),
+                        ),
+                    ));
+                }
+            }
             SctkEvent::Subcompositor(s) => {
                 *subsurface_state = Some(s);
             }
@@ -48,6 +48,10 @@ use wayland_protocols::wp::{
         wp_alpha_modifier_surface_v1::WpAlphaModifierSurfaceV1,
         wp_alpha_modifier_v1::WpAlphaModifierV1,
     },
+    idle_inhibit::zv1::client::{
+        zwp_idle_inhibit_manager_v1::ZwpIdleInhibitManagerV1,
+        zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1,
+    },
     linux_dmabuf::zv1::client::{
         zwp_linux_buffer_params_v1::{self, ZwpLinuxBufferParamsV1},
         zwp_linux_dmabuf_v1::{self, ZwpLinuxDmabufV1},
@@ -365,6 +369,7 @@ pub struct SubsurfaceState {
     pub wl_shm: WlShm,
     pub wp_dmabuf: Option<ZwpLinuxDmabufV1>,
     pub wp_alpha_modifier: Option<WpAlphaModifierV1>,
+    pub wp_idle_inhibit: Option<ZwpIdleInhibitManagerV1>,
     pub qh: QueueHandle<SctkState>,
     pub(crate) buffers: HashMap<WeakBufferSource, Vec<WlBuffer>>,
     pub unmapped_subsurfaces: Vec<SubsurfaceInstance>,
@@ -428,6 +433,7 @@ impl SubsurfaceState {
             wl_subsurface,
             wp_viewport,
             wp_alpha_modifier_surface,
+            idle_inhibitor: None,
             wl_buffer: None,
             bounds: None,
             transform: wl_output::Transform::Normal,
@@ -528,6 +534,7 @@ pub(crate) struct SubsurfaceInstance {
     wl_subsurface: WlSubsurface,
     wp_viewport: WpViewport,
     wp_alpha_modifier_surface: Option<WpAlphaModifierSurfaceV1>,
+    idle_inhibitor: Option<ZwpIdleInhibitorV1>,
     wl_buffer: Option<WlBuffer>,
     bounds: Option<Rectangle<f32>>,
     transform: wl_output::Transform,
@@ -603,6 +610,18 @@ impl SubsurfaceInstance {
             wp_alpha_modifier_surface.set_multiplier(alpha);
         }
 
+        if info.inhibit_idle != self.idle_inhibitor.is_some() {
+            if let Some(idle_inhibitor) = self.idle_inhibitor.take() {
+                idle_inhibitor.destroy();
+            } else if let Some(wp_idle_inhibit) = &state.wp_idle_inhibit {
+                self.idle_inhibitor = Some(wp_idle_inhibit.create_inhibitor(
+                    &self.wl_surface,
+                    &state.qh,
+                    (),
+                ));
+            }
+        }
+
         self.wl_buffer = Some(buffer);
         self.bounds = Some(info.bounds);
         self.transform = info.transform;
@@ -616,6 +635,9 @@ impl SubsurfaceInstance {
 
 impl Drop for SubsurfaceInstance {
     fn drop(&mut self) {
+        if let Some(idle_inhibitor) = self.idle_inhibitor.as_ref() {
+            idle_inhibitor.destroy();
+        }
         self.wp_viewport.destroy();
         self.wl_subsurface.destroy();
         self.wl_surface.destroy();
@@ -631,6 +653,7 @@ pub(crate) struct SubsurfaceInfo {
     pub bounds: Rectangle<f32>,
     pub alpha: f32,
     pub transform: wl_output::Transform,
+    pub inhibit_idle: bool,
 }
 
 thread_local! {
@@ -649,6 +672,7 @@ pub struct Subsurface {
     content_fit: ContentFit,
     alpha: f32,
     transform: wl_output::Transform,
+    inhibit_idle: bool,
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Subsurface
@@ -714,6 +738,7 @@ where
                 bounds: layout.bounds(),
                 alpha: self.alpha,
                 transform: self.transform,
+                inhibit_idle: self.inhibit_idle,
             })
         });
     }
@@ -729,6 +754,7 @@ impl Subsurface {
             content_fit: ContentFit::Contain,
             alpha: 1.,
             transform: wl_output::Transform::Normal,
+            inhibit_idle: false,
         }
     }
 
@@ -756,6 +782,12 @@ impl Subsurface {
         self.transform = transform;
         self
     }
+
+    /// Prevents the screen from blanking while the subsurface is visible.
+    pub fn inhibit_idle(mut self, inhibit_idle: bool) -> Self {
+        self.inhibit_idle = inhibit_idle;
+        self
+    }
 }
 
 impl<Message, Theme, Renderer> From<Subsurface>
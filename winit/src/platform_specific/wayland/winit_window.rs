@@ -48,6 +48,11 @@ impl SctkWinitWindow {
             queue_handle,
         })
     }
+
+    fn update_ime(&self) {
+        let surface = self.surface.wl_surface();
+        _ = self.tx.send(Action::UpdateIme(surface.id()));
+    }
 }
 
 impl winit::window::Window for SctkWinitWindow {
@@ -226,15 +231,25 @@ impl winit::window::Window for SctkWinitWindow {
         position: winit::dpi::Position,
         size: winit::dpi::Size,
     ) {
-        todo!()
+        let mut guard = self.common.lock().unwrap();
+        let scale_factor = guard.fractional_scale.unwrap_or(1.);
+        guard.ime_pos = position.to_logical(scale_factor);
+        guard.ime_size = size.to_logical(scale_factor);
+        drop(guard);
+
+        self.update_ime();
     }
 
     fn set_ime_allowed(&self, allowed: bool) {
-        todo!()
+        self.common.lock().unwrap().ime_allowed = allowed;
+
+        self.update_ime();
     }
 
     fn set_ime_purpose(&self, purpose: winit::window::ImePurpose) {
-        todo!()
+        self.common.lock().unwrap().ime_purpose = purpose;
+
+        self.update_ime();
     }
 
     fn set_blur(&self, blur: bool) {
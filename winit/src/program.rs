@@ -1,10 +1,14 @@
 //! Create interactive, native cross-platform applications for WGPU.
 #[path = "application/drag_resize.rs"]
 mod drag_resize;
+pub(crate) mod decorations;
+mod geometry;
 mod state;
 mod window_manager;
 
-pub use runtime::{default, Appearance, DefaultStyle};
+pub use runtime::{
+    default, default_header, Appearance, DefaultStyle, HeaderAppearance,
+};
 use winit::dpi::PhysicalSize;
 use winit::event_loop::OwnedDisplayHandle;
 
@@ -18,7 +22,7 @@ use crate::core::widget::Operation;
 use crate::core::window;
 use crate::core::Clipboard as CoreClipboard;
 use crate::core::Length;
-use crate::core::{Element, Point, Size};
+use crate::core::{Element, Point, Size, Vector};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -29,6 +33,7 @@ use crate::graphics;
 use crate::graphics::{compositor, Compositor};
 use crate::platform_specific;
 use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::record::{self, Recorder};
 use crate::runtime::Debug;
 use crate::runtime::{self, Action, Task};
 use crate::{Clipboard, Error, Proxy, Settings};
@@ -47,6 +52,7 @@ use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::mem::ManuallyDrop;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -60,7 +66,8 @@ use std::time::Duration;
 /// [`Task`] in some of its methods.
 ///
 /// When using a [`Program`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles a widget
+/// inspector, where `Ctrl+F12` collapses the hovered widget in the tree.
 pub trait Program
 where
     Self: Sized,
@@ -154,6 +161,17 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Returns the [`Recorder`] of the inputs of the [`Program`], if any.
+    ///
+    /// It is called once, when the [`Program`] starts. Every replayable
+    /// event, window size and message not produced by a widget is recorded,
+    /// so the session can later be re-run with a [`record::Replay`].
+    ///
+    /// By default, nothing is recorded.
+    fn recorder(&self) -> Option<Recorder<Self::Message>> {
+        None
+    }
 }
 
 /// Runs a [`Program`] with an executor, compositor, and the provided
@@ -338,6 +356,17 @@ where
             }
         }
 
+        fn device_event(
+            &mut self,
+            event_loop: &dyn winit::event_loop::ActiveEventLoop,
+            _device_id: winit::event::DeviceId,
+            event: winit::event::DeviceEvent,
+        ) {
+            if let winit::event::DeviceEvent::MouseMotion { delta } = event {
+                self.process_event(event_loop, Some(Event::RawMotion(delta)));
+            }
+        }
+
         fn about_to_wait(
             &mut self,
             event_loop: &dyn winit::event_loop::ActiveEventLoop,
@@ -474,11 +503,41 @@ where
                             }
                             Control::CreateWindow {
                                 id,
-                                settings,
+                                mut settings,
                                 title,
                                 monitor,
                                 on_open,
                             } => {
+                                let geometry =
+                                    settings.geometry_id.as_deref().and_then(
+                                        |geometry_id| {
+                                            geometry::path(
+                                                self.id.as_deref(),
+                                                geometry_id,
+                                            )
+                                        },
+                                    );
+
+                                let restored = geometry
+                                    .as_deref()
+                                    .and_then(geometry::load)
+                                    .map(|restored| {
+                                        let monitors: Vec<_> = event_loop
+                                            .available_monitors()
+                                            .filter_map(|monitor| {
+                                                geometry::Monitor::from_winit(
+                                                    &monitor,
+                                                )
+                                            })
+                                            .collect();
+
+                                        restored.clamp(&monitors)
+                                    });
+
+                                if let Some(restored) = restored {
+                                    settings.size = restored.size;
+                                }
+
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
                                 let resize_border = settings.resize_border;
@@ -499,6 +558,18 @@ where
                                     )
                                     .with_visible(false);
 
+                                let window_attributes = match restored
+                                    .and_then(|restored| restored.position)
+                                {
+                                    Some(position) => window_attributes
+                                        .with_position(
+                                            winit::dpi::PhysicalPosition::new(
+                                                position.x, position.y,
+                                            ),
+                                        ),
+                                    None => window_attributes,
+                                };
+
                                 #[cfg(target_arch = "wasm32")]
                                 let window_attributes = {
                                     use winit::platform::web::WindowAttributesExtWebSys;
@@ -514,6 +585,12 @@ where
                                         .expect("Create window"),
                                 );
 
+                                if restored
+                                    .is_some_and(|restored| restored.maximized)
+                                {
+                                    window.set_maximized(true);
+                                }
+
                                 #[cfg(target_arch = "wasm32")]
                                 {
                                     use winit::platform::web::WindowExtWebSys;
@@ -569,6 +646,7 @@ where
                                         make_visible: visible,
                                         on_open,
                                         resize_border,
+                                        geometry,
                                     }),
                                 );
                             }
@@ -657,6 +735,7 @@ pub(crate) enum Event<Message: 'static> {
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
         resize_border: u32,
+        geometry: Option<PathBuf>,
     },
     Dnd(dnd::DndEvent<dnd::DndSurface>),
     #[cfg(feature = "a11y")]
@@ -664,6 +743,7 @@ pub(crate) enum Event<Message: 'static> {
     #[cfg(feature = "a11y")]
     AccessibilityEnabled(bool),
     Winit(winit::window::WindowId, winit::event::WindowEvent),
+    RawMotion((f64, f64)),
     AboutToWait,
     UserEvent(Action<Message>),
     NewEvents(winit::event::StartCause),
@@ -733,6 +813,7 @@ async fn run_instance<'a, P, C>(
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut actions = 0;
+    let mut recorder = program.recorder();
 
     #[cfg(feature = "a11y")]
     let (mut adapters, mut a11y_enabled) = if let Some((main_id, title, raw)) =
@@ -994,6 +1075,7 @@ async fn run_instance<'a, P, C>(
                 make_visible,
                 on_open,
                 resize_border,
+                geometry,
             } => {
                 let window = window_manager.insert(
                     id,
@@ -1002,7 +1084,13 @@ async fn run_instance<'a, P, C>(
                     &mut compositor,
                     exit_on_close_request,
                     resize_border,
+                    geometry,
                 );
+                window.decorations = if window.raw.is_decorated() {
+                    window::Decorations::ServerSide
+                } else {
+                    window::Decorations::None
+                };
                 #[cfg(feature = "wayland")]
                 platform_specific_handler.send_wayland(
                     platform_specific::Action::TrackWindow(
@@ -1060,6 +1148,7 @@ async fn run_instance<'a, P, C>(
                         &mut debug,
                         id,
                         window.raw.clone(),
+                        window.header(),
                         window.prev_dnd_destination_rectangles_count,
                         &mut clipboard,
                     ),
@@ -1078,6 +1167,16 @@ async fn run_instance<'a, P, C>(
                     }),
                 ));
 
+                // Negotiated decorations are reported once they are known
+                if !platform_specific_handler.negotiates_decorations() {
+                    events.push((
+                        Some(id),
+                        core::Event::Window(window::Event::DecorationsChanged(
+                            window.decorations,
+                        )),
+                    ));
+                }
+
                 if clipboard.window_id().is_none() {
                     clipboard = Clipboard::connect(
                         window.raw.clone(),
@@ -1093,6 +1192,13 @@ async fn run_instance<'a, P, C>(
             }
             Event::UserEvent(action) => {
                 rebuild_a11y_tree = true;
+
+                if let Action::Output(message) = &action {
+                    record(&mut recorder, |recorder| {
+                        recorder.message(message)
+                    });
+                }
+
                 let exited = run_action(
                     action,
                     &program,
@@ -1157,6 +1263,10 @@ async fn run_instance<'a, P, C>(
                             window::Event::RedrawRequested(Instant::now()),
                         );
 
+                        record(&mut recorder, |recorder| {
+                            recorder.event(id, &redraw_event)
+                        });
+
                         let cursor = window.state.cursor();
 
                         let ui = user_interfaces
@@ -1171,6 +1281,14 @@ async fn run_instance<'a, P, C>(
                             &mut messages,
                         );
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = &ui_state
+                        {
+                            window.request_input_method(input_method);
+                        }
+
                         debug.draw_started();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
@@ -1216,6 +1334,7 @@ async fn run_instance<'a, P, C>(
                             .start_send(Control::ChangeFlow(match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.request_redraw();
@@ -1302,6 +1421,17 @@ async fn run_instance<'a, P, C>(
                                 window.state.viewport_version();
                         }
 
+                        if debug.is_inspecting() {
+                            if let Some(ui) = user_interfaces.get(&id) {
+                                debug.inspect(
+                                    ui.inspect(),
+                                    window.state.cursor(),
+                                );
+                            }
+
+                            debug.draw_inspector(&mut window.renderer);
+                        }
+
                         window.raw.pre_present_notify();
                         debug.render_started();
                         match compositor.present(
@@ -1412,6 +1542,23 @@ async fn run_instance<'a, P, C>(
                                 &window_event,
                                 &mut debug,
                             );
+
+                            // Configuring the decoration mode of a toplevel
+                            // resizes its surface
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::SurfaceResized(_)
+                            ) && window.sync_decorations()
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        window::Event::DecorationsChanged(
+                                            window.decorations,
+                                        ),
+                                    ),
+                                ));
+                            }
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
@@ -1424,6 +1571,23 @@ async fn run_instance<'a, P, C>(
                     _ => {}
                 }
             }
+            Event::RawMotion((x, y)) => {
+                let event = core::Event::Mouse(mouse::Event::RawMotion {
+                    delta: Vector::new(x as f32, y as f32),
+                });
+
+                // Device events are not tied to a window, so they go to the
+                // focused one, as long as it has grabbed the pointer
+                for (id, window) in window_manager.iter_mut() {
+                    if !platform_specific_handler.has_raw_motion(id)
+                        && window.pointer_grab
+                            != core::window::PointerGrab::None
+                        && window.raw.has_focus()
+                    {
+                        events.push((Some(id), event.clone()));
+                    }
+                }
+            }
             Event::AboutToWait => {
                 let skip = events.is_empty() && messages.is_empty();
                 if skip
@@ -1435,6 +1599,7 @@ async fn run_instance<'a, P, C>(
                 debug.event_processing_started();
                 let mut uis_stale = false;
                 let mut resized = false;
+                let mut decoration_actions = Vec::new();
                 for (id, window) in window_manager.iter_mut() {
                     if skip && !window.resize_enabled {
                         continue;
@@ -1450,6 +1615,13 @@ async fn run_instance<'a, P, C>(
                         }
                     });
                     let no_window_events = window_events.is_empty();
+
+                    for event in &window_events {
+                        record(&mut recorder, |recorder| {
+                            recorder.event(id, event)
+                        });
+                    }
+
                     #[cfg(feature = "wayland")]
                     window_events.push(core::Event::PlatformSpecific(
                         core::event::PlatformSpecific::Wayland(
@@ -1467,6 +1639,36 @@ async fn run_instance<'a, P, C>(
                             &mut messages,
                         );
 
+                    for request in window.header.take_requests() {
+                        let action = match request {
+                            decorations::Request::Drag => {
+                                runtime::window::Action::Drag(id)
+                            }
+                            decorations::Request::Minimize => {
+                                runtime::window::Action::Minimize(id, true)
+                            }
+                            decorations::Request::ToggleMaximize => {
+                                runtime::window::Action::ToggleMaximize(id)
+                            }
+                            decorations::Request::Close
+                                if !window.exit_on_close_request =>
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        window::Event::CloseRequested,
+                                    ),
+                                ));
+                                continue;
+                            }
+                            decorations::Request::Close => {
+                                runtime::window::Action::Close(id)
+                            }
+                        };
+
+                        decoration_actions.push(Action::Window(action));
+                    }
+
                     let mut needs_redraw =
                         !no_window_events || !messages.is_empty();
 
@@ -1513,6 +1715,17 @@ async fn run_instance<'a, P, C>(
                             matches!(ui_state, user_interface::State::Outdated);
                     }
 
+                    // The header bar of the window comes and goes with its
+                    // decorations
+                    uis_stale |= window_events.iter().any(|event| {
+                        matches!(
+                            event,
+                            core::Event::Window(
+                                window::Event::DecorationsChanged(_)
+                            )
+                        )
+                    });
+
                     for (event, status) in
                         window_events.into_iter().zip(statuses.into_iter())
                     {
@@ -1524,6 +1737,24 @@ async fn run_instance<'a, P, C>(
                     }
                 }
 
+                for action in decoration_actions {
+                    _ = run_action(
+                        action,
+                        &program,
+                        &mut compositor,
+                        &mut events,
+                        &mut messages,
+                        &mut clipboard,
+                        &mut control_sender,
+                        &mut debug,
+                        &mut user_interfaces,
+                        &mut window_manager,
+                        &mut ui_caches,
+                        &mut is_window_opening,
+                        &mut platform_specific_handler,
+                    );
+                }
+
                 if !resized && skip {
                     continue;
                 }
@@ -1601,6 +1832,10 @@ async fn run_instance<'a, P, C>(
                         window::Event::RedrawRequested(Instant::now()),
                     );
 
+                    record(&mut recorder, |recorder| {
+                        recorder.event(id, &redraw_event)
+                    });
+
                     let cursor = window.state.cursor();
 
                     let ui = user_interfaces
@@ -1615,6 +1850,13 @@ async fn run_instance<'a, P, C>(
                         &mut messages,
                     );
 
+                    if let user_interface::State::Updated {
+                        input_method, ..
+                    } = &ui_state
+                    {
+                        window.request_input_method(input_method);
+                    }
+
                     let new_mouse_interaction = {
                         let state = &window.state;
 
@@ -1662,6 +1904,7 @@ async fn run_instance<'a, P, C>(
                         match ui_state {
                             user_interface::State::Updated {
                                 redraw_request: Some(redraw_request),
+                                ..
                             } => match redraw_request {
                                 window::RedrawRequest::NextFrame => {
                                     window.request_redraw();
@@ -1859,6 +2102,7 @@ pub(crate) fn build_user_interface<'a, P: Program>(
     debug: &mut Debug,
     id: window::Id,
     raw: Arc<dyn winit::window::Window>,
+    header: Option<decorations::Header>,
     prev_dnd_destination_rectangles_count: usize,
     clipboard: &mut Clipboard,
 ) -> UserInterface<'a, P::Message, P::Theme, P::Renderer>
@@ -1866,7 +2110,15 @@ where
     P::Theme: DefaultStyle,
 {
     debug.view_started();
-    let view = program.view(id);
+    let view = match header {
+        Some(header) => Element::from(decorations::Decorated::new(
+            program.view(id),
+            header,
+            program.title(id),
+            raw.is_maximized(),
+        )),
+        None => program.view(id),
+    };
     debug.view_finished();
 
     debug.layout_started();
@@ -1887,6 +2139,23 @@ where
     user_interface
 }
 
+/// Records some input with the [`Recorder`], if any.
+///
+/// The recording is stopped if it fails.
+fn record<Message>(
+    recorder: &mut Option<Recorder<Message>>,
+    f: impl FnOnce(&mut Recorder<Message>) -> Result<(), record::Error>,
+) {
+    let Some(current) = recorder else {
+        return;
+    };
+
+    if let Err(error) = f(current) {
+        log::error!("Recording stopped: {error}");
+        *recorder = None;
+    }
+}
+
 fn update<P: Program, E: Executor>(
     program: &mut P,
     runtime: &mut Runtime<E, Proxy<P::Message>, Action<P::Message>>,
@@ -2133,7 +2402,15 @@ where
             }
             window::Action::ToggleDecorations(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    window.raw.set_decorations(!window.raw.is_decorated());
+                    window.set_decorations(
+                        window.decorations == window::Decorations::None,
+                    );
+                    events.push((
+                        Some(id),
+                        core::Event::Window(window::Event::DecorationsChanged(
+                            window.decorations,
+                        )),
+                    ));
                 }
             }
             window::Action::RequestUserAttention(id, attention_type) => {
@@ -2221,6 +2498,38 @@ where
                     window.raw.set_blur(false);
                 }
             }
+            window::Action::GrabPointer(id, grab) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    if platform_specific.grab_pointer(id, grab) {
+                        window.pointer_grab = grab;
+                    } else {
+                        let (mode, fallback) = conversion::cursor_grab(grab);
+
+                        match window
+                            .raw
+                            .set_cursor_grab(mode)
+                            .or_else(|_| window.raw.set_cursor_grab(fallback))
+                        {
+                            Ok(()) => {
+                                window.pointer_grab = grab;
+                            }
+                            Err(error) => {
+                                log::warn!(
+                                    "Failed to grab the pointer of {id:?}: \
+                                    {error}"
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            window::Action::InhibitIdle(id, inhibit) => {
+                if window_manager.get_mut(id).is_some()
+                    && !platform_specific.inhibit_idle(id, inhibit)
+                {
+                    log::warn!("Idle inhibit is not supported for {id:?}");
+                }
+            }
         },
         Action::System(action) => match action {
             system::Action::QueryInformation(_channel) => {
@@ -2317,6 +2626,7 @@ where
                 debug,
                 id,
                 window.raw.clone(),
+                window.header(),
                 window.prev_dnd_destination_rectangles_count,
                 clipboard,
             );
@@ -0,0 +1,655 @@
+//! Draw the decorations of windows whose window system does not.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping, Text, Wrapping};
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Border, Clipboard, Color, Element, Layout, Length, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::program::DefaultStyle;
+
+use std::sync::{Arc, Mutex};
+
+/// The height of the header bar, in logical pixels.
+const HEADER_HEIGHT: f32 = 32.0;
+
+/// The size of the icons of the header bar buttons.
+const ICON_SIZE: f32 = 10.0;
+
+/// A request of a header bar to the window system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Request {
+    /// Start moving the window.
+    Drag,
+    /// Minimize the window.
+    Minimize,
+    /// Maximize or restore the window.
+    ToggleMaximize,
+    /// Close the window.
+    Close,
+}
+
+/// The header bar of a window, which collects the requests the user makes
+/// through it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Header {
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Header {
+    /// Takes the requests made through the header bar since the last call.
+    pub(crate) fn take_requests(&self) -> Vec<Request> {
+        std::mem::take(&mut *self.requests.lock().expect("Lock requests"))
+    }
+
+    fn request(&self, request: Request) {
+        self.requests.lock().expect("Lock requests").push(request);
+    }
+}
+
+/// A button of the header bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Button {
+    Minimize,
+    Maximize,
+    Close,
+}
+
+impl Button {
+    const ALL: [Button; 3] =
+        [Button::Minimize, Button::Maximize, Button::Close];
+
+    /// The bounds of the button in a header bar with the given bounds.
+    fn bounds(self, header: Rectangle) -> Rectangle {
+        let index = match self {
+            Button::Minimize => 3.0,
+            Button::Maximize => 2.0,
+            Button::Close => 1.0,
+        };
+
+        Rectangle {
+            x: header.x + header.width - index * HEADER_HEIGHT,
+            y: header.y,
+            width: HEADER_HEIGHT,
+            height: HEADER_HEIGHT,
+        }
+    }
+
+    fn request(self) -> Request {
+        match self {
+            Button::Minimize => Request::Minimize,
+            Button::Maximize => Request::ToggleMaximize,
+            Button::Close => Request::Close,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pressed: Option<Button>,
+    last_click: Option<mouse::Click>,
+}
+
+/// The content of a window, with a header bar on top of it.
+///
+/// It is used for windows whose decorations are drawn by the client.
+///
+/// The header bar shows the title of the window and lets the user drag,
+/// minimize, maximize and close it. Its colors follow the
+/// [`DefaultStyle::header_style`] of the theme.
+#[allow(missing_debug_implementations)]
+pub(crate) struct Decorated<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    header: Header,
+    title: String,
+    is_maximized: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Decorated<'a, Message, Theme, Renderer> {
+    /// Wraps the content of a window with the given [`Header`].
+    pub(crate) fn new(
+        content: Element<'a, Message, Theme, Renderer>,
+        header: Header,
+        title: String,
+        is_maximized: bool,
+    ) -> Self {
+        Self {
+            content,
+            header,
+            title,
+            is_maximized,
+        }
+    }
+}
+
+/// The bounds of the header bar of a window with the given bounds.
+fn header_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        height: HEADER_HEIGHT,
+        ..bounds
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Decorated<'a, Message, Theme, Renderer>
+where
+    Theme: DefaultStyle,
+    Renderer: renderer::Renderer + text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, HEADER_HEIGHT)),
+        );
+        let size = content.size();
+
+        layout::Node::with_children(
+            Size::new(size.width, size.height + HEADER_HEIGHT),
+            vec![content.move_to(Point::new(0.0, HEADER_HEIGHT))],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let header = header_bounds(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(header) {
+                    if let Some(button) = Button::ALL
+                        .into_iter()
+                        .find(|button| button.bounds(header).contains(position))
+                    {
+                        state.pressed = Some(button);
+                    } else {
+                        let click = mouse::Click::new(
+                            position,
+                            mouse::Button::Left,
+                            state.last_click,
+                        );
+
+                        match click.kind() {
+                            mouse::click::Kind::Double => {
+                                self.header.request(Request::ToggleMaximize);
+                            }
+                            _ => self.header.request(Request::Drag),
+                        }
+                        state.last_click = Some(click);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(button) = state.pressed.take() {
+                    if cursor.is_over(button.bounds(header)) {
+                        self.header.request(button.request());
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let header = header_bounds(layout.bounds());
+
+        if Button::ALL
+            .into_iter()
+            .any(|button| cursor.is_over(button.bounds(header)))
+        {
+            return mouse::Interaction::Pointer;
+        }
+        if cursor.is_over(header) {
+            return mouse::Interaction::Idle;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        let appearance = theme.header_style();
+        let header = header_bounds(layout.bounds());
+        let text_color = appearance.text_color;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header,
+                ..renderer::Quad::default()
+            },
+            appearance.background_color,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: header.y + header.height - 1.0,
+                    height: 1.0,
+                    ..header
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.border_color,
+        );
+
+        let title_width = (header.width - 6.0 * HEADER_HEIGHT).max(0.0);
+
+        renderer.fill_text(
+            Text {
+                content: self.title.clone(),
+                bounds: Size::new(title_width, HEADER_HEIGHT),
+                size: Pixels(14.0),
+                line_height: LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+                wrapping: Wrapping::None,
+            },
+            header.center(),
+            text_color,
+            Rectangle {
+                x: header.center_x() - title_width / 2.0,
+                width: title_width,
+                ..header
+            },
+        );
+
+        for button in Button::ALL {
+            let bounds = button.bounds(header);
+            let is_hovered = cursor.is_over(bounds);
+            let icon_color = if is_hovered && button == Button::Close {
+                appearance.hovered_close_icon_color
+            } else {
+                text_color
+            };
+
+            if is_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    if button == Button::Close {
+                        appearance.hovered_close_color
+                    } else {
+                        appearance.hovered_button_color
+                    },
+                );
+            }
+
+            let icon = Rectangle::new(
+                bounds.center() - Vector::new(ICON_SIZE / 2.0, ICON_SIZE / 2.0),
+                Size::new(ICON_SIZE, ICON_SIZE),
+            );
+
+            match button {
+                Button::Minimize => {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: icon.y + icon.height - 1.5,
+                                height: 1.5,
+                                ..icon
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        icon_color,
+                    );
+                }
+                Button::Maximize => {
+                    let squares: &[Rectangle] = if self.is_maximized {
+                        &[
+                            Rectangle {
+                                x: icon.x + 2.0,
+                                width: icon.width - 2.0,
+                                height: icon.height - 2.0,
+                                ..icon
+                            },
+                            Rectangle {
+                                y: icon.y + 2.0,
+                                width: icon.width - 2.0,
+                                height: icon.height - 2.0,
+                                ..icon
+                            },
+                        ]
+                    } else {
+                        &[icon]
+                    };
+
+                    for square in squares {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: *square,
+                                border: Border::default()
+                                    .color(icon_color)
+                                    .width(1.5),
+                                ..renderer::Quad::default()
+                            },
+                            Color::TRANSPARENT,
+                        );
+                    }
+                }
+                Button::Close => {
+                    renderer.fill_text(
+                        Text {
+                            content: String::from("×"),
+                            bounds: bounds.size(),
+                            size: Pixels(18.0),
+                            line_height: LineHeight::default(),
+                            font: renderer.default_font(),
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: Shaping::Basic,
+                            wrapping: Wrapping::None,
+                        },
+                        bounds.center(),
+                        icon_color,
+                        bounds,
+                    );
+                }
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut crate::core::clipboard::DndDestinationRectangles,
+    ) {
+        if let (Some(state), Some(layout)) =
+            (state.children.first(), layout.children().next())
+        {
+            self.content.as_widget().drag_destinations(
+                state,
+                layout,
+                renderer,
+                dnd_rectangles,
+            );
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content.as_widget().a11y_nodes(
+            layout.children().next().unwrap(),
+            &state.children[0],
+            cursor,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Decorated<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: DefaultStyle + 'a,
+    Renderer: renderer::Renderer + text::Renderer + 'a,
+{
+    fn from(decorated: Decorated<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(decorated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::Theme;
+
+    /// Content that fills the window below the header bar.
+    struct Content;
+
+    impl Widget<(), Theme, ()> for Content {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fill, Length::Fill)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    /// Sends the given events to a 300x200 decorated window, with the
+    /// cursor at the given positions, and returns the requests of its
+    /// header bar.
+    fn run(events: impl IntoIterator<Item = (Event, Point)>) -> Vec<Request> {
+        let header = Header::default();
+        let mut decorated: Element<'_, (), Theme, ()> = Decorated::new(
+            Element::new(Content),
+            header.clone(),
+            String::from("Decorated"),
+            false,
+        )
+        .into();
+
+        let mut tree = Tree::new(&decorated);
+        let node = decorated.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(300.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = decorated.as_widget_mut().on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+
+        header.take_requests()
+    }
+
+    #[test]
+    fn buttons_request_their_action_when_released_over_them() {
+        let minimize = Point::new(220.0, 16.0);
+        let maximize = Point::new(250.0, 16.0);
+        let close = Point::new(290.0, 16.0);
+
+        assert_eq!(
+            run([
+                (press(), minimize),
+                (release(), minimize),
+                (press(), maximize),
+                (release(), maximize),
+                (press(), close),
+                (release(), close),
+            ]),
+            vec![Request::Minimize, Request::ToggleMaximize, Request::Close]
+        );
+
+        assert_eq!(
+            run([(press(), close), (release(), Point::new(290.0, 100.0))]),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn double_clicking_title_toggles_maximize() {
+        let title = Point::new(100.0, 16.0);
+
+        assert_eq!(
+            run([
+                (press(), title),
+                (release(), title),
+                (press(), title),
+                (release(), title),
+            ]),
+            vec![Request::Drag, Request::ToggleMaximize]
+        );
+    }
+
+    #[test]
+    fn clicks_below_header_make_no_requests() {
+        let content = Point::new(100.0, 100.0);
+
+        assert_eq!(run([(press(), content), (release(), content)]), vec![]);
+    }
+
+    #[test]
+    fn requests_are_taken_once() {
+        let header = Header::default();
+
+        header.request(Request::Drag);
+        header.request(Request::Close);
+
+        assert_eq!(header.take_requests(), vec![Request::Drag, Request::Close]);
+        assert_eq!(header.take_requests(), Vec::new());
+    }
+}
@@ -0,0 +1,259 @@
+//! Persist the geometry of windows across sessions.
+use crate::core::{Point, Rectangle, Size};
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The geometry of a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    /// The outer position of the window, in physical pixels.
+    ///
+    /// Some platforms (e.g. Wayland) do not expose it.
+    pub position: Option<Point<i32>>,
+    /// The logical size of the window.
+    pub size: Size,
+    /// Whether the window is maximized.
+    pub maximized: bool,
+}
+
+/// A monitor where a window can be placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Monitor {
+    /// The bounds of the monitor on the desktop, in physical pixels.
+    pub bounds: Rectangle,
+    /// The scale factor of the monitor.
+    pub scale_factor: f32,
+}
+
+impl Monitor {
+    /// Returns the [`Monitor`] of a winit monitor, if its geometry is known.
+    pub fn from_winit(monitor: &winit::monitor::MonitorHandle) -> Option<Self> {
+        let position = monitor.position()?;
+        let size = monitor.current_video_mode()?.size();
+
+        Some(Self {
+            bounds: Rectangle {
+                x: position.x as f32,
+                y: position.y as f32,
+                width: size.width as f32,
+                height: size.height as f32,
+            },
+            scale_factor: monitor.scale_factor() as f32,
+        })
+    }
+}
+
+impl Geometry {
+    /// Fits the [`Geometry`] inside the most suitable of the given monitors.
+    ///
+    /// A window that is no longer visible on any monitor is moved to the
+    /// first one.
+    pub fn clamp(self, monitors: &[Monitor]) -> Self {
+        let overlap = |monitor: &Monitor| {
+            let Some(position) = self.position else {
+                return 0.0;
+            };
+
+            let bounds = Rectangle {
+                x: position.x as f32,
+                y: position.y as f32,
+                width: self.size.width * monitor.scale_factor,
+                height: self.size.height * monitor.scale_factor,
+            };
+
+            monitor
+                .bounds
+                .intersection(&bounds)
+                .as_ref()
+                .map_or(0.0, Rectangle::area)
+        };
+
+        let Some(monitor) = monitors
+            .iter()
+            .enumerate()
+            .max_by(|(i, a), (j, b)| {
+                overlap(a).total_cmp(&overlap(b)).then(j.cmp(i))
+            })
+            .map(|(_, monitor)| monitor)
+        else {
+            return self;
+        };
+
+        let bounds = monitor.bounds;
+        let scale_factor = monitor.scale_factor;
+
+        let size = Size::new(
+            self.size.width.min(bounds.width / scale_factor),
+            self.size.height.min(bounds.height / scale_factor),
+        );
+
+        let position = self.position.map(|position| {
+            let max_x = bounds.x + bounds.width - size.width * scale_factor;
+            let max_y = bounds.y + bounds.height - size.height * scale_factor;
+
+            Point::new(
+                (position.x as f32).min(max_x).max(bounds.x).round() as i32,
+                (position.y as f32).min(max_y).max(bounds.y).round() as i32,
+            )
+        });
+
+        Self {
+            position,
+            size,
+            maximized: self.maximized,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut values = line.split_whitespace();
+
+        let width = values.next()?.parse().ok()?;
+        let height = values.next()?.parse().ok()?;
+        let maximized = values.next()?.parse().ok()?;
+
+        let position = match (values.next(), values.next()) {
+            (Some(x), Some(y)) => {
+                Some(Point::new(x.parse().ok()?, y.parse().ok()?))
+            }
+            _ => None,
+        };
+
+        Some(Self {
+            position,
+            size: Size::new(width, height),
+            maximized,
+        })
+    }
+
+    fn serialize(&self) -> String {
+        let mut line = format!(
+            "{} {} {}",
+            self.size.width, self.size.height, self.maximized
+        );
+
+        if let Some(position) = self.position {
+            line.push_str(&format!(" {} {}", position.x, position.y));
+        }
+
+        line
+    }
+}
+
+/// Returns the file where the geometry of the window with the given
+/// identifier is persisted.
+///
+/// Files are stored in the state directory of the platform (e.g.
+/// `$XDG_STATE_HOME` on Linux), under the identifier of the application or,
+/// if missing, the name of the executable.
+pub fn path(application: Option<&str>, window: &str) -> Option<PathBuf> {
+    let application = application.map(str::to_owned).or_else(|| {
+        env::current_exe()
+            .ok()?
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+    })?;
+
+    Some(
+        state_dir()?
+            .join(sanitize(&application))
+            .join("windows")
+            .join(sanitize(window)),
+    )
+}
+
+/// Loads the [`Geometry`] persisted in the given file.
+pub fn load(path: &Path) -> Option<Geometry> {
+    Geometry::parse(&fs::read_to_string(path).ok()?)
+}
+
+/// Persists the [`Geometry`] in the given file.
+pub fn save(path: &Path, geometry: &Geometry) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, geometry.serialize())
+}
+
+fn state_dir() -> Option<PathBuf> {
+    let home = || env::var_os("HOME").map(PathBuf::from);
+
+    if cfg!(target_os = "windows") {
+        env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env::var_os("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| home().map(|home| home.join(".local").join("state")))
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometry_is_clamped_to_available_monitors() {
+        let monitors = [
+            Monitor {
+                bounds: Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(1920.0, 1080.0),
+                ),
+                scale_factor: 1.0,
+            },
+            Monitor {
+                bounds: Rectangle::new(
+                    Point::new(1920.0, 0.0),
+                    Size::new(2560.0, 1440.0),
+                ),
+                scale_factor: 2.0,
+            },
+        ];
+
+        let geometry = Geometry {
+            position: Some(Point::new(4000, 100)),
+            size: Size::new(800.0, 600.0),
+            maximized: false,
+        };
+
+        assert_eq!(
+            geometry.clamp(&monitors).position,
+            Some(Point::new(2880, 100))
+        );
+
+        let lost = Geometry {
+            position: Some(Point::new(-5000, -5000)),
+            size: Size::new(3000.0, 600.0),
+            maximized: true,
+        };
+
+        assert_eq!(
+            lost.clamp(&monitors),
+            Geometry {
+                position: Some(Point::new(0, 0)),
+                size: Size::new(1920.0, 600.0),
+                maximized: true,
+            }
+        );
+
+        assert_eq!(Geometry::parse(&lost.serialize()), Some(lost));
+    }
+}
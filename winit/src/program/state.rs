@@ -206,7 +206,15 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift_key() {
+                    _debug.toggle_inspector();
+                } else if self.modifiers.control_key() {
+                    _debug.toggle_collapsed();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
@@ -1,10 +1,15 @@
+use crate::conversion;
+use crate::core::input_method;
 use crate::core::mouse;
-use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::window::{Decorations, Id, PointerGrab};
+use crate::core::{InputMethod, Point, Rectangle, Size};
 use crate::graphics::Compositor;
+use crate::program::decorations::Header;
+use crate::program::geometry::{self, Geometry};
 use crate::program::{DefaultStyle, Program, State};
 
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use winit::monitor::MonitorHandle;
 
@@ -40,6 +45,7 @@ where
         compositor: &mut C,
         exit_on_close_request: bool,
         resize_border: u32,
+        geometry: Option<PathBuf>,
     ) -> &mut Window<P, C> {
         let state = State::new(application, id, window.as_ref());
         let viewport_version = state.viewport_version();
@@ -72,6 +78,12 @@ where
                 prev_dnd_destination_rectangles_count: 0,
                 resize_enabled: false,
                 redraw_requested: false,
+                decorations: Decorations::None,
+                server_side_decorations: None,
+                header: Header::default(),
+                pointer_grab: PointerGrab::None,
+                ime: None,
+                geometry,
             },
         );
 
@@ -123,10 +135,25 @@ where
         let window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
 
+        window.save_geometry();
+
         Some(window)
     }
 }
 
+impl<P, C> Drop for WindowManager<P, C>
+where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: DefaultStyle,
+{
+    fn drop(&mut self) {
+        for window in self.entries.values() {
+            window.save_geometry();
+        }
+    }
+}
+
 impl<P, C> Default for WindowManager<P, C>
 where
     P: Program,
@@ -169,6 +196,12 @@ where
     pub renderer: P::Renderer,
     pub resize_enabled: bool,
     pub(crate) redraw_requested: bool,
+    pub(crate) decorations: Decorations,
+    pub(crate) server_side_decorations: Option<bool>,
+    pub(crate) header: Header,
+    pub(crate) pointer_grab: PointerGrab,
+    ime: Option<(Rectangle, input_method::Purpose)>,
+    geometry: Option<PathBuf>,
 }
 
 impl<P, C> Window<P, C>
@@ -188,6 +221,96 @@ where
             })
     }
 
+    /// Shows or hides the decorations of the [`Window`].
+    ///
+    /// A header bar is drawn instead when the window system cannot draw the
+    /// decorations.
+    pub(crate) fn set_decorations(&mut self, decorated: bool) {
+        self.decorations = if !decorated {
+            Decorations::None
+        } else if self.server_side_decorations.unwrap_or(true) {
+            Decorations::ServerSide
+        } else {
+            Decorations::ClientSide
+        };
+
+        self.raw
+            .set_decorations(self.decorations == Decorations::ServerSide);
+    }
+
+    /// Follows the decoration mode the window system configured for the
+    /// [`Window`], returning whether its [`Decorations`] changed.
+    ///
+    /// The decoration object of a toplevel belongs to winit, which draws a
+    /// frame of its own only while the compositor configures client-side
+    /// decorations. A decorated window without a frame around its surface
+    /// is therefore decorated by the compositor.
+    pub(crate) fn sync_decorations(&mut self) -> bool {
+        let Some(server_side) = self.server_side_decorations else {
+            return false;
+        };
+
+        if self.decorations == Decorations::None {
+            return false;
+        }
+
+        let configured = self.raw.is_decorated()
+            && self.raw.outer_size() == self.raw.surface_size();
+
+        if configured == server_side {
+            return false;
+        }
+
+        self.server_side_decorations = Some(configured);
+        self.set_decorations(true);
+
+        true
+    }
+
+    /// Returns the [`Header`] of the [`Window`], if it has client-side
+    /// decorations.
+    pub(crate) fn header(&self) -> Option<Header> {
+        (self.decorations == Decorations::ClientSide)
+            .then(|| self.header.clone())
+    }
+
+    /// Persists the geometry of the [`Window`], if it was opened with a
+    /// `geometry_id`.
+    pub fn save_geometry(&self) {
+        let Some(path) = &self.geometry else {
+            return;
+        };
+
+        let geometry = if self.raw.is_maximized() {
+            // Keep the geometry the window will be restored to
+            Geometry {
+                maximized: true,
+                ..geometry::load(path).unwrap_or(Geometry {
+                    position: None,
+                    size: self.size(),
+                    maximized: true,
+                })
+            }
+        } else {
+            Geometry {
+                position: self
+                    .raw
+                    .outer_position()
+                    .ok()
+                    .map(|position| Point::new(position.x, position.y)),
+                size: self.size(),
+                maximized: false,
+            }
+        };
+
+        if let Err(error) = geometry::save(path, &geometry) {
+            log::warn!(
+                "Failed to save window geometry to {}: {error}",
+                path.display()
+            );
+        }
+    }
+
     pub fn size(&self) -> Size {
         let size = self.raw.surface_size().to_logical(self.raw.scale_factor());
 
@@ -200,4 +323,36 @@ where
             self.raw.request_redraw();
         }
     }
+
+    pub fn request_input_method(&mut self, input_method: &InputMethod) {
+        match input_method {
+            InputMethod::Disabled => {
+                if self.ime.take().is_some() {
+                    self.raw.set_ime_allowed(false);
+                }
+            }
+            InputMethod::Enabled {
+                cursor, purpose, ..
+            } => {
+                if self.ime.is_none() {
+                    self.raw.set_ime_allowed(true);
+                }
+
+                if self.ime != Some((*cursor, *purpose)) {
+                    self.raw.set_ime_cursor_area(
+                        winit::dpi::LogicalPosition::new(cursor.x, cursor.y)
+                            .into(),
+                        winit::dpi::LogicalSize::new(
+                            cursor.width,
+                            cursor.height,
+                        )
+                        .into(),
+                    );
+                    self.raw.set_ime_purpose(conversion::ime_purpose(*purpose));
+
+                    self.ime = Some((*cursor, *purpose));
+                }
+            }
+        }
+    }
 }